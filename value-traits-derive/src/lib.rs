@@ -13,30 +13,27 @@
 //! Derive macros for the [`value-traits`](https://docs.rs/value-traits/latest/value_traits/) crate.
 
 use proc_macro::TokenStream;
-use quote::{ToTokens, quote};
-use syn::{
-    AngleBracketedGenericArguments, DeriveInput, parse_macro_input, parse2, punctuated::Punctuated,
-};
-
-/// Helper function returning the list of parameter names without angle brackets.
-fn get_names(ty_generics_token_stream: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-    if ty_generics_token_stream.is_empty() {
-        proc_macro2::TokenStream::new()
-    } else {
-        let parsed_args: AngleBracketedGenericArguments =
-            parse2(ty_generics_token_stream)
-                .expect("Failed to parse ty_generics into AngleBracketedGenericArguments. This indicates an unexpected structure in the generic parameters.");
-
-        parsed_args.args.into_token_stream()
-    }
+use quote::quote;
+use syn::{DeriveInput, parse_macro_input, punctuated::Punctuated};
+
+/// Attributes of a derive macro.
+struct Attributes {
+    /// Additional bounds for the where clause.
+    bounds: Vec<proc_macro2::TokenStream>,
+    /// The custom index type, if any.
+    index: Option<syn::Type>,
+    /// Whether to generate inherent subslicing methods.
+    inherent: bool,
 }
 
-/// Helper function to extract additional bounds from attributes
-fn extract_additional_bounds(
-    input: &DeriveInput,
-    attr_name: &str,
-) -> Vec<proc_macro2::TokenStream> {
-    let mut additional_bounds = Vec::new();
+/// Helper function to extract the attributes of a derive macro; besides
+/// `bound`, only the keys in `allowed` are accepted.
+fn extract_attributes(input: &DeriveInput, attr_name: &str, allowed: &[&str]) -> Attributes {
+    let mut attributes = Attributes {
+        bounds: Vec::new(),
+        index: None,
+        inherent: false,
+    };
     for attr in &input.attrs {
         if attr.path().is_ident(attr_name) {
             attr.parse_nested_meta(|meta| {
@@ -44,14 +41,34 @@ fn extract_additional_bounds(
                     let bound: syn::LitStr = meta.value()?.parse()?;
                     let bound_tokens: proc_macro2::TokenStream =
                         bound.value().parse().expect("Failed to parse bound");
-                    additional_bounds.push(bound_tokens);
+                    attributes.bounds.push(bound_tokens);
+                    Ok(())
+                } else if allowed.contains(&"index") && meta.path.is_ident("index") {
+                    let index: syn::LitStr = meta.value()?.parse()?;
+                    if attributes.index.is_some() {
+                        return Err(meta.error("the index type can be specified only once"));
+                    }
+                    attributes.index = Some(index.parse()?);
+                    Ok(())
+                } else if allowed.contains(&"inherent") && meta.path.is_ident("inherent") {
+                    attributes.inherent = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute key"))
                 }
-                Ok(())
             })
             .unwrap_or_else(|e| panic!("Failed to parse attribute {attr_name}: {e}"));
         }
     }
-    additional_bounds
+    attributes
+}
+
+/// Helper function to extract additional bounds from attributes
+fn extract_additional_bounds(
+    input: &DeriveInput,
+    attr_name: &str,
+) -> Vec<proc_macro2::TokenStream> {
+    extract_attributes(input, attr_name, &[]).bounds
 }
 
 /// Helper function to add additional bounds to a where clause
@@ -86,15 +103,47 @@ fn get_params_without_defaults(
     params
 }
 
+/// Helper function returning the range types for which subslicing is
+/// implemented.
+fn range_types() -> [proc_macro2::TokenStream; 6] {
+    [
+        quote! { ::core::ops::Range<usize> },
+        quote! { ::core::ops::RangeFrom<usize> },
+        quote! { ::core::ops::RangeToInclusive<usize> },
+        quote! { ::core::ops::RangeFull },
+        quote! { ::core::ops::RangeInclusive<usize> },
+        quote! { ::core::ops::RangeTo<usize> },
+    ]
+}
+
 /// A derive macro fully implementing subslices on top of a
 /// [`SliceByValue`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValue.html).
 ///
-/// The macro defines a structure `<YOUR TYPE>SubsliceImpl` that keeps track of
-/// a reference to a slice, and of the start and end of the subslice.
-/// `<YOUR TYPE>SubsliceImpl` then implements
-/// [`SliceByValue`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValue.html)
-/// and
-/// [`SliceByValueSubslice`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubslice.html).
+/// The macro implements
+/// [`SliceByValueSubslice`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubslice.html)
+/// using as subslice type the generic structure
+/// [`SubsliceOf`](https://docs.rs/value-traits/latest/value_traits/adapters/subslice/struct.SubsliceOf.html)
+/// provided by `value-traits`, which keeps track of a reference to a slice
+/// and of the start and end of the subslice, and implements all by-value
+/// traits by delegation. In particular, if the values are
+/// [comparable](PartialEq), subslices can be compared with standard slices
+/// and arrays of values (and references to them), and if the values
+/// implement [`Debug`], they are formatted as a list.
+///
+/// The macro implements also
+/// [`FilterIndices`](https://docs.rs/value-traits/latest/value_traits/algorithms/trait.FilterIndices.html)
+/// using its default implementation.
+///
+/// ## Generated Items
+///
+/// The derive macros of this crate generate only trait implementations for
+/// your type, which refer to generic structures and iterators defined in
+/// `value-traits`; no new types are defined. Thus, deriving on multiple types
+/// in the same module, or on types defined inside function bodies, is always
+/// possible, and bug fixes and improvements to the structures do not require
+/// changes to the generated code. The structures can be named as
+/// [`Subslice<'_, <YOUR TYPE>>`](https://docs.rs/value-traits/latest/value_traits/slices/type.Subslice.html),
+/// or directly as `SubsliceOf<'_, <YOUR TYPE>>`.
 ///
 /// ## Additional Bounds
 ///
@@ -104,86 +153,146 @@ fn get_params_without_defaults(
 /// additional bounds with respect to the type declaration must be specified
 /// using the `#[value_traits_subslices(bound = "<BOUND>")]` attribute. Multiple bounds can
 /// be specified with multiple attributes.
+///
+/// ## Typed Indices
+///
+/// If values are addressed by a newtype index, such as `NodeId`, the
+/// `#[value_traits_subslices(index = "NodeId")]` attribute generates
+/// additionally the inherent methods `get_value_at`, `index_value_at`,
+/// `get_subslice_at`, and `index_subslice_at`, which accept typed indices
+/// (and [ranges](core::ops::Range) of typed indices) and convert them with
+/// [`Into<usize>`]. The index type must implement [`Into<usize>`], and it can
+/// be specified only once.
+///
+/// ## Inherent Methods
+///
+/// The `#[value_traits_subslices(inherent)]` attribute generates
+/// additionally an inherent method `subslice` accepting any range, so that
+/// users of your type can extract subslices without importing
+/// [`SliceByValueSubslice`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubslice.html).
+/// The method calls
+/// [`index_subslice`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubsliceRange.html#method.index_subslice),
+/// so it panics if the range is out of bounds. The same attribute for
+/// [`SubslicesMut`] generates an inherent method `subslice_mut`.
 #[proc_macro_derive(Subslices, attributes(value_traits_subslices))]
 pub fn subslices(input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as DeriveInput);
 
     // Extract and add additional bounds
-    let additional_bounds = extract_additional_bounds(&input, "value_traits_subslices");
-    add_bounds_to_where_clause(&mut input.generics, additional_bounds);
+    let attributes = extract_attributes(&input, "value_traits_subslices", &["index", "inherent"]);
+    add_bounds_to_where_clause(&mut input.generics, attributes.bounds);
 
     let input_ident = input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let params = get_params_without_defaults(&input.generics);
-    let ty_generics_token_stream = ty_generics.clone().into_token_stream();
-
-    let names = get_names(ty_generics_token_stream);
-    let subslice_impl = quote::format_ident!("{}SubsliceImpl", input_ident);
     let mut res = quote! {
         #[automatically_derived]
-        pub struct #subslice_impl<'__subslice_impl, #params> {
-            slice: &'__subslice_impl #input_ident #ty_generics,
-            range: ::core::ops::Range<usize>,
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValue for #subslice_impl<'__subslice_impl, #names> #where_clause {
-            type Value = <#input_ident #ty_generics as ::value_traits::slices::SliceByValue>::Value;
-
-            #[inline]
-            fn len(&self) -> usize {
-                self.range.len()
-            }
-
-            unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
-                self.slice.get_value_unchecked(index + self.range.start)
-            }
+        impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValueSubsliceGat<'__subslice_impl> for #input_ident #ty_generics #where_clause  {
+            type Subslice = ::value_traits::adapters::subslice::SubsliceOf<'__subslice_impl, Self>;
         }
 
         #[automatically_derived]
-        impl<'__subslice_impl, '__subslice_gat, #params> ::value_traits::slices::SliceByValueSubsliceGat<'__subslice_gat> for #subslice_impl<'__subslice_impl, #names> #where_clause {
-            type Subslice = #subslice_impl<'__subslice_gat, #names>;
-        }
+        impl #impl_generics ::value_traits::__private::DerivedSubslices for #input_ident #ty_generics #where_clause {}
 
         #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValueSubsliceGat<'__subslice_impl> for #input_ident #ty_generics #where_clause  {
-            type Subslice = #subslice_impl<'__subslice_impl, #names>;
-        }
+        impl #impl_generics ::value_traits::algorithms::FilterIndices for #input_ident #ty_generics #where_clause {}
     };
 
-    for range_type in [
-        quote! { ::core::ops::Range<usize> },
-        quote! { ::core::ops::RangeFrom<usize> },
-        quote! { ::core::ops::RangeToInclusive<usize> },
-        quote! { ::core::ops::RangeFull },
-        quote! { ::core::ops::RangeInclusive<usize> },
-        quote! { ::core::ops::RangeTo<usize> },
-    ] {
+    for range_type in range_types() {
         res.extend(quote! {
             #[automatically_derived]
             impl #impl_generics ::value_traits::slices::SliceByValueSubsliceRange<#range_type> for #input_ident #ty_generics #where_clause {
+                #[inline]
                 unsafe fn get_subslice_unchecked(
                     &self,
                     range: #range_type,
                 ) -> ::value_traits::slices::Subslice<'_, Self> {
-                    #subslice_impl {
-                        slice: &self,
-                        range: ::value_traits::slices::ComposeRange::compose(&range, 0..self.len()),
-                    }
+                    let range = ::value_traits::slices::ComposeRange::compose(
+                        &range,
+                        0..::value_traits::slices::SliceByValue::len(self),
+                    );
+                    unsafe { ::value_traits::adapters::subslice::SubsliceOf::new_unchecked(self, range) }
                 }
             }
+        });
+    }
+
+    if attributes.inherent {
+        res.extend(quote! {
             #[automatically_derived]
-            impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValueSubsliceRange<#range_type>
-                for #subslice_impl<'__subslice_impl, #names> #where_clause
-            {
-                unsafe fn get_subslice_unchecked(
+            impl #impl_generics #input_ident #ty_generics #where_clause {
+                /// Returns a subslice.
+                ///
+                /// # Panics
+                ///
+                /// This method will panic if the range is out of bounds.
+                #[inline]
+                pub fn subslice<R: ::value_traits::slices::ComposeRange>(&self, range: R) -> ::value_traits::slices::Subslice<'_, Self>
+                where
+                    Self: ::value_traits::slices::SliceByValueSubsliceRange<R>,
+                {
+                    ::value_traits::slices::SliceByValueSubsliceRange::index_subslice(self, range)
+                }
+            }
+        });
+    }
+
+    if let Some(index) = attributes.index {
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { #index: ::core::convert::Into<usize> });
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        res.extend(quote! {
+            #[automatically_derived]
+            impl #impl_generics #input_ident #ty_generics #where_clause {
+                /// Returns the value at the given typed index, or `None` if
+                /// the index is out of bounds.
+                #[inline]
+                pub fn get_value_at(
                     &self,
-                    range: #range_type,
+                    index: #index,
+                ) -> ::core::option::Option<<Self as ::value_traits::slices::SliceByValue>::Value> {
+                    ::value_traits::slices::SliceByValue::get_value(self, ::core::convert::Into::<usize>::into(index))
+                }
+
+                /// Returns the value at the given typed index.
+                ///
+                /// # Panics
+                ///
+                /// This method will panic if the index is out of bounds.
+                #[inline]
+                pub fn index_value_at(
+                    &self,
+                    index: #index,
+                ) -> <Self as ::value_traits::slices::SliceByValue>::Value {
+                    ::value_traits::slices::SliceByValue::index_value(self, ::core::convert::Into::<usize>::into(index))
+                }
+
+                /// Returns a subslice delimited by typed indices, or `None`
+                /// if the range is out of bounds.
+                #[inline]
+                pub fn get_subslice_at(
+                    &self,
+                    range: ::core::ops::Range<#index>,
+                ) -> ::core::option::Option<::value_traits::slices::Subslice<'_, Self>> {
+                    let range = ::core::convert::Into::<usize>::into(range.start)..::core::convert::Into::<usize>::into(range.end);
+                    ::value_traits::slices::SliceByValueSubsliceRange::get_subslice(self, range)
+                }
+
+                /// Returns a subslice delimited by typed indices.
+                ///
+                /// # Panics
+                ///
+                /// This method will panic if the range is out of bounds.
+                #[inline]
+                pub fn index_subslice_at(
+                    &self,
+                    range: ::core::ops::Range<#index>,
                 ) -> ::value_traits::slices::Subslice<'_, Self> {
-                    #subslice_impl {
-                        slice: self.slice,
-                        range: ::value_traits::slices::ComposeRange::compose(&range, self.range.clone()),
-                    }
+                    let range = ::core::convert::Into::<usize>::into(range.start)..::core::convert::Into::<usize>::into(range.end);
+                    ::value_traits::slices::SliceByValueSubsliceRange::index_subslice(self, range)
                 }
             }
         });
@@ -196,23 +305,28 @@ pub fn subslices(input: TokenStream) -> TokenStream {
 /// [`SliceByValueMut`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueMut.html)
 /// for which the derive macro [`Subslices`] has been already applied.
 ///
-/// The macro defines a structure `<YOUR TYPE>SubsliceImplMut` that keeps track
-/// of a mutable reference to a slice, and of the start and end of the subslice.
-/// `<YOUR TYPE>SubsliceImplMut` then implements
-/// [`SliceByValueMut`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueMut.html),
-/// [`SliceByValueSubslice`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubslice.html),
-/// and
-/// [`SliceByValueSubsliceMut`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubsliceMut.html).
+/// The macro implements
+/// [`SliceByValueSubsliceMut`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubsliceMut.html)
+/// using as mutable subslice type the generic structure
+/// [`SubsliceOfMut`](https://docs.rs/value-traits/latest/value_traits/adapters/subslice/struct.SubsliceOfMut.html)
+/// provided by `value-traits`, which keeps track of a mutable reference to a
+/// slice and of the start and end of the subslice.
 ///
 /// Note that
 /// [`SliceByValueSubslice`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubslice.html)
-/// methods will return the `<YOUR TYPE>SubsliceImpl` structure generated by the
-/// [`Subslices`] macro.
+/// methods of mutable subslices return a
+/// [`SubsliceOf`](https://docs.rs/value-traits/latest/value_traits/adapters/subslice/struct.SubsliceOf.html),
+/// as in the case of [`Subslices`].
+///
+/// The inherent method `as_subslice` reborrows a mutable subslice as a
+/// read-only subslice covering the same range, without consuming it.
+/// Comparisons and formatting are implemented as in the case of
+/// [`Subslices`].
 ///
 /// ## Chunks
 ///
 /// Presently, [`try_chunks_mut`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueMut.html#method.try_chunks_mut)
-/// is not supported.
+/// is not supported on mutable subslices.
 ///
 /// ## Additional Bounds
 ///
@@ -224,132 +338,69 @@ pub fn subslices(input: TokenStream) -> TokenStream {
 /// additional bounds with respect to the type declaration must be specified
 /// using the `#[value_traits_subslices_mut(bound = "<BOUND>")]` attribute.
 /// Multiple bounds can be specified with multiple attributes.
+///
+/// ## Inherent Methods
+///
+/// The `#[value_traits_subslices_mut(inherent)]` attribute generates
+/// additionally an inherent method `subslice_mut` accepting any range, so
+/// that users of your type can extract mutable subslices without importing
+/// [`SliceByValueSubsliceMut`](https://docs.rs/value-traits/latest/value_traits/slices/trait.SliceByValueSubsliceMut.html).
+/// The method panics if the range is out of bounds.
 #[proc_macro_derive(SubslicesMut, attributes(value_traits_subslices_mut))]
 pub fn subslices_mut(input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as DeriveInput);
 
     // Extract and add additional bounds
-    let additional_bounds = extract_additional_bounds(&input, "value_traits_subslices_mut");
-    add_bounds_to_where_clause(&mut input.generics, additional_bounds);
+    let attributes = extract_attributes(&input, "value_traits_subslices_mut", &["inherent"]);
+    add_bounds_to_where_clause(&mut input.generics, attributes.bounds);
 
     let input_ident = input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let params = get_params_without_defaults(&input.generics);
-    let ty_generics_token_stream = ty_generics.clone().into_token_stream();
-
-    let names = get_names(ty_generics_token_stream);
-    let subslice_impl = quote::format_ident!("{}SubsliceImpl", input_ident);
-    let subslice_impl_mut = quote::format_ident!("{}SubsliceImplMut", input_ident);
     let mut res = quote! {
-        #[automatically_derived]
-        pub struct #subslice_impl_mut<'__subslice_impl, #params> {
-            slice: &'__subslice_impl mut #input_ident #ty_generics,
-            range: ::core::ops::Range<usize>,
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValue for #subslice_impl_mut<'__subslice_impl, #names> #where_clause {
-            type Value = <#input_ident #ty_generics as ::value_traits::slices::SliceByValue>::Value;
-
-            #[inline]
-            fn len(&self) -> usize {
-                self.range.len()
-            }
-
-            unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
-                self.slice.get_value_unchecked(index + self.range.start)
-            }
-        }
-
-
-        #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValueMut for #subslice_impl_mut<'__subslice_impl, #names> #where_clause  {
-            unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
-                self.slice.set_value_unchecked(index + self.range.start, value)
-            }
-
-            unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
-                self.slice.replace_value_unchecked(index + self.range.start, value)
-            }
-
-            type ChunksMut<'a> = ::core::iter::Empty<&'a mut Self>
-            where
-                Self: 'a;
-
-            type ChunksMutError = ::value_traits::slices::ChunksMutNotSupported;
-
-            fn try_chunks_mut(&mut self, _chunk_size: usize) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
-                // Derived subslice types cannot provide mutable chunks
-                Err(::value_traits::slices::ChunksMutNotSupported)
-            }
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, '__subslice_gat, #params> ::value_traits::slices::SliceByValueSubsliceGat<'__subslice_gat> for #subslice_impl_mut<'__subslice_impl, #names> #where_clause {
-            type Subslice = #subslice_impl<'__subslice_gat, #names>;
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, '__subslice_gat, #params> ::value_traits::slices::SliceByValueSubsliceGatMut<'__subslice_gat> for #subslice_impl_mut<'__subslice_impl, #names> #where_clause {
-            type SubsliceMut = #subslice_impl_mut<'__subslice_gat, #names>;
-        }
-
         #[automatically_derived]
         impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValueSubsliceGatMut<'__subslice_impl> for #input_ident #ty_generics #where_clause  {
-            type SubsliceMut = #subslice_impl_mut<'__subslice_impl, #names>;
+            type SubsliceMut = ::value_traits::adapters::subslice::SubsliceOfMut<'__subslice_impl, Self>;
         }
 
+        #[automatically_derived]
+        impl #impl_generics ::value_traits::__private::DerivedSubslicesMut for #input_ident #ty_generics #where_clause {}
     };
 
-    for range_type in [
-        quote! { ::core::ops::Range<usize> },
-        quote! { ::core::ops::RangeFrom<usize> },
-        quote! { ::core::ops::RangeToInclusive<usize> },
-        quote! { ::core::ops::RangeFull },
-        quote! { ::core::ops::RangeInclusive<usize> },
-        quote! { ::core::ops::RangeTo<usize> },
-    ] {
-        // Impl subslice mut traits for the original type
-        res.extend(quote!{
+    for range_type in range_types() {
+        res.extend(quote! {
             #[automatically_derived]
             impl #impl_generics ::value_traits::slices::SliceByValueSubsliceRangeMut<#range_type> for #input_ident #ty_generics #where_clause {
+                #[inline]
                 unsafe fn get_subslice_unchecked_mut(
                     &mut self,
                     range: #range_type,
                 ) -> ::value_traits::slices::SubsliceMut<'_, Self> {
-                    let len = self.len();
-                    #subslice_impl_mut {
-                        slice: self,
-                        range: ::value_traits::slices::ComposeRange::compose(&range, 0..len),
-                    }
-                }
-            }
-            #[automatically_derived]
-            impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValueSubsliceRange<#range_type>
-                for #subslice_impl_mut<'__subslice_impl, #names> #where_clause
-            {
-                unsafe fn get_subslice_unchecked(
-                    &self,
-                    range: #range_type,
-                ) -> ::value_traits::slices::Subslice<'_, Self> {
-                    #subslice_impl {
-                        slice: &*self.slice,
-                        range: ::value_traits::slices::ComposeRange::compose(&range, self.range.clone()),
-                    }
+                    let range = ::value_traits::slices::ComposeRange::compose(
+                        &range,
+                        0..::value_traits::slices::SliceByValue::len(self),
+                    );
+                    unsafe { ::value_traits::adapters::subslice::SubsliceOfMut::new_unchecked(self, range) }
                 }
             }
+        });
+    }
+
+    if attributes.inherent {
+        res.extend(quote! {
             #[automatically_derived]
-            impl<'__subslice_impl, #params> ::value_traits::slices::SliceByValueSubsliceRangeMut<#range_type>
-                for #subslice_impl_mut<'__subslice_impl, #names> #where_clause
-            {
-                unsafe fn get_subslice_unchecked_mut(
-                    &mut self,
-                    range: #range_type,
-                ) -> ::value_traits::slices::SubsliceMut<'_, Self> {
-                    #subslice_impl_mut {
-                        slice: self.slice,
-                        range: ::value_traits::slices::ComposeRange::compose(&range, self.range.clone()),
-                    }
+            impl #impl_generics #input_ident #ty_generics #where_clause {
+                /// Returns a mutable subslice.
+                ///
+                /// # Panics
+                ///
+                /// This method will panic if the range is out of bounds.
+                #[inline]
+                pub fn subslice_mut<R: ::value_traits::slices::ComposeRange>(&mut self, range: R) -> ::value_traits::slices::SubsliceMut<'_, Self>
+                where
+                    Self: ::value_traits::slices::SliceByValueSubsliceRangeMut<R>,
+                {
+                    ::value_traits::slices::SliceByValueSubsliceRangeMut::index_subslice_mut(self, range)
                 }
             }
         });
@@ -358,20 +409,44 @@ pub fn subslices_mut(input: TokenStream) -> TokenStream {
     res.into()
 }
 
-/// A derive macro fully implementing
+/// A derive macro providing
 /// [`IterateByValue`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValue.html)
 /// and
 /// [`IterateByValueFrom`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValueFrom.html)
-/// for subslices on top of a the `<YOUR TYPE>SubsliceImpl` structure generated
-/// by the derive macro [`Subslices`].
+/// for the subslices generated by the derive macro [`Subslices`].
 ///
-/// The macro defines a structure `<YOUR TYPE>Iter` that keeps track of a
-/// mutable reference to a slice and of a current iteration range; the structure
-/// is used to implement
-/// [`IterateByValue`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValue.html)
+/// The subslice structure
+/// [`SubsliceOf`](https://docs.rs/value-traits/latest/value_traits/adapters/subslice/struct.SubsliceOf.html)
+/// used by [`Subslices`] implements the iteration traits, and the marker
+/// trait
+/// [`ExactSizeIterateByValue`](https://docs.rs/value-traits/latest/value_traits/iter/trait.ExactSizeIterateByValue.html),
+/// using the generic iterator
+/// [`SubsliceIter`](https://docs.rs/value-traits/latest/value_traits/adapters/subslice/struct.SubsliceIter.html),
+/// so this macro just checks that [`Subslices`] has been derived, too. It is
+/// retained so that the iteration capabilities of a type are documented by
+/// its derive list.
+///
+/// The iterator implements [`Clone`] (cloning is cheap, as only a reference
+/// and a range are copied) and [`Debug`], showing the remaining iteration
+/// range. Since [`Iterator::advance_by`] is unstable, the iterator has an
+/// inherent method `skip_values` that advances it in constant time, and an
+/// inherent method `take_values` that limits the number of returned elements
+/// without wrapping the iterator in an adapter. Moreover, the inherent
+/// methods `peek_value` and `peek_nth` make it possible to look ahead without
+/// buffering.
+///
+/// The iterator also implements
+/// [`SeekableValueIter`](https://docs.rs/value-traits/latest/value_traits/iter/trait.SeekableValueIter.html):
+/// seeking to an index takes constant time, and seeking to a value uses an
+/// exponential search followed by a binary search.
+///
+/// Besides the iterators returned by
+/// [`iter_value`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValue.html#tymethod.iter_value)
 /// and
-/// [`IterateByValueFrom`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValueFrom.html)
-/// on `<YOUR TYPE>SubsliceImpl`.
+/// [`iter_value_from`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValueFrom.html#tymethod.iter_value_from),
+/// subslices have inherent methods `iter_value_to(end)` and
+/// `iter_value_range(range)` returning an iterator of the same type restricted
+/// to a range, without creating an intermediate subslice.
 ///
 /// ## Additional Bounds
 ///
@@ -390,226 +465,31 @@ pub fn iterators(input: TokenStream) -> TokenStream {
     add_bounds_to_where_clause(&mut input.generics, additional_bounds);
 
     let input_ident = input.ident;
-    input.generics.make_where_clause();
-    let (_impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
     let params = get_params_without_defaults(&input.generics);
-    let ty_generics_token_stream = ty_generics.clone().into_token_stream();
-
-    let names = get_names(ty_generics_token_stream);
-    let subslice_impl = quote::format_ident!("{}SubsliceImpl", input_ident);
-    let iter = quote::format_ident!("{}Iter", input_ident);
     quote! {
-        #[automatically_derived]
-        pub struct #iter<'__iter_ref, #params> {
-            subslice: &'__iter_ref #input_ident #ty_generics,
-            range: ::core::ops::Range<usize>,
-        }
-
-        #[automatically_derived]
-        impl<'__iter_ref, #params> #iter<'__iter_ref, #names> #where_clause {
-            pub fn new(subslice: &'__iter_ref #input_ident #ty_generics) -> Self {
-                let len = subslice.len();
-                Self {
-                    subslice,
-                    range: 0..len,
-                }
-            }
-            pub fn new_with_range(subslice: &'__iter_ref #input_ident #ty_generics, range: ::core::ops::Range<usize>) -> Self {
-                Self {
-                    subslice,
-                    range,
-                }
-            }
-        }
-
-        /*#[automatically_derived]
-        impl<#params> ::value_traits::iter::IterateByValue for #input_ident #ty_generics #where_clause {
-            type Item = <Self as ::value_traits::slices::SliceByValue>::Value;
-            type Iter<'__iter_ref>
-                = #iter<'__iter_ref, #names>
-            where
-                Self: '__iter_ref;
-
-            #[inline]
-            fn iter_value(&self) -> Self::Iter<'_> {
-                #iter::new(self)
-            }
-        }
-
-        #[automatically_derived]
-        impl<#params> ::value_traits::iter::IterateByValueFrom for #input_ident #ty_generics #where_clause {
-            type IterFrom<'__iter_ref>
-                = #iter<'__iter_ref, #names>
-            where
-                Self: '__iter_ref;
-
-            #[inline]
-            fn iter_value_from(&self, from: usize) -> Self::IterFrom<'_> {
-                let len = self.len();
-                assert!(from <= len, "index out of bounds: the len is {len} but the starting index is {from}");
-                #iter::new_with_range(self, from..len)
-            }
-        }*/
-
-        #[automatically_derived]
-        /// Ideally we would like to also implement [`::core::iter::Iterator::advance_by`], but it is
-        /// nightly, and [`::core::iter::Iterator::skip`], [`::core::iter::Iterator::take`], [`::core::iter::Iterator::step_by`],
-        /// as we can do it more efficiently, but the [`::core::iter::Iterator`] trait definition
-        /// doesn't allow to return an arbitrary type.
-        impl<'__iter_ref, #params> ::core::iter::Iterator for #iter<'__iter_ref, #names> #where_clause {
-            type Item = <#input_ident #ty_generics as ::value_traits::slices::SliceByValue>::Value;
-
-            #[inline]
-            fn next(&mut self) -> Option<Self::Item> {
-                if self.range.is_empty() {
-                    return ::core::option::Option::None;
-                }
-                let value = unsafe { self.subslice.get_value_unchecked(self.range.start) };
-                self.range.start += 1;
-                ::core::option::Option::Some(value)
-            }
-
-            /// Since we are indexing into a subslice, we can implement
-            /// [`::core::iter::Iterator::nth`] without needing to consume the first `n` elements.
-            #[inline]
-            fn nth(&mut self, n: usize) -> Option<Self::Item> {
-                if n >= self.range.len() {
-                    self.range.start = self.range.end; // consume the iterator
-                    return ::core::option::Option::None;
-                }
-                let value = unsafe { self.subslice.get_value_unchecked(self.range.start + n) };
-                self.range.start += n + 1;
-                ::core::option::Option::Some(value)
-            }
-
-            #[inline]
-            fn size_hint(&self) -> (usize, Option<usize>) {
-                let len = self.range.len();
-                (len, Some(len))
+        const _: () = {
+            #[allow(dead_code)]
+            fn __value_traits_iterators<#params>(slice: &#input_ident #ty_generics) #where_clause {
+                ::value_traits::__private::assert_subslices(slice);
             }
-
-            #[inline]
-            fn count(self) -> usize {
-                self.range.len()
-            }
-
-            #[inline]
-            fn last(self) -> ::core::option::Option<Self::Item> {
-                if self.range.is_empty() {
-                    return ::core::option::Option::None;
-                }
-                ::core::option::Option::Some(unsafe { self.subslice.get_value_unchecked(self.range.end - 1) })
-            }
-
-            fn fold<__FoldB, __FoldF>(self, init: __FoldB, mut f: __FoldF) -> __FoldB
-            where
-                __FoldF: FnMut(__FoldB, Self::Item) -> __FoldB,
-            {
-                let subslice = self.subslice;
-                let mut acc = init;
-                for idx in self.range {
-                    acc = f(acc, unsafe { subslice.get_value_unchecked(idx) });
-                }
-                acc
-            }
-
-            fn for_each<__ForEachF>(self, mut f: __ForEachF)
-            where
-                __ForEachF: FnMut(Self::Item),
-            {
-                let subslice = self.subslice;
-                for idx in self.range {
-                    f(unsafe { subslice.get_value_unchecked(idx) });
-                }
-            }
-        }
-
-        impl<'__iter_ref, #params> ::core::iter::DoubleEndedIterator for #iter<'__iter_ref, #names> #where_clause {
-            #[inline]
-            fn next_back(&mut self) -> Option<Self::Item> {
-                if self.range.is_empty() {
-                    return ::core::option::Option::None;
-                }
-                self.range.end -= 1;
-                let value = unsafe { self.subslice.get_value_unchecked(self.range.end) };
-                ::core::option::Option::Some(value)
-            }
-
-            #[inline]
-            fn nth_back(&mut self, n: usize) -> ::core::option::Option<Self::Item> {
-                if n >= self.range.len() {
-                    self.range.end = self.range.start;
-                    return ::core::option::Option::None;
-                }
-                self.range.end -= n + 1;
-                ::core::option::Option::Some(unsafe { self.subslice.get_value_unchecked(self.range.end) })
-            }
-
-            fn rfold<__RFoldB, __RFoldF>(self, init: __RFoldB, mut f: __RFoldF) -> __RFoldB
-            where
-                __RFoldF: FnMut(__RFoldB, Self::Item) -> __RFoldB,
-            {
-                let subslice = self.subslice;
-                let mut acc = init;
-                for idx in self.range.rev() {
-                    acc = f(acc, unsafe { subslice.get_value_unchecked(idx) });
-                }
-                acc
-            }
-        }
-
-        impl<'__iter_ref, #params> ::core::iter::ExactSizeIterator for #iter<'__iter_ref, #names> #where_clause {
-            #[inline]
-            fn len(&self) -> usize {
-                self.range.len()
-            }
-        }
-
-        impl<'__iter_ref, #params> ::core::iter::FusedIterator for #iter<'__iter_ref, #names> #where_clause {}
-
-        #[automatically_derived]
-        impl<'__subslice_impl, '__iter_ref, #params> ::value_traits::iter::IterateByValueGat<'__iter_ref> for #subslice_impl<'__subslice_impl, #names> #where_clause {
-            type Item = <#input_ident #ty_generics as ::value_traits::slices::SliceByValue>::Value;
-            type Iter = #iter<'__iter_ref, #names>;
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::iter::IterateByValue for #subslice_impl<'__subslice_impl, #names> #where_clause {
-            #[inline]
-            fn iter_value(&self) -> ::value_traits::iter::Iter<'_, Self> {
-                #iter::new_with_range(self.slice, self.range.clone())
-            }
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, '__iter_ref,#params> ::value_traits::iter::IterateByValueFromGat<'__iter_ref> for #subslice_impl<'__subslice_impl, #names> #where_clause {
-            type Item = <#input_ident #ty_generics as ::value_traits::slices::SliceByValue>::Value;
-            type IterFrom = #iter<'__iter_ref, #names>;
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::iter::IterateByValueFrom for #subslice_impl<'__subslice_impl, #names> #where_clause {
-            #[inline]
-            fn iter_value_from(&self, from: usize) -> ::value_traits::iter::IterFrom<'_, Self> {
-                let len = self.len();
-                assert!(from <= len, "index out of bounds: the len is {len} but the starting index is {from}");
-                let range = ::value_traits::slices::ComposeRange::compose(&(from..), self.range.clone());
-                #iter::new_with_range(self.slice, range)
-            }
-        }
-    }.into()
+        };
+    }
+    .into()
 }
 
-/// A derive macro that implements
+/// A derive macro providing
 /// [`IterateByValue`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValue.html)
 /// and
 /// [`IterateByValueFrom`](https://docs.rs/value-traits/latest/value_traits/iter/trait.IterateByValueFrom.html)
-/// for mutable subslices on top of the `<YOUR TYPE>SubsliceImplMut` structure
-/// generated by the derive macro [`SubslicesMut`].
+/// for the mutable subslices generated by the derive macro [`SubslicesMut`].
 ///
-/// To call this macro, you first need to derive both [`SubslicesMut`] and
-/// [`Iterators`] on the same struct, as this macro uses the `<YOUR TYPE>Iter`
-/// structure defined by [`Iterators`].
+/// As in the case of [`Iterators`], the mutable subslice structure
+/// [`SubsliceOfMut`](https://docs.rs/value-traits/latest/value_traits/adapters/subslice/struct.SubsliceOfMut.html)
+/// used by [`SubslicesMut`] implements the iteration traits, the marker trait
+/// [`ExactSizeIterateByValue`](https://docs.rs/value-traits/latest/value_traits/iter/trait.ExactSizeIterateByValue.html),
+/// and the inherent methods `iter_value_to` and `iter_value_range`, so this
+/// macro just checks that [`SubslicesMut`] has been derived, too.
 ///
 /// ## Additional Bounds
 ///
@@ -629,42 +509,15 @@ pub fn iterators_mut(input: TokenStream) -> TokenStream {
     add_bounds_to_where_clause(&mut input.generics, additional_bounds);
 
     let input_ident = input.ident;
-    input.generics.make_where_clause();
-    let (_impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
     let params = get_params_without_defaults(&input.generics);
-    let ty_generics_token_stream = ty_generics.clone().into_token_stream();
-
-    let names = get_names(ty_generics_token_stream);
-    let subslice_impl_mut = quote::format_ident!("{}SubsliceImplMut", input_ident);
-    let iter = quote::format_ident!("{}Iter", input_ident);
-    quote!{
-        #[automatically_derived]
-        impl<'__subslice_impl, '__iter_ref, #params> ::value_traits::iter::IterateByValueGat<'__iter_ref> for #subslice_impl_mut<'__subslice_impl, #names> #where_clause {
-            type Item = <#input_ident #ty_generics as ::value_traits::slices::SliceByValue>::Value;
-            type Iter = #iter<'__iter_ref, #names>;
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::iter::IterateByValue for #subslice_impl_mut<'__subslice_impl, #names> #where_clause {
-            fn iter_value(&self) -> ::value_traits::iter::Iter<'_, Self> {
-                #iter::new_with_range(self.slice, self.range.clone())
-            }
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, '__iter_ref, #params> ::value_traits::iter::IterateByValueFromGat<'__iter_ref> for #subslice_impl_mut<'__subslice_impl, #names> #where_clause {
-            type Item = <#input_ident #ty_generics as ::value_traits::slices::SliceByValue>::Value;
-            type IterFrom = #iter<'__iter_ref, #names>;
-        }
-
-        #[automatically_derived]
-        impl<'__subslice_impl, #params> ::value_traits::iter::IterateByValueFrom for #subslice_impl_mut<'__subslice_impl, #names> #where_clause {
-            fn iter_value_from(&self, from: usize) -> ::value_traits::iter::IterFrom<'_, Self> {
-                let len = self.len();
-                assert!(from <= len, "index out of bounds: the len is {len} but the starting index is {from}");
-                let range = ::value_traits::slices::ComposeRange::compose(&(from..), self.range.clone());
-                #iter::new_with_range(self.slice, range)
+    quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            fn __value_traits_iterators_mut<#params>(slice: &#input_ident #ty_generics) #where_clause {
+                ::value_traits::__private::assert_subslices_mut(slice);
             }
-        }
-    }.into()
+        };
+    }
+    .into()
 }
@@ -0,0 +1,20 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Accepted and rejected inputs of the derive macros.
+//!
+//! Diagnostics of the rejected inputs are compared with the `.stderr` files
+//! in `tests/ui/fail`; run with `TRYBUILD=overwrite` to regenerate them after
+//! an intentional change.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
@@ -0,0 +1,49 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! IteratorsMut needs the mutable subslices generated by SubslicesMut.
+
+use value_traits::slices::*;
+use value_traits::{Iterators, IteratorsMut, Subslices};
+
+#[derive(Subslices, Iterators, IteratorsMut)]
+pub struct Sbv<T: Copy>([T; 4]);
+
+impl<T: Copy> SliceByValue for Sbv<T> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index]
+    }
+}
+
+impl<T: Copy> SliceByValueMut for Sbv<T> {
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: T) {
+        self.0[index] = value;
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Iterators needs the subslices generated by Subslices.
+
+use value_traits::Iterators;
+use value_traits::slices::*;
+
+#[derive(Iterators)]
+pub struct Sbv<T: Copy>([T; 4]);
+
+impl<T: Copy> SliceByValue for Sbv<T> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Additional bounds for generic parameters not implied by the declaration.
+
+use value_traits::slices::*;
+use value_traits::{Iterators, IteratorsMut, Subslices, SubslicesMut};
+
+#[derive(Subslices, Iterators, SubslicesMut, IteratorsMut)]
+#[value_traits_subslices(bound = "T: Copy")]
+#[value_traits_iterators(bound = "T: Copy")]
+#[value_traits_subslices_mut(bound = "T: Copy")]
+#[value_traits_iterators_mut(bound = "T: Copy")]
+pub struct Sbv<T>([T; 4]);
+
+impl<T: Copy> SliceByValue for Sbv<T> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index]
+    }
+}
+
+impl<T: Copy> SliceByValueMut for Sbv<T> {
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: T) {
+        self.0[index] = value;
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+fn main() {
+    let s = Sbv([1, 2, 3, 4]);
+    assert_eq!(s.index_subslice(1..3).len(), 2);
+}
@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! All derive macros on a generic struct with a default type parameter.
+
+use value_traits::iter::*;
+use value_traits::slices::*;
+use value_traits::{Iterators, IteratorsMut, Subslices, SubslicesMut};
+
+#[derive(Subslices, Iterators, SubslicesMut, IteratorsMut)]
+pub struct Sbv<T: Copy = usize>([T; 4]);
+
+impl<T: Copy> SliceByValue for Sbv<T> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index]
+    }
+}
+
+impl<T: Copy> SliceByValueMut for Sbv<T> {
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: T) {
+        self.0[index] = value;
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+fn main() {
+    let mut s = Sbv([1_usize, 2, 3, 4]);
+    assert_eq!(s.index_subslice(1..).index_subslice(1..).index_value(0), 3);
+    assert_eq!(s.index_subslice(..2).iter_value().sum::<usize>(), 3);
+    s.index_subslice_mut(2..).set_value(0, 0);
+    assert_eq!(s.index_subslice_mut(..).iter_value_from(2).next(), Some(0));
+}
@@ -0,0 +1,41 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Enums are accepted, as the macros do not look at the data.
+
+use value_traits::slices::*;
+use value_traits::{Iterators, Subslices};
+
+#[derive(Subslices, Iterators)]
+pub enum Sbv {
+    Empty,
+    Constant(usize, u8),
+}
+
+impl SliceByValue for Sbv {
+    type Value = u8;
+
+    fn len(&self) -> usize {
+        match self {
+            Sbv::Empty => 0,
+            Sbv::Constant(len, _) => *len,
+        }
+    }
+
+    unsafe fn get_value_unchecked(&self, _index: usize) -> u8 {
+        match self {
+            Sbv::Empty => unreachable!(),
+            Sbv::Constant(_, value) => *value,
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(Sbv::Constant(3, 7).index_subslice(1..).index_value(1), 7);
+    assert!(Sbv::Empty.index_subslice(..).is_empty());
+}
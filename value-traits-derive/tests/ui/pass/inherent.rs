@@ -0,0 +1,61 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Inherent subslicing methods, usable without importing the subslice traits.
+
+use value_traits::slices::{SliceByValue, SliceByValueMut};
+use value_traits::{Iterators, IteratorsMut, Subslices, SubslicesMut};
+
+#[derive(Subslices, Iterators, SubslicesMut, IteratorsMut)]
+#[value_traits_subslices(inherent, bound = "T: Copy")]
+#[value_traits_iterators(bound = "T: Copy")]
+#[value_traits_subslices_mut(inherent, bound = "T: Copy")]
+#[value_traits_iterators_mut(bound = "T: Copy")]
+pub struct Values<T>(Vec<T>);
+
+impl<T: Copy> SliceByValue for Values<T> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index]
+    }
+}
+
+impl<T: Copy> SliceByValueMut for Values<T> {
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: T) {
+        self.0[index] = value;
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = value_traits::slices::ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Err(value_traits::slices::ChunksMutNotSupported)
+    }
+}
+
+fn main() {
+    let mut v = Values(vec![1, 2, 3, 4, 5]);
+    assert_eq!(v.subslice(1..3), [2, 3]);
+    assert_eq!(v.subslice(..=1), [1, 2]);
+    assert_eq!(v.subslice(3..).len(), 2);
+    let mut s = v.subslice_mut(2..);
+    s.set_value(0, 10);
+    assert_eq!(v.subslice(..), [1, 2, 10, 4, 5]);
+}
@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Lifetime and const parameters are threaded through the generated items.
+
+use value_traits::iter::*;
+use value_traits::slices::*;
+use value_traits::{Iterators, IteratorsMut, Subslices, SubslicesMut};
+
+#[derive(Subslices, Iterators, SubslicesMut, IteratorsMut)]
+pub struct View<'a, T: Copy, const W: usize = 1>(&'a mut [T]);
+
+impl<T: Copy, const W: usize> SliceByValue for View<'_, T, W> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len() / W
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index * W]
+    }
+}
+
+impl<T: Copy, const W: usize> SliceByValueMut for View<'_, T, W> {
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: T) {
+        self.0[index * W] = value;
+    }
+
+    type ChunksMut<'b>
+        = core::iter::Empty<&'b mut Self>
+    where
+        Self: 'b;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+fn main() {
+    let mut data = [0, 1, 2, 3];
+    let mut v = View::<_, 2>(&mut data);
+    assert_eq!(v.index_subslice(1..), [2]);
+    v.index_subslice_mut(..).set_value(1, 5);
+    assert_eq!(v.index_subslice_mut(..).iter_value().sum::<i32>(), 5);
+}
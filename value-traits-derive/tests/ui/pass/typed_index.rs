@@ -0,0 +1,49 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Accessors taking a newtype index.
+
+use value_traits::slices::*;
+use value_traits::{Iterators, Subslices};
+
+#[derive(Clone, Copy)]
+pub struct NodeId(u32);
+
+impl From<NodeId> for usize {
+    fn from(id: NodeId) -> usize {
+        id.0 as usize
+    }
+}
+
+#[derive(Subslices, Iterators)]
+#[value_traits_subslices(index = "NodeId", bound = "T: Copy")]
+#[value_traits_iterators(bound = "T: Copy")]
+pub struct Degrees<T>(Vec<T>);
+
+impl<T: Copy> SliceByValue for Degrees<T> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index]
+    }
+}
+
+fn main() {
+    let d = Degrees(vec![3, 1, 4, 1, 5]);
+    assert_eq!(d.index_value_at(NodeId(2)), 4);
+    assert_eq!(d.get_value_at(NodeId(5)), None);
+    let s = d.index_subslice_at(NodeId(1)..NodeId(4));
+    assert_eq!(s, [1, 4, 1]);
+    assert!(d.get_subslice_at(NodeId(3)..NodeId(6)).is_none());
+    // The usize-based traits are still available
+    assert_eq!(d.index_value(0), 3);
+}
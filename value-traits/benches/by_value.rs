@@ -0,0 +1,190 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Benchmarks comparing by-value access with direct access to standard
+//! slices.
+//!
+//! Every group contains a `std` baseline using `&[T]` directly, a `by_value`
+//! benchmark using the by-value traits on the same slice, and a `derived`
+//! benchmark using a wrapper whose subslices and iterators are generated by
+//! the derive macros. The first two should be indistinguishable.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+use value_traits::{Iterators, IteratorsMut, Subslices, SubslicesMut, iter::*, slices::*};
+
+const LEN: usize = 1 << 16;
+
+/// A vector wrapper using the derived subslices and iterators.
+#[derive(Subslices, Iterators, SubslicesMut, IteratorsMut)]
+pub struct Wrapper(Vec<u64>);
+
+impl SliceByValue for Wrapper {
+    type Value = u64;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    unsafe fn get_value_unchecked(&self, index: usize) -> u64 {
+        unsafe { *self.0.get_unchecked(index) }
+    }
+}
+
+impl SliceByValueMut for Wrapper {
+    #[inline(always)]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: u64) {
+        unsafe { *self.0.get_unchecked_mut(index) = value };
+    }
+
+    type ChunksMut<'a> = core::iter::Empty<&'a mut [u64]>;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+fn data() -> Vec<u64> {
+    (0..LEN as u64).collect()
+}
+
+/// Pseudorandom indices, so that access cannot be vectorized.
+fn indices() -> Vec<usize> {
+    let mut x = 0x9E37_79B9_7F4A_7C15_u64;
+    (0..LEN)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            (x % LEN as u64) as usize
+        })
+        .collect()
+}
+
+fn sum_index<S: SliceByValue<Value = u64>>(s: &S, indices: &[usize]) -> u64 {
+    indices.iter().map(|&i| s.index_value(i)).sum()
+}
+
+fn sum_iter<S: IterateByValue + for<'a> IterateByValueGat<'a, Item = u64>>(s: &S) -> u64 {
+    s.iter_value().sum()
+}
+
+fn bench_index_value(c: &mut Criterion) {
+    let v = data();
+    let w = Wrapper(data());
+    let indices = indices();
+    let mut group = c.benchmark_group("index_value");
+    group.bench_function("std", |b| {
+        b.iter(|| black_box(indices.iter().map(|&i| v[i]).sum::<u64>()))
+    });
+    group.bench_function("by_value", |b| {
+        b.iter(|| black_box(sum_index(&v.as_slice(), &indices)))
+    });
+    group.bench_function("derived", |b| {
+        b.iter(|| black_box(sum_index(&w.index_subslice(..), &indices)))
+    });
+    group.finish();
+}
+
+fn bench_iter(c: &mut Criterion) {
+    let v = data();
+    let w = Wrapper(data());
+    let mut group = c.benchmark_group("iter_value");
+    group.bench_function("std", |b| {
+        b.iter(|| black_box(v.iter().copied().sum::<u64>()))
+    });
+    group.bench_function("by_value", |b| {
+        b.iter(|| black_box(sum_iter(&v.as_slice())))
+    });
+    group.bench_function("derived", |b| {
+        b.iter(|| black_box(sum_iter(&w.index_subslice(..))))
+    });
+    group.finish();
+}
+
+/// Recursion is necessary as derived subslices borrow their parent, but
+/// subslices of derived subslices have the same type.
+fn derived_chain(s: &Subslice<'_, Wrapper>, depth: usize) -> u64 {
+    if depth == 0 {
+        sum_iter(s)
+    } else {
+        derived_chain(&s.index_subslice(1..s.len() - 1), depth - 1)
+    }
+}
+
+fn bench_subslice_chain(c: &mut Criterion) {
+    let v = data();
+    let w = Wrapper(data());
+    let mut group = c.benchmark_group("subslice_chain");
+    for depth in [1, 4, 16] {
+        group.bench_with_input(BenchmarkId::new("std", depth), &depth, |b, &depth| {
+            b.iter(|| {
+                let mut s = v.as_slice();
+                for _ in 0..depth {
+                    s = &s[1..s.len() - 1];
+                }
+                black_box(s.iter().copied().sum::<u64>())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("by_value", depth), &depth, |b, &depth| {
+            b.iter(|| {
+                let mut s = v.index_subslice(..);
+                for _ in 0..depth {
+                    s = s.index_subslice(1..s.len() - 1);
+                }
+                black_box(sum_iter(&s))
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("derived", depth), &depth, |b, &depth| {
+            b.iter(|| black_box(derived_chain(&w.index_subslice(..), depth)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_apply_in_place(c: &mut Criterion) {
+    let mut v = data();
+    let mut w = Wrapper(data());
+    let mut group = c.benchmark_group("apply_in_place");
+    group.bench_function("std", |b| {
+        b.iter(|| {
+            v.iter_mut().for_each(|x| *x = x.wrapping_mul(3));
+            black_box(&v);
+        })
+    });
+    group.bench_function("by_value", |b| {
+        b.iter(|| {
+            v.as_mut_slice().apply_in_place(|x| x.wrapping_mul(3));
+            black_box(&v);
+        })
+    });
+    group.bench_function("derived", |b| {
+        b.iter(|| {
+            w.index_subslice_mut(..)
+                .apply_in_place(|x| x.wrapping_mul(3));
+            black_box(&w.0);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_index_value,
+    bench_iter,
+    bench_subslice_chain,
+    bench_apply_in_place
+);
+criterion_main!(benches);
@@ -0,0 +1,170 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! By-value slices materializing a standard iterator on demand.
+//!
+//! A [`BufferedSlice`] bridges streaming producers with random-access
+//! consumers: it wraps a standard iterator, and consumes it into an internal
+//! growable buffer only when [`ensure`](BufferedSlice::ensure) is called.
+//! The by-value traits expose the prefix that has been materialized so far:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::buffered::BufferedSlice;
+//! use value_traits::slices::*;
+//!
+//! let mut squares = BufferedSlice::from_iter((0..).map(|i| i * i));
+//! assert_eq!(squares.len(), 0);
+//! assert_eq!(squares.ensure(10), 10);
+//! assert_eq!(squares.len(), 10);
+//! assert_eq!(squares.index_value(7), 49);
+//! assert_eq!(squares.get_value(10), None);
+//!
+//! let mut short = BufferedSlice::from_iter([1, 2, 3]);
+//! assert_eq!(short.ensure(5), 3);
+//! assert!(short.is_exhausted());
+//! # }
+//! ```
+//!
+//! Since values are appended to a vector, the amortized cost of
+//! materializing a value is constant.
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::iter::{Cloned, Fuse, Skip};
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::SliceByValue,
+};
+
+/// A read-only by-value slice exposing the values of an iterator that
+/// have been materialized so far.
+///
+/// See the [module documentation](self).
+pub struct BufferedSlice<I: Iterator> {
+    buffer: Vec<I::Item>,
+    iter: Fuse<I>,
+    exhausted: bool,
+}
+
+impl<I: Iterator> BufferedSlice<I> {
+    /// Creates a new slice consuming the given iterator on demand.
+    ///
+    /// No value is consumed until [`ensure`](BufferedSlice::ensure) is
+    /// called.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter<J: IntoIterator<IntoIter = I>>(iter: J) -> Self {
+        Self {
+            buffer: Vec::new(),
+            iter: iter.into_iter().fuse(),
+            exhausted: false,
+        }
+    }
+
+    /// Consumes the iterator until at least `len` values have been
+    /// materialized, or the iterator is exhausted, and returns the length of
+    /// the slice, which is the minimum between `len` and the number of
+    /// values returned by the iterator.
+    pub fn ensure(&mut self, len: usize) -> usize {
+        let missing = len.saturating_sub(self.buffer.len());
+        if missing != 0 && !self.exhausted {
+            self.buffer.extend(self.iter.by_ref().take(missing));
+            self.exhausted = self.buffer.len() < len;
+        }
+        self.buffer.len()
+    }
+
+    /// Consumes the whole iterator and returns the length of the slice.
+    ///
+    /// This method will not terminate if the iterator is infinite.
+    pub fn ensure_all(&mut self) -> usize {
+        if !self.exhausted {
+            self.buffer.extend(&mut self.iter);
+            self.exhausted = true;
+        }
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the iterator has been exhausted, that is, if all its
+    /// values have been materialized.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Returns the values materialized so far as a standard slice.
+    pub fn as_slice(&self) -> &[I::Item] {
+        &self.buffer
+    }
+
+    /// Returns the values materialized so far and the iterator, which will
+    /// return the remaining values.
+    pub fn into_parts(self) -> (Vec<I::Item>, Fuse<I>) {
+        (self.buffer, self.iter)
+    }
+}
+
+impl<I: Iterator<Item: core::fmt::Debug>> core::fmt::Debug for BufferedSlice<I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BufferedSlice")
+            .field("buffer", &self.buffer)
+            .field("exhausted", &self.exhausted)
+            .finish()
+    }
+}
+
+impl<I: Iterator<Item: Clone>> SliceByValue for BufferedSlice<I> {
+    type Value = I::Item;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.buffer.get(index).cloned()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.buffer.get_unchecked(index).clone() }
+    }
+}
+
+impl<'a, I: Iterator<Item: Clone>> IterateByValueGat<'a> for BufferedSlice<I> {
+    type Item = I::Item;
+    type Iter = Cloned<core::slice::Iter<'a, I::Item>>;
+}
+
+impl<I: Iterator<Item: Clone>> IterateByValue for BufferedSlice<I> {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.buffer.iter().cloned()
+    }
+}
+
+impl<'a, I: Iterator<Item: Clone>> IterateByValueFromGat<'a> for BufferedSlice<I> {
+    type Item = I::Item;
+    type IterFrom = Cloned<Skip<core::slice::Iter<'a, I::Item>>>;
+}
+
+impl<I: Iterator<Item: Clone>> IterateByValueFrom for BufferedSlice<I> {
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.buffer.iter().skip(from).cloned()
+    }
+}
+
+impl<I: Iterator<Item: Clone>> ExactSizeIterateByValue for BufferedSlice<I> {}
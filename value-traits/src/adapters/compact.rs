@@ -0,0 +1,363 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Compact representations of by-value slices, chosen at runtime.
+//!
+//! This module provides two read-only representations in addition to
+//! vectors: [`RunLength`], which stores runs of equal values, and
+//! [`Sparse`], which stores only the values different from a default value.
+//!
+//! The [`compact`] function scans a by-value slice, estimates the space
+//! used by each representation, and returns a [`Compacted`] slice using the
+//! smallest one. Since [`Compacted`] implements the read-only by-value
+//! traits, code using it does not depend on the representation:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::compact::{compact, Compacted};
+//! use value_traits::slices::*;
+//!
+//! let runs = compact(&[1, 1, 1, 1, 2, 2, 2, 2, 2, 2]);
+//! assert!(matches!(runs, Compacted::RunLength(_)));
+//! let sparse = compact(&[0, 0, 5, 0, 0, 0, 7, 0, 0, 0]);
+//! assert!(matches!(sparse, Compacted::Sparse(_)));
+//! let plain = compact(&[3, 1, 4, 1, 5]);
+//! assert!(matches!(plain, Compacted::Plain(_)));
+//!
+//! assert_eq!(runs.index_value(5), 2);
+//! assert_eq!(sparse.index_value(6), 7);
+//! assert_eq!(plain.index_value(2), 4);
+//! # }
+//! ```
+//!
+//! The [`compact_boxed`] function returns instead a boxed
+//! [`SliceByValue`] trait object, so representations can be exchanged
+//! behind dynamic dispatch, for example together with other backends:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::compact::compact_boxed;
+//! use value_traits::slices::*;
+//!
+//! let slices: Vec<Box<dyn SliceByValue<Value = i32>>> = vec![
+//!     compact_boxed(&[0, 0, 5, 0, 0, 0, 7, 0, 0, 0]),
+//!     Box::new(vec![3, 1, 4]),
+//! ];
+//! assert_eq!(slices[0].index_value(2), 5);
+//! assert_eq!(slices[1].len(), 3);
+//! # }
+//! ```
+//!
+//! Access to a [`RunLength`] or a [`Sparse`] slice uses a binary search, so
+//! it takes time logarithmic in the number of runs or of stored values.
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    adapters::subslice::{SubsliceIter, SubsliceOf, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::assert_from,
+    slices::{SliceByValue, SliceByValueSubsliceGat},
+};
+
+/// A read-only by-value slice storing runs of equal values.
+///
+/// Run-length slices can be created from iterators.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RunLength<V> {
+    /// The (exclusive) end of each run.
+    ends: Vec<usize>,
+    /// The value of each run.
+    values: Vec<V>,
+}
+
+impl<V> RunLength<V> {
+    /// Returns the number of runs.
+    #[inline]
+    pub fn num_runs(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<V: PartialEq> FromIterator<V> for RunLength<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut ends: Vec<usize> = Vec::new();
+        let mut values = Vec::new();
+        let mut len = 0;
+        for value in iter {
+            len += 1;
+            if values.last() == Some(&value) {
+                *ends.last_mut().unwrap() = len;
+            } else {
+                ends.push(len);
+                values.push(value);
+            }
+        }
+        Self { ends, values }
+    }
+}
+
+impl<V: Clone> SliceByValue for RunLength<V> {
+    type Value = V;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> V {
+        let run = self.ends.partition_point(|&end| end <= index);
+        // SAFETY: index is within bounds, so it is smaller than the last end
+        unsafe { self.values.get_unchecked(run) }.clone()
+    }
+}
+
+/// A read-only by-value slice storing only the values different from a
+/// default value.
+///
+/// Sparse slices can be created with
+/// [`with_default`](Sparse::with_default).
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sparse<V> {
+    len: usize,
+    default: V,
+    /// The positions of the stored values, in increasing order.
+    positions: Vec<usize>,
+    values: Vec<V>,
+}
+
+impl<V: PartialEq> Sparse<V> {
+    /// Creates a new sparse slice containing the values returned by an
+    /// iterator, storing only those different from `default`.
+    pub fn with_default(default: V, iter: impl IntoIterator<Item = V>) -> Self {
+        let mut len = 0;
+        let mut positions = Vec::new();
+        let mut values = Vec::new();
+        for value in iter {
+            if value != default {
+                positions.push(len);
+                values.push(value);
+            }
+            len += 1;
+        }
+        Self {
+            len,
+            default,
+            positions,
+            values,
+        }
+    }
+}
+
+impl<V> Sparse<V> {
+    /// Returns the default value.
+    #[inline]
+    pub fn default_value(&self) -> &V {
+        &self.default
+    }
+
+    /// Returns the number of values different from the default value.
+    #[inline]
+    pub fn num_entries(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<V: Clone> SliceByValue for Sparse<V> {
+    type Value = V;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> V {
+        match self.positions.binary_search(&index) {
+            // SAFETY: positions and values have the same length
+            Ok(entry) => unsafe { self.values.get_unchecked(entry) }.clone(),
+            Err(_) => self.default.clone(),
+        }
+    }
+}
+
+/// A read-only by-value slice whose representation is chosen at runtime.
+///
+/// Usually returned by [`compact`]; see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Compacted<V> {
+    /// A vector of values.
+    Plain(Vec<V>),
+    /// A slice storing runs of equal values.
+    RunLength(RunLength<V>),
+    /// A slice storing only the values different from a default value.
+    Sparse(Sparse<V>),
+}
+
+impl<V: Clone> SliceByValue for Compacted<V> {
+    type Value = V;
+
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Compacted::Plain(v) => v.len(),
+            Compacted::RunLength(r) => r.len(),
+            Compacted::Sparse(s) => s.len(),
+        }
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> V {
+        // SAFETY: index is within bounds
+        unsafe {
+            match self {
+                Compacted::Plain(v) => v.get_unchecked(index).clone(),
+                Compacted::RunLength(r) => r.get_value_unchecked(index),
+                Compacted::Sparse(s) => s.get_value_unchecked(index),
+            }
+        }
+    }
+}
+
+impl<V: Clone> Compacted<V> {
+    /// Returns the representation as a boxed [`SliceByValue`] trait object.
+    pub fn into_boxed<'a>(self) -> Box<dyn SliceByValue<Value = V> + 'a>
+    where
+        V: 'a,
+    {
+        match self {
+            Compacted::Plain(v) => Box::new(v),
+            Compacted::RunLength(r) => Box::new(r),
+            Compacted::Sparse(s) => Box::new(s),
+        }
+    }
+}
+
+/// Rebuilds a by-value slice using the representation, among those
+/// of [`Compacted`], with the smallest estimated space usage.
+///
+/// The estimate takes into account only the size of the values and of the
+/// positions stored, and ties are broken in favor of the simplest
+/// representation. The default value of a [`Sparse`] representation is the
+/// majority value of the slice, if any.
+///
+/// The slice is scanned three times.
+///
+/// See the [module documentation](self) for an example.
+pub fn compact<S>(slice: &S) -> Compacted<S::Value>
+where
+    S: SliceByValue<Value: Clone + PartialEq> + ?Sized,
+{
+    let len = slice.len();
+    // SAFETY: all indices are within bounds
+    let values = || (0..len).map(|i| unsafe { slice.get_value_unchecked(i) });
+
+    // Count the runs and find a majority candidate (Boyer–Moore)
+    let mut runs = 0;
+    let mut prev = None;
+    let mut candidate = None;
+    let mut count = 0_usize;
+    for value in values() {
+        if prev.as_ref() != Some(&value) {
+            runs += 1;
+        }
+        if count == 0 {
+            candidate = Some(value.clone());
+            count = 1;
+        } else if candidate.as_ref() == Some(&value) {
+            count += 1;
+        } else {
+            count -= 1;
+        }
+        prev = Some(value);
+    }
+
+    let Some(candidate) = candidate else {
+        return Compacted::Plain(Vec::new());
+    };
+    let occurrences = values().filter(|value| *value == candidate).count();
+
+    let value_size = size_of::<S::Value>();
+    let entry_size = value_size + size_of::<usize>();
+    let plain = len * value_size;
+    let run_length = runs * entry_size;
+    let sparse = (len - occurrences) * entry_size + value_size;
+
+    if plain <= run_length && plain <= sparse {
+        Compacted::Plain(values().collect())
+    } else if run_length <= sparse {
+        Compacted::RunLength(values().collect())
+    } else {
+        Compacted::Sparse(Sparse::with_default(candidate, values()))
+    }
+}
+
+/// Rebuilds a by-value slice as [`compact`] does, and returns the result
+/// as a boxed [`SliceByValue`] trait object.
+///
+/// See the [module documentation](self) for an example.
+pub fn compact_boxed<'a, S>(slice: &S) -> Box<dyn SliceByValue<Value = S::Value> + 'a>
+where
+    S: SliceByValue<Value: Clone + PartialEq + 'a> + ?Sized,
+{
+    compact(slice).into_boxed()
+}
+
+macro_rules! impl_compact {
+    ($ty:ident) => {
+        impl<'a, V: Clone> SliceByValueSubsliceGat<'a> for $ty<V> {
+            type Subslice = SubsliceOf<'a, Self>;
+        }
+
+        impl_subslice_of_ranges!($ty<V>, V: Clone);
+
+        impl<'a, V: Clone> IterateByValueGat<'a> for $ty<V> {
+            type Item = V;
+            type Iter = SubsliceIter<'a, Self>;
+        }
+
+        impl<V: Clone> IterateByValue for $ty<V> {
+            fn iter_value(&self) -> Iter<'_, Self> {
+                SubsliceIter::new(self, 0..self.len())
+            }
+        }
+
+        impl<'a, V: Clone> IterateByValueFromGat<'a> for $ty<V> {
+            type Item = V;
+            type IterFrom = SubsliceIter<'a, Self>;
+        }
+
+        impl<V: Clone> IterateByValueFrom for $ty<V> {
+            /// # Panics
+            ///
+            /// This method will panic if `from` is greater than the length of
+            /// the slice.
+            fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+                assert_from(from, self.len());
+                SubsliceIter::new(self, from..self.len())
+            }
+        }
+
+        impl<V: Clone> ExactSizeIterateByValue for $ty<V> {}
+    };
+}
+
+impl_compact!(RunLength);
+impl_compact!(Sparse);
+impl_compact!(Compacted);
@@ -0,0 +1,211 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Clone-on-write subslices.
+//!
+//! A [`SubsliceCow`] is either a borrowed subslice of a by-value slice or an
+//! owned vector of values, in the spirit of
+//! [`Cow`](https://doc.rust-lang.org/std/borrow/enum.Cow.html). Algorithms
+//! that usually pass windows of a slice through unchanged, but occasionally
+//! need to patch them, can return a [`SubsliceCow`] and copy values only when
+//! [`to_mut`](SubsliceCow::to_mut) is called.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::cow::SubsliceCow;
+//! use value_traits::slices::*;
+//!
+//! let v = vec![1, 2, 3, 4, 5];
+//! let mut window = SubsliceCow::<Vec<i32>>::Borrowed(v.index_subslice(1..4));
+//! assert!(window.is_borrowed());
+//! assert_eq!(window.index_value(0), 2);
+//!
+//! window.to_mut()[0] = 0;
+//! assert!(window.is_owned());
+//! assert_eq!(window.index_value(0), 0);
+//! // The original slice is untouched
+//! assert_eq!(v[1], 2);
+//! # }
+//! ```
+//!
+//! This adapter is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::{
+    adapters::subslice::{SubsliceIter, SubsliceOf, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::assert_from,
+    slices::{SliceByValue, SliceByValueSubsliceGat, Subslice},
+};
+
+/// A subslice of `S` that is either borrowed or owned.
+///
+/// See the [module documentation](self).
+pub enum SubsliceCow<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> {
+    /// A borrowed subslice.
+    Borrowed(Subslice<'a, S>),
+    /// An owned vector of values.
+    Owned(Vec<S::Value>),
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> SubsliceCow<'a, S> {
+    /// Returns true if the data is borrowed.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Returns true if the data is owned.
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Returns a mutable reference to the owned values, copying the values of
+    /// the borrowed subslice into a new vector first if necessary.
+    pub fn to_mut(&mut self) -> &mut Vec<S::Value> {
+        if let Self::Borrowed(subslice) = self {
+            *self = Self::Owned(to_vec(subslice));
+        }
+        match self {
+            Self::Owned(v) => v,
+            Self::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the owned values, copying the values of the borrowed subslice
+    /// into a new vector if necessary.
+    pub fn into_owned(self) -> Vec<S::Value> {
+        match self {
+            Self::Borrowed(subslice) => to_vec(&subslice),
+            Self::Owned(v) => v,
+        }
+    }
+}
+
+fn to_vec<S: SliceByValue>(s: &S) -> Vec<S::Value> {
+    (0..s.len())
+        // SAFETY: i is within bounds
+        .map(|i| unsafe { s.get_value_unchecked(i) })
+        .collect()
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> Clone for SubsliceCow<'a, S>
+where
+    Subslice<'a, S>: Clone,
+    S::Value: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Borrowed(subslice) => Self::Borrowed(subslice.clone()),
+            Self::Owned(v) => Self::Owned(v.clone()),
+        }
+    }
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> core::fmt::Debug for SubsliceCow<'a, S>
+where
+    Subslice<'a, S>: core::fmt::Debug,
+    S::Value: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Borrowed(subslice) => f.debug_tuple("Borrowed").field(subslice).finish(),
+            Self::Owned(v) => f.debug_tuple("Owned").field(v).finish(),
+        }
+    }
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> SliceByValue for SubsliceCow<'a, S>
+where
+    S::Value: Clone,
+{
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Self::Borrowed(subslice) => subslice.len(),
+            Self::Owned(v) => v.len(),
+        }
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        match self {
+            // SAFETY: index is within bounds
+            Self::Borrowed(subslice) => unsafe { subslice.get_value_unchecked(index) },
+            // SAFETY: index is within bounds
+            Self::Owned(v) => unsafe { v.get_unchecked(index).clone() },
+        }
+    }
+}
+
+impl<'a, 'b, S: SliceByValueSubsliceGat<'a> + ?Sized> SliceByValueSubsliceGat<'b>
+    for SubsliceCow<'a, S>
+where
+    S::Value: Clone,
+{
+    type Subslice = SubsliceOf<'b, Self>;
+}
+
+impl_subslice_of_ranges!(
+    SubsliceCow<'a, S>,
+    'a,
+    S: SliceByValueSubsliceGat<'a, Value: Clone> + ?Sized
+);
+
+impl<'a, 'b, S: SliceByValueSubsliceGat<'a> + ?Sized> IterateByValueGat<'b> for SubsliceCow<'a, S>
+where
+    S::Value: Clone,
+{
+    type Item = S::Value;
+    type Iter = SubsliceIter<'b, Self>;
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> IterateByValue for SubsliceCow<'a, S>
+where
+    S::Value: Clone,
+{
+    fn iter_value(&self) -> Iter<'_, Self> {
+        SubsliceIter::new(self, 0..self.len())
+    }
+}
+
+impl<'a, 'b, S: SliceByValueSubsliceGat<'a> + ?Sized> IterateByValueFromGat<'b>
+    for SubsliceCow<'a, S>
+where
+    S::Value: Clone,
+{
+    type Item = S::Value;
+    type IterFrom = SubsliceIter<'b, Self>;
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> IterateByValueFrom for SubsliceCow<'a, S>
+where
+    S::Value: Clone,
+{
+    /// # Panics
+    ///
+    /// This method will panic if `from` is greater than the length of the
+    /// slice.
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        assert_from(from, self.len());
+        SubsliceIter::new(self, from..self.len())
+    }
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> ExactSizeIterateByValue for SubsliceCow<'a, S> where
+    S::Value: Clone
+{
+}
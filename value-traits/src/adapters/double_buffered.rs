@@ -0,0 +1,212 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Double-buffered mutable by-value slices.
+//!
+//! A [`DoubleBuffered`] holds two mutable by-value slices of the same
+//! length: a front slice, from which all values are read, and a back slice,
+//! to which all values are written. After a generation of values has been
+//! written, [`swap`](DoubleBuffered::swap) exchanges the two slices, making
+//! the new generation readable. This is the usual structure of iterative
+//! algorithms, such as label propagation or cellular automata, that must
+//! read the previous generation while writing the next one, and it makes it
+//! possible to use packed storage for both generations:
+//!
+//! ```rust
+//! use value_traits::adapters::double_buffered::DoubleBuffered;
+//! use value_traits::slices::*;
+//!
+//! // Each value becomes the maximum of itself and its neighbors
+//! let mut d = DoubleBuffered::new([0, 0, 5, 0, 0, 0], [0; 6]);
+//! for _ in 0..2 {
+//!     for i in 0..d.len() {
+//!         let left = d.index_value(i.saturating_sub(1));
+//!         let right = d.index_value((i + 1).min(d.len() - 1));
+//!         let max = d.index_value(i).max(left).max(right);
+//!         d.set_value(i, max);
+//!     }
+//!     d.swap();
+//! }
+//! assert_eq!(d.front(), &[5, 5, 5, 5, 5, 0]);
+//! ```
+//!
+//! All by-value traits follow the same convention: subslices and iterators
+//! read from the front slice, and mutable subslices write to the back slice.
+//! Since mutable chunks of the back slice would not be readable through the
+//! read traits, [`try_chunks_mut`](SliceByValueMut::try_chunks_mut) is not
+//! supported.
+
+use crate::{
+    adapters::subslice::{SubsliceOf, SubsliceOfMut, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::check_eq,
+    slices::{
+        ChunksMutNotSupported, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut,
+    },
+};
+
+/// A pair of mutable by-value slices, reading from the front slice and
+/// writing to the back slice.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct DoubleBuffered<S> {
+    front: S,
+    back: S,
+}
+
+impl<S: SliceByValueMut> DoubleBuffered<S> {
+    /// Creates a new double-buffered slice from the given front and back
+    /// slices.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the two slices have different lengths.
+    pub fn new(front: S, back: S) -> Self {
+        check_eq!(
+            front.len(),
+            back.len(),
+            "the front and back slices have different lengths",
+            "the front and back slices have different lengths"
+        );
+        Self { front, back }
+    }
+
+    /// Returns a shared reference to the front slice.
+    pub fn front(&self) -> &S {
+        &self.front
+    }
+
+    /// Returns a shared reference to the back slice.
+    pub fn back(&self) -> &S {
+        &self.back
+    }
+
+    /// Swaps the front and back slices, making readable the values written
+    /// since the last swap.
+    ///
+    /// The swap takes constant time, as only the slices are moved.
+    #[inline]
+    pub fn swap(&mut self) {
+        core::mem::swap(&mut self.front, &mut self.back);
+    }
+
+    /// Copies all values of the front slice to the back slice.
+    ///
+    /// This method is useful when an iteration writes only some of the
+    /// values of the back slice.
+    pub fn copy_front_to_back(&mut self) {
+        for i in 0..self.front.len() {
+            // SAFETY: i is within bounds, and the slices have the same length
+            unsafe {
+                self.back
+                    .set_value_unchecked(i, self.front.get_value_unchecked(i))
+            };
+        }
+    }
+
+    /// Returns the front and back slices.
+    pub fn into_parts(self) -> (S, S) {
+        (self.front, self.back)
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValue for DoubleBuffered<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.front.len()
+    }
+
+    /// Returns a value of the front slice.
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.front.get_value_unchecked(index) }
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValueMut for DoubleBuffered<S> {
+    /// Sets a value of the back slice.
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds, and the slices have the same length
+        unsafe { self.back.set_value_unchecked(index, value) }
+    }
+
+    /// Replaces a value of the back slice, returning the previous value of
+    /// the back slice.
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds, and the slices have the same length
+        unsafe { self.back.replace_value_unchecked(index, value) }
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    /// Returns always an error, as mutable chunks of the back slice would
+    /// not be readable through the read traits.
+    ///
+    /// # Errors
+    ///
+    /// Returns always [`ChunksMutNotSupported`].
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+impl<'a, S: SliceByValueMut> SliceByValueSubsliceGat<'a> for DoubleBuffered<S> {
+    type Subslice = SubsliceOf<'a, Self>;
+}
+
+impl<'a, S: SliceByValueMut> SliceByValueSubsliceGatMut<'a> for DoubleBuffered<S> {
+    type SubsliceMut = SubsliceOfMut<'a, Self>;
+}
+
+impl_subslice_of_ranges!(mut DoubleBuffered<S>, S: SliceByValueMut);
+
+impl<'a, S: SliceByValueMut + IterateByValueGat<'a>> IterateByValueGat<'a> for DoubleBuffered<S> {
+    type Item = <S as IterateByValueGat<'a>>::Item;
+    type Iter = <S as IterateByValueGat<'a>>::Iter;
+}
+
+impl<S: SliceByValueMut + IterateByValue> IterateByValue for DoubleBuffered<S> {
+    #[inline]
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.front.iter_value()
+    }
+}
+
+impl<'a, S: SliceByValueMut + IterateByValueFromGat<'a>> IterateByValueFromGat<'a>
+    for DoubleBuffered<S>
+{
+    type Item = <S as IterateByValueFromGat<'a>>::Item;
+    type IterFrom = <S as IterateByValueFromGat<'a>>::IterFrom;
+}
+
+impl<S: SliceByValueMut + IterateByValueFrom> IterateByValueFrom for DoubleBuffered<S> {
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.front.iter_value_from(from)
+    }
+}
+
+impl<S: SliceByValueMut + ExactSizeIterateByValue> ExactSizeIterateByValue for DoubleBuffered<S> {}
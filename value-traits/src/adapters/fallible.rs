@@ -0,0 +1,119 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Adapters for fallible iteration.
+//!
+//! [`Fallible`] makes it possible to pass an infallible by-value iterable
+//! to code expecting a [`TryIterateByValue`]: its iterator wraps the values
+//! in [`Ok`], and its error type is [`Infallible`]. See
+//! [`TryIterateByValue`] for an example.
+
+use core::convert::Infallible;
+
+use crate::{
+    iter::{
+        Iter, IterateByValue, IterateByValueGat, TryIter, TryIterateByValue, TryIterateByValueGat,
+    },
+    slices::SliceByValue,
+};
+
+/// An adapter exposing an infallible by-value iterable as a
+/// [`TryIterateByValue`] whose error type is [`Infallible`].
+///
+/// The adapter implements also [`SliceByValue`], delegating to the wrapped
+/// type.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fallible<S>(S);
+
+impl<S> Fallible<S> {
+    /// Wraps the given iterable.
+    pub const fn new(iterable: S) -> Self {
+        Self(iterable)
+    }
+
+    /// Returns the wrapped iterable.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: SliceByValue> SliceByValue for Fallible<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.0.get_value(index)
+    }
+
+    #[inline]
+    fn index_value(&self, index: usize) -> Self::Value {
+        self.0.index_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.0.get_value_unchecked(index) }
+    }
+}
+
+/// The iterator returned by the
+/// [`try_iter_value`](TryIterateByValue::try_iter_value) method of
+/// [`Fallible`].
+#[derive(Debug, Clone)]
+pub struct FallibleIter<I>(I);
+
+impl<I: Iterator> Iterator for FallibleIter<I> {
+    type Item = Result<I::Item, Infallible>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Ok)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(Ok)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for FallibleIter<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Ok)
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for FallibleIter<I> {}
+
+impl<I: core::iter::FusedIterator> core::iter::FusedIterator for FallibleIter<I> {}
+
+impl<'a, S: IterateByValueGat<'a>> TryIterateByValueGat<'a> for Fallible<S> {
+    type Item = S::Item;
+    type Error = Infallible;
+    type TryIter = FallibleIter<Iter<'a, S>>;
+}
+
+impl<S: IterateByValue> TryIterateByValue for Fallible<S> {
+    #[inline]
+    fn try_iter_value(&self) -> TryIter<'_, Self> {
+        FallibleIter(self.0.iter_value())
+    }
+}
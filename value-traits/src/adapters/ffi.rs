@@ -0,0 +1,170 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! A C-compatible interface to by-value iterators.
+//!
+//! An [`ExternIter`] is a `#[repr(C)]` structure containing a pointer to a
+//! boxed exact-size iterator and a table of `extern "C"` functions operating
+//! on it, so by-value sequences can be read from C or C++ code, for example
+//! when exposing the same memory-mapped structures to readers in different
+//! languages. The C declaration of the structure is
+//!
+//! ```c
+//! typedef struct {
+//!     void *state;
+//!     size_t value_size;
+//!     size_t (*len)(const void *state);
+//!     bool (*next_into)(void *state, void *out);
+//!     void (*drop)(void *state);
+//! } ExternIter;
+//! ```
+//!
+//! where `len` returns the number of remaining values, and `next_into` writes
+//! the next value, if any, to the memory pointed by `out`, which must be
+//! valid for writes of `value_size` bytes and suitably aligned, returning
+//! whether a value was written. The iterator is owned by the Rust side: C code
+//! must not call `drop`, which is called when the [`ExternIter`] is dropped.
+//!
+//! ```rust
+//! # #[cfg(feature = "ffi")] {
+//! use value_traits::adapters::ffi::ExternIter;
+//!
+//! let v = [1_u32, 2, 3];
+//! let mut iter = ExternIter::new(&v);
+//! assert_eq!(iter.value_size(), 4);
+//! assert_eq!(iter.len(), 3);
+//! let mut out = 0;
+//! // This is what C code would do through the function pointers
+//! assert!(iter.next_into(&mut out));
+//! assert_eq!(out, 1);
+//! assert_eq!(iter.len(), 2);
+//! # }
+//! ```
+//!
+//! Since values are copied bitwise to C memory, they must be [`Copy`], and
+//! they should have a C-compatible layout.
+//!
+//! This module is available only if the `ffi` feature is enabled.
+
+#![cfg(feature = "ffi")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+use core::{ffi::c_void, marker::PhantomData};
+
+use crate::iter::{Iter, IterateByValue};
+
+/// A C-compatible exact-size iterator on values of type `T`.
+///
+/// The type parameter makes [`next_into`](ExternIter::next_into) safe on the
+/// Rust side; it does not affect the layout of the structure.
+///
+/// See the [module documentation](self).
+#[repr(C)]
+pub struct ExternIter<'a, T> {
+    state: *mut c_void,
+    value_size: usize,
+    len: unsafe extern "C" fn(*const c_void) -> usize,
+    next_into: unsafe extern "C" fn(*mut c_void, *mut c_void) -> bool,
+    drop: unsafe extern "C" fn(*mut c_void),
+    _marker: PhantomData<(&'a (), T)>,
+}
+
+unsafe extern "C" fn len<I: ExactSizeIterator>(state: *const c_void) -> usize {
+    // SAFETY: state points to the boxed iterator
+    unsafe { &*(state as *const I) }.len()
+}
+
+unsafe extern "C" fn next_into<I: ExactSizeIterator<Item: Copy>>(
+    state: *mut c_void,
+    out: *mut c_void,
+) -> bool {
+    // SAFETY: state points to the boxed iterator
+    match unsafe { &mut *(state as *mut I) }.next() {
+        Some(value) => {
+            // SAFETY: the caller guarantees that out is valid for writes
+            unsafe { (out as *mut I::Item).write(value) };
+            true
+        }
+        None => false,
+    }
+}
+
+unsafe extern "C" fn drop<I>(state: *mut c_void) {
+    // SAFETY: state has been obtained by Box::into_raw
+    core::mem::drop(unsafe { Box::from_raw(state as *mut I) });
+}
+
+impl<'a, T: Copy> ExternIter<'a, T> {
+    /// Creates a C-compatible iterator on the values of a by-value
+    /// iterable.
+    pub fn new<S>(iterable: &'a S) -> Self
+    where
+        S: IterateByValue + ?Sized,
+        Iter<'a, S>: ExactSizeIterator<Item = T>,
+    {
+        Self::from_iter(iterable.iter_value())
+    }
+
+    /// Creates a C-compatible iterator from an exact-size iterator on
+    /// values.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter<I: ExactSizeIterator<Item = T> + 'a>(iter: I) -> Self {
+        Self {
+            state: Box::into_raw(Box::new(iter)) as *mut c_void,
+            value_size: size_of::<T>(),
+            len: len::<I>,
+            next_into: next_into::<I>,
+            drop: drop::<I>,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the size in bytes of the values.
+    #[inline]
+    pub fn value_size(&self) -> usize {
+        self.value_size
+    }
+
+    /// Returns the number of remaining values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        // SAFETY: the state matches the function
+        unsafe { (self.len)(self.state) }
+    }
+
+    /// Returns `true` if there are no remaining values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Writes the next value, if any, to `out`, and returns whether a value
+    /// was written.
+    #[inline]
+    pub fn next_into(&mut self, out: &mut T) -> bool {
+        // SAFETY: the state matches the function, and out is valid
+        unsafe { (self.next_into)(self.state, out as *mut T as *mut c_void) }
+    }
+}
+
+impl<T> Drop for ExternIter<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the state matches the function, and it is not used anymore
+        unsafe { (self.drop)(self.state) }
+    }
+}
+
+impl<T: Copy> core::fmt::Debug for ExternIter<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExternIter")
+            .field("value_size", &self.value_size)
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -0,0 +1,305 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Front-coded sequences of strings.
+//!
+//! [`FrontCoded`] is a reference implementation of
+//! [`SliceOfStringsByValue`]: strings are divided in blocks of fixed size,
+//! called the _ratio_, and each string but the first of each block is
+//! stored as the length of the longest common prefix with the previous string
+//! followed by the remaining suffix. Compression is effective when the
+//! strings are sorted, as in a dictionary of terms.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::front_coded::FrontCoded;
+//! use value_traits::iter::IterateByValue;
+//! use value_traits::slices::SliceByValue;
+//! use value_traits::strings::SliceOfStringsByValue;
+//!
+//! let terms = ["apple", "applet", "apply", "banana", "band"];
+//! let fc = FrontCoded::new(terms, 2);
+//! assert_eq!(fc.len(), 5);
+//! assert_eq!(fc.index_value(2), "apply");
+//!
+//! let mut buffer = String::new();
+//! fc.get_str_into(4, &mut buffer);
+//! assert_eq!(buffer, "band");
+//! assert_eq!(fc.iter_value().collect::<Vec<_>>(), terms);
+//! # }
+//! ```
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{string::String, vec::Vec};
+
+use core::iter::FusedIterator;
+
+use crate::{
+    algorithms::{DescribeSliceByValue, MismatchValues},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat, SeekableValueIter,
+    },
+    slices::{SliceByValue, SliceByValueGetInto},
+    strings::SliceOfStringsByValue,
+};
+
+/// A front-coded sequence of strings.
+///
+/// The first string of each block is stored in full, preceded by its length;
+/// the other strings are stored as the length of the common prefix with the
+/// previous string, the length of the remaining suffix, and the suffix.
+/// Lengths are stored as variable-length integers. Accessing a string
+/// requires decoding at most [`ratio`](FrontCoded::ratio) strings, whereas
+/// iteration decodes each string once.
+#[derive(Debug, Clone)]
+pub struct FrontCoded {
+    data: Vec<u8>,
+    pointers: Vec<usize>,
+    len: usize,
+    ratio: usize,
+}
+
+fn write_len(data: &mut Vec<u8>, mut x: usize) {
+    while x >= 0x80 {
+        data.push((x as u8) | 0x80);
+        x >>= 7;
+    }
+    data.push(x as u8);
+}
+
+fn read_len(data: &[u8], pos: &mut usize) -> usize {
+    let mut x = 0;
+    let mut shift = 0;
+    loop {
+        let byte = data[*pos];
+        *pos += 1;
+        x |= ((byte & 0x7F) as usize) << shift;
+        if byte < 0x80 {
+            return x;
+        }
+        shift += 7;
+    }
+}
+
+impl FrontCoded {
+    /// Creates a new front-coded sequence containing the given strings,
+    /// using blocks of `ratio` strings.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `ratio` is zero.
+    pub fn new<I, S>(strings: I, ratio: usize) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        assert!(ratio != 0, "the ratio is zero");
+        let mut data = Vec::new();
+        let mut pointers = Vec::new();
+        let mut len = 0;
+        let mut prev = String::new();
+        for s in strings {
+            let s = s.as_ref();
+            if len % ratio == 0 {
+                pointers.push(data.len());
+                write_len(&mut data, s.len());
+                data.extend_from_slice(s.as_bytes());
+            } else {
+                let mut lcp = prev.as_bytes().common_prefix_len(s.as_bytes());
+                // The suffix must start at a character boundary
+                while !s.is_char_boundary(lcp) {
+                    lcp -= 1;
+                }
+                write_len(&mut data, lcp);
+                write_len(&mut data, s.len() - lcp);
+                data.extend_from_slice(&s.as_bytes()[lcp..]);
+            }
+            prev.clear();
+            prev.push_str(s);
+            len += 1;
+        }
+        Self {
+            data,
+            pointers,
+            len,
+            ratio,
+        }
+    }
+
+    /// Returns the number of strings in each block.
+    #[inline]
+    pub fn ratio(&self) -> usize {
+        self.ratio
+    }
+
+    /// Decodes the string of given index starting at byte position `pos`,
+    /// assuming that `out` contains the previous string if `index` is not
+    /// the first index of a block.
+    fn decode(&self, index: usize, pos: &mut usize, out: &mut String) {
+        let lcp = if index % self.ratio == 0 {
+            0
+        } else {
+            read_len(&self.data, pos)
+        };
+        let suffix_len = read_len(&self.data, pos);
+        let suffix = &self.data[*pos..*pos + suffix_len];
+        *pos += suffix_len;
+        out.truncate(lcp);
+        // SAFETY: the suffix was obtained by splitting a string at a
+        // character boundary
+        out.push_str(unsafe { core::str::from_utf8_unchecked(suffix) });
+    }
+
+    /// Returns an iterator starting at the given index, which must be
+    /// within bounds.
+    fn iter_from(&self, from: usize) -> FrontCodedIter<'_> {
+        let mut iter = FrontCodedIter {
+            front_coded: self,
+            index: from - from % self.ratio,
+            pos: self.pointers.get(from / self.ratio).copied().unwrap_or(0),
+            buffer: String::new(),
+        };
+        while iter.index < from {
+            self.decode(iter.index, &mut iter.pos, &mut iter.buffer);
+            iter.index += 1;
+        }
+        iter
+    }
+}
+
+impl SliceByValue for FrontCoded {
+    type Value = String;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        let mut out = String::new();
+        // SAFETY: index is within bounds
+        unsafe { self.get_str_into_unchecked(index, &mut out) };
+        out
+    }
+}
+
+impl SliceOfStringsByValue for FrontCoded {
+    unsafe fn get_str_into_unchecked(&self, index: usize, out: &mut String) {
+        let block = index / self.ratio;
+        let mut pos = self.pointers[block];
+        for i in block * self.ratio..=index {
+            self.decode(i, &mut pos, out);
+        }
+    }
+}
+
+impl SliceByValueGetInto for FrontCoded {
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+        // SAFETY: index is within bounds
+        unsafe { self.get_str_into_unchecked(index, out) }
+    }
+}
+
+impl DescribeSliceByValue for FrontCoded {
+    #[inline]
+    fn backend_name(&self) -> &'static str {
+        "FrontCoded"
+    }
+}
+
+/// An iterator on the strings of a [`FrontCoded`] sequence.
+///
+/// Each string is decoded once, reusing the previous one.
+#[derive(Debug, Clone)]
+pub struct FrontCodedIter<'a> {
+    front_coded: &'a FrontCoded,
+    index: usize,
+    pos: usize,
+    buffer: String,
+}
+
+impl Iterator for FrontCodedIter<'_> {
+    type Item = String;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.front_coded.len {
+            return None;
+        }
+        self.front_coded
+            .decode(self.index, &mut self.pos, &mut self.buffer);
+        self.index += 1;
+        Some(self.buffer.clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front_coded.len - self.index;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for FrontCodedIter<'_> {}
+
+impl SeekableValueIter for FrontCodedIter<'_> {
+    #[inline]
+    fn current_index(&self) -> usize {
+        self.index
+    }
+
+    /// Seeking to a later block jumps directly to its start, so at most
+    /// [`ratio`](FrontCoded::ratio) strings are decoded.
+    fn seek(&mut self, to_index: usize) {
+        let front_coded = self.front_coded;
+        let to_index = to_index.min(front_coded.len);
+        if to_index <= self.index {
+            return;
+        }
+        let block = to_index / front_coded.ratio;
+        if block > self.index / front_coded.ratio && block < front_coded.pointers.len() {
+            self.index = block * front_coded.ratio;
+            self.pos = front_coded.pointers[block];
+        }
+        while self.index < to_index {
+            front_coded.decode(self.index, &mut self.pos, &mut self.buffer);
+            self.index += 1;
+        }
+    }
+}
+
+impl FusedIterator for FrontCodedIter<'_> {}
+
+impl<'a> IterateByValueGat<'a> for FrontCoded {
+    type Item = String;
+    type Iter = FrontCodedIter<'a>;
+}
+
+impl IterateByValue for FrontCoded {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.iter_from(0)
+    }
+}
+
+impl<'a> IterateByValueFromGat<'a> for FrontCoded {
+    type Item = String;
+    type IterFrom = FrontCodedIter<'a>;
+}
+
+impl IterateByValueFrom for FrontCoded {
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.iter_from(from.min(self.len))
+    }
+}
+
+impl ExactSizeIterateByValue for FrontCoded {}
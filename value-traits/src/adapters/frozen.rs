@@ -0,0 +1,156 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Read-only views of mutable by-value slices.
+//!
+//! [`Frozen`] wraps a by-value slice and implements only the read-only
+//! by-value traits (access, subslicing, and iteration), delegating to the
+//! wrapped slice. Since it does not implement [`SliceByValueMut`] and gives
+//! no mutable access to the wrapped slice, calling setters through it is a
+//! compile-time error, even when the wrapped slice is mutable:
+//!
+//! ```rust
+//! use value_traits::adapters::frozen::Frozen;
+//! use value_traits::slices::*;
+//!
+//! let mut v = [1, 2, 3];
+//! let frozen = Frozen::new(&mut v);
+//! assert_eq!(frozen.index_value(1), 2);
+//! assert_eq!(frozen.index_subslice(1..).len(), 2);
+//! ```
+//!
+//! ```compile_fail
+//! use value_traits::adapters::frozen::Frozen;
+//! use value_traits::slices::*;
+//!
+//! let mut v = [1, 2, 3];
+//! let mut frozen = Frozen::new(&mut v);
+//! frozen.set_value(0, 0);
+//! ```
+//!
+//! [`SliceByValueMut`]: crate::slices::SliceByValueMut
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueGetInto, SliceByValueSubsliceGat,
+        SliceByValueSubsliceRange, Subslice,
+    },
+};
+
+/// A read-only view of a by-value slice.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Frozen<S>(S);
+
+impl<S> Frozen<S> {
+    /// Wraps the given slice.
+    pub const fn new(slice: S) -> Self {
+        Self(slice)
+    }
+
+    /// Returns a shared reference to the wrapped slice.
+    pub const fn as_inner(&self) -> &S {
+        &self.0
+    }
+
+    /// Returns the wrapped slice.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: SliceByValue> SliceByValue for Frozen<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.0.get_value(index)
+    }
+
+    #[inline]
+    fn index_value(&self, index: usize) -> Self::Value {
+        self.0.index_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.0.get_value_unchecked(index) }
+    }
+}
+
+impl<S: SliceByValueGetInto> SliceByValueGetInto for Frozen<S> {
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+        // SAFETY: index is within bounds
+        unsafe { self.0.get_value_into_unchecked(index, out) }
+    }
+
+    #[inline]
+    fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+        self.0.get_value_into(index, out)
+    }
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a>> SliceByValueSubsliceGat<'a> for Frozen<S> {
+    type Subslice = S::Subslice;
+}
+
+impl<R: ComposeRange, S: SliceByValueSubsliceRange<R>> SliceByValueSubsliceRange<R> for Frozen<S> {
+    #[inline]
+    fn get_subslice(&self, range: R) -> Option<Subslice<'_, Self>> {
+        self.0.get_subslice(range)
+    }
+
+    #[inline]
+    fn index_subslice(&self, range: R) -> Subslice<'_, Self> {
+        self.0.index_subslice(range)
+    }
+
+    #[inline]
+    unsafe fn get_subslice_unchecked(&self, range: R) -> Subslice<'_, Self> {
+        // SAFETY: range is within bounds
+        unsafe { self.0.get_subslice_unchecked(range) }
+    }
+}
+
+impl<'a, S: IterateByValueGat<'a>> IterateByValueGat<'a> for Frozen<S> {
+    type Item = S::Item;
+    type Iter = S::Iter;
+}
+
+impl<S: IterateByValue> IterateByValue for Frozen<S> {
+    #[inline]
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.0.iter_value()
+    }
+}
+
+impl<'a, S: IterateByValueFromGat<'a>> IterateByValueFromGat<'a> for Frozen<S> {
+    type Item = S::Item;
+    type IterFrom = S::IterFrom;
+}
+
+impl<S: IterateByValueFrom> IterateByValueFrom for Frozen<S> {
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.0.iter_value_from(from)
+    }
+}
+
+impl<S: ExactSizeIterateByValue> ExactSizeIterateByValue for Frozen<S> {}
@@ -0,0 +1,263 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Functionally defined by-value slices.
+//!
+//! [`FnSlice`] defines a read-only by-value slice using a length and a
+//! function from indices to values, whereas [`ConstSlice`] defines a slice
+//! containing the same value repeated a given number of times. The
+//! [`fn_slice!`](crate::fn_slice) and [`const_slice!`](crate::const_slice)
+//! macros make it possible to create such slices inline, the same way
+//! [`vec!`](https://doc.rust-lang.org/std/macro.vec.html) does for vectors:
+//!
+//! ```rust
+//! use value_traits::{const_slice, fn_slice};
+//! use value_traits::slices::*;
+//!
+//! let squares = fn_slice!(100, |i| i * i);
+//! assert_eq!(squares.len(), 100);
+//! assert_eq!(squares.index_value(7), 49);
+//!
+//! let zeroes = const_slice!(0_u8; 10);
+//! assert_eq!(zeroes.get_value(9), Some(0));
+//! assert_eq!(zeroes.get_value(10), None);
+//! ```
+//!
+//! Methods of traits cannot be `const`, so [`ConstSlice`] has inherent
+//! `const` accessors such as [`get_value_const`](ConstSlice::get_value_const),
+//! making it possible to evaluate lookups in const contexts, for example to
+//! initialize statics. Arrays can be indexed directly in const contexts.
+
+use core::{
+    iter::{Map, RepeatN},
+    ops::Range,
+};
+
+use crate::{
+    iter::{
+        ConsistentIteration, ExactSizeIterateByValue, Iter, IterFrom, IterateByValue,
+        IterateByValueFrom, IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::SliceByValue,
+};
+
+/// A read-only by-value slice defined by a length and a function from
+/// indices to values.
+///
+/// See the [module documentation](self) and the [`fn_slice!`](crate::fn_slice)
+/// macro.
+#[derive(Clone, Copy)]
+pub struct FnSlice<F> {
+    len: usize,
+    f: F,
+}
+
+impl<V, F: Fn(usize) -> V> FnSlice<F> {
+    /// Creates a new slice of the given length whose value at index `i` is
+    /// `f(i)`.
+    ///
+    /// The function will be called only on indices smaller than `len`.
+    pub const fn new(len: usize, f: F) -> Self {
+        Self { len, f }
+    }
+}
+
+impl<F> core::fmt::Debug for FnSlice<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FnSlice").field("len", &self.len).finish()
+    }
+}
+
+impl<V, F: Fn(usize) -> V> SliceByValue for FnSlice<F> {
+    type Value = V;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        (self.f)(index)
+    }
+}
+
+impl<'a, V, F: Fn(usize) -> V> IterateByValueGat<'a> for FnSlice<F> {
+    type Item = V;
+    type Iter = Map<Range<usize>, &'a F>;
+}
+
+impl<V, F: Fn(usize) -> V> IterateByValue for FnSlice<F> {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        (0..self.len).map(&self.f)
+    }
+}
+
+impl<'a, V, F: Fn(usize) -> V> IterateByValueFromGat<'a> for FnSlice<F> {
+    type Item = V;
+    type IterFrom = Map<Range<usize>, &'a F>;
+}
+
+impl<V, F: Fn(usize) -> V> IterateByValueFrom for FnSlice<F> {
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        (from..self.len).map(&self.f)
+    }
+}
+
+impl<V, F: Fn(usize) -> V> ExactSizeIterateByValue for FnSlice<F> {}
+impl<V, F: Fn(usize) -> V> ConsistentIteration for FnSlice<F> {}
+
+/// A read-only by-value slice containing the same value repeated a given
+/// number of times.
+///
+/// See the [module documentation](self) and the
+/// [`const_slice!`](crate::const_slice) macro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstSlice<T> {
+    value: T,
+    len: usize,
+}
+
+impl<T: Clone> ConstSlice<T> {
+    /// Creates a new slice containing `len` copies of `value`.
+    pub const fn new(value: T, len: usize) -> Self {
+        Self { value, len }
+    }
+
+    /// Returns the length of the slice.
+    ///
+    /// This method is the `const` analogue of [`SliceByValue::len`].
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slice is empty.
+    ///
+    /// This method is the `const` analogue of [`SliceByValue::is_empty`].
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Copy> ConstSlice<T> {
+    /// Returns the value at the given index, or `None` if the index is out
+    /// of bounds.
+    ///
+    /// This method is the `const` analogue of [`SliceByValue::get_value`],
+    /// and it can be used to initialize constants and statics:
+    ///
+    /// ```rust
+    /// use value_traits::const_slice;
+    /// use value_traits::adapters::functional::ConstSlice;
+    ///
+    /// const TABLE: ConstSlice<u8> = const_slice!(42; 16);
+    /// const VALUE: Option<u8> = TABLE.get_value_const(15);
+    /// const LEN: usize = TABLE.len();
+    /// assert_eq!(VALUE, Some(42));
+    /// assert_eq!(LEN, 16);
+    /// ```
+    #[inline]
+    pub const fn get_value_const(&self, index: usize) -> Option<T> {
+        if index < self.len {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value at the given index.
+    ///
+    /// This method is the `const` analogue of
+    /// [`SliceByValue::index_value`]; in a const context, an out-of-bounds
+    /// index is a compile-time error.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is out of bounds.
+    #[inline]
+    pub const fn index_value_const(&self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        self.value
+    }
+}
+
+impl<T: Clone> SliceByValue for ConstSlice<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, _index: usize) -> Self::Value {
+        self.value.clone()
+    }
+}
+
+impl<'a, T: Clone> IterateByValueGat<'a> for ConstSlice<T> {
+    type Item = T;
+    type Iter = RepeatN<T>;
+}
+
+impl<T: Clone> IterateByValue for ConstSlice<T> {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        core::iter::repeat_n(self.value.clone(), self.len)
+    }
+}
+
+impl<'a, T: Clone> IterateByValueFromGat<'a> for ConstSlice<T> {
+    type Item = T;
+    type IterFrom = RepeatN<T>;
+}
+
+impl<T: Clone> IterateByValueFrom for ConstSlice<T> {
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        core::iter::repeat_n(self.value.clone(), self.len.saturating_sub(from))
+    }
+}
+
+impl<T: Clone> ExactSizeIterateByValue for ConstSlice<T> {}
+impl<T: Clone> ConsistentIteration for ConstSlice<T> {}
+
+/// Creates a [`FnSlice`] from a length and a function from indices to values.
+///
+/// ```rust
+/// use value_traits::fn_slice;
+/// use value_traits::slices::*;
+///
+/// let evens = fn_slice!(10, |i| 2 * i);
+/// assert_eq!(evens.index_value(3), 6);
+/// ```
+#[macro_export]
+macro_rules! fn_slice {
+    ($len:expr, $f:expr $(,)?) => {
+        $crate::adapters::functional::FnSlice::new($len, $f)
+    };
+}
+
+/// Creates a [`ConstSlice`] containing a value repeated a given number of
+/// times, using the same syntax of
+/// [`vec!`](https://doc.rust-lang.org/std/macro.vec.html).
+///
+/// ```rust
+/// use value_traits::const_slice;
+/// use value_traits::slices::*;
+///
+/// let ones = const_slice!(1; 5);
+/// assert_eq!(ones.len(), 5);
+/// assert_eq!(ones.index_value(4), 1);
+/// ```
+#[macro_export]
+macro_rules! const_slice {
+    ($value:expr; $len:expr) => {
+        $crate::adapters::functional::ConstSlice::new($value, $len)
+    };
+}
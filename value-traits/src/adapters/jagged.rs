@@ -0,0 +1,225 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Adapters implementing [`JaggedByValue`].
+//!
+//! [`Csr`] stores a jagged array using a by-value slice of offsets and a
+//! by-value slice of values, and [`JaggedBuilder`] builds it row by row
+//! using any [`VecByValue`] backends. See the [`jagged`](crate::jagged)
+//! module for an example.
+
+use core::ops::Range;
+
+use crate::{
+    jagged::JaggedByValue,
+    slices::{SliceByValue, SliceByValueSubsliceRange, Subslice},
+    vecs::VecByValue,
+};
+
+/// A jagged array in _compressed sparse row_ (CSR) format.
+///
+/// The rows are stored consecutively in a by-value slice of values, and a
+/// by-value slice of offsets of length [`num_rows`](JaggedByValue::num_rows)
+/// \+ 1 contains the starting position of each row, followed by the length
+/// of the slice of values. Both slices can be compressed representations.
+#[derive(Debug, Clone, Copy)]
+pub struct Csr<O, V> {
+    offsets: O,
+    values: V,
+}
+
+impl<O, V> Csr<O, V>
+where
+    O: SliceByValue<Value = usize>,
+    V: SliceByValueSubsliceRange<Range<usize>>,
+{
+    /// Creates a new jagged array from the given offsets and values.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the offsets are empty or not nondecreasing,
+    /// if the first offset is not zero, or if the last offset is not the
+    /// length of the values.
+    pub fn new(offsets: O, values: V) -> Self {
+        assert!(!offsets.is_empty(), "the offsets are empty");
+        assert!(offsets.index_value(0) == 0, "the first offset is not zero");
+        assert!(
+            offsets.index_value(offsets.len() - 1) == values.len(),
+            "the last offset is not the length of the values"
+        );
+        let mut prev = 0;
+        for i in 1..offsets.len() {
+            // SAFETY: i is within bounds
+            let offset = unsafe { offsets.get_value_unchecked(i) };
+            assert!(prev <= offset, "the offsets are not nondecreasing");
+            prev = offset;
+        }
+        // SAFETY: we just checked the offsets
+        unsafe { Self::new_unchecked(offsets, values) }
+    }
+
+    /// Creates a new jagged array from the given offsets and values without
+    /// checking them.
+    ///
+    /// # Safety
+    ///
+    /// The offsets must be nonempty and nondecreasing, the first offset must
+    /// be zero, and the last offset must be the length of the values.
+    pub unsafe fn new_unchecked(offsets: O, values: V) -> Self {
+        Self { offsets, values }
+    }
+
+    /// Returns the offsets and the values.
+    pub fn into_parts(self) -> (O, V) {
+        (self.offsets, self.values)
+    }
+}
+
+impl<O, V> JaggedByValue for Csr<O, V>
+where
+    O: SliceByValue<Value = usize>,
+    V: SliceByValueSubsliceRange<Range<usize>>,
+{
+    type Value = V::Value;
+
+    type Row<'a>
+        = Subslice<'a, V>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn num_rows(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    #[inline]
+    fn total_len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    unsafe fn get_row_unchecked(&self, row: usize) -> Self::Row<'_> {
+        // SAFETY: row + 1 is within bounds, and offsets are valid
+        unsafe {
+            let start = self.offsets.get_value_unchecked(row);
+            let end = self.offsets.get_value_unchecked(row + 1);
+            self.values.get_subslice_unchecked(start..end)
+        }
+    }
+
+    #[inline]
+    fn row_len(&self, row: usize) -> usize {
+        self.offsets.index_value(row + 1) - self.offsets.index_value(row)
+    }
+}
+
+/// A builder for [`Csr`] jagged arrays.
+///
+/// Rows are appended by [`push_row`](JaggedBuilder::push_row), which
+/// consumes an iterator on the values of the row. The offsets and the values
+/// are stored in two [`VecByValue`] backends, so the resulting jagged array
+/// can be, for example, compressed.
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use value_traits::adapters::jagged::JaggedBuilder;
+/// use value_traits::jagged::JaggedByValue;
+/// use value_traits::slices::SliceByValue;
+///
+/// let mut builder = JaggedBuilder::<Vec<_>, Vec<_>>::default();
+/// builder.push_row([1, 2]);
+/// builder.push_row([]);
+/// builder.push_row(3..6);
+/// let j = builder.build();
+/// assert_eq!(j.num_rows(), 3);
+/// assert_eq!(j.row(2).index_value(1), 4);
+/// assert_eq!(j.into_parts(), (vec![0, 2, 2, 5], vec![1, 2, 3, 4, 5]));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct JaggedBuilder<O, V> {
+    offsets: O,
+    values: V,
+}
+
+impl<O, V> JaggedBuilder<O, V>
+where
+    O: VecByValue<Value = usize>,
+    V: VecByValue,
+{
+    /// Creates a new builder storing offsets and values in the given
+    /// backends.
+    ///
+    /// The backends are cleared before use.
+    pub fn new(mut offsets: O, mut values: V) -> Self {
+        offsets.clear_values();
+        values.clear_values();
+        offsets.push_value(0);
+        Self { offsets, values }
+    }
+
+    /// Returns the number of rows appended so far.
+    #[inline]
+    pub fn num_rows(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the number of values appended so far.
+    #[inline]
+    pub fn total_len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Appends a row containing the values returned by the given iterator.
+    pub fn push_row<I: IntoIterator<Item = V::Value>>(&mut self, row: I) {
+        for value in row {
+            self.values.push_value(value);
+        }
+        self.offsets.push_value(self.values.len());
+    }
+
+    /// Appends the rows returned by the given iterator.
+    pub fn extend_rows<R, I>(&mut self, rows: R)
+    where
+        R: IntoIterator<Item = I>,
+        I: IntoIterator<Item = V::Value>,
+    {
+        for row in rows {
+            self.push_row(row);
+        }
+    }
+
+    /// Returns the offsets and the values built so far.
+    ///
+    /// The offsets contain [`num_rows`](JaggedBuilder::num_rows) + 1
+    /// elements, the first being zero and the last being the number of
+    /// values.
+    pub fn into_parts(self) -> (O, V) {
+        (self.offsets, self.values)
+    }
+
+    /// Returns a jagged array containing the rows appended so far.
+    pub fn build(self) -> Csr<O, V>
+    where
+        V: SliceByValueSubsliceRange<Range<usize>>,
+    {
+        // SAFETY: offsets are nonempty, start from zero, are nondecreasing,
+        // and end at the length of the values by construction
+        unsafe { Csr::new_unchecked(self.offsets, self.values) }
+    }
+}
+
+impl<O, V> Default for JaggedBuilder<O, V>
+where
+    O: VecByValue<Value = usize> + Default,
+    V: VecByValue + Default,
+{
+    fn default() -> Self {
+        Self::new(O::default(), V::default())
+    }
+}
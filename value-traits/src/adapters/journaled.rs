@@ -0,0 +1,239 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Mutable by-value slices with undoable mutations.
+//!
+//! A [`Journaled`] wraps a mutable by-value slice and records in a journal
+//! the index and the previous value of every mutation performed through
+//! [`SliceByValueMut`], including those performed through its mutable
+//! subslices. Mutations can then be made permanent with
+//! [`commit`](Journaled::commit), or undone with
+//! [`rollback`](Journaled::rollback). Speculative algorithms, such as
+//! backtracking searches, can use [`savepoint`](Journaled::savepoint) and
+//! [`rollback_to`](Journaled::rollback_to) to undo only the most recent
+//! mutations:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::journaled::Journaled;
+//! use value_traits::slices::*;
+//!
+//! let mut j = Journaled::new([0, 0, 0]);
+//! j.set_value(0, 1);
+//! let savepoint = j.savepoint();
+//! j.set_value(1, 2);
+//! j.set_value(1, 3);
+//! j.rollback_to(savepoint);
+//! assert_eq!(j.as_inner(), &[1, 0, 0]);
+//! j.rollback();
+//! assert_eq!(j.as_inner(), &[0, 0, 0]);
+//! # }
+//! ```
+//!
+//! Since mutable chunks would bypass the journal,
+//! [`try_chunks_mut`](SliceByValueMut::try_chunks_mut) is not supported.
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::{
+    adapters::subslice::{SubsliceOf, SubsliceOfMut, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::check,
+    slices::{
+        ChunksMutNotSupported, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut,
+    },
+};
+
+/// A mutable by-value slice recording its mutations so that they can be
+/// undone.
+///
+/// See the [module documentation](self).
+pub struct Journaled<S: SliceByValue> {
+    slice: S,
+    journal: Vec<(usize, S::Value)>,
+}
+
+impl<S: SliceByValue<Value: core::fmt::Debug> + core::fmt::Debug> core::fmt::Debug
+    for Journaled<S>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Journaled")
+            .field("slice", &self.slice)
+            .field("journal", &self.journal)
+            .finish()
+    }
+}
+
+impl<S: SliceByValueMut> Journaled<S> {
+    /// Wraps the given slice, with an empty journal.
+    pub fn new(slice: S) -> Self {
+        Self {
+            slice,
+            journal: Vec::new(),
+        }
+    }
+
+    /// Returns a shared reference to the wrapped slice.
+    pub fn as_inner(&self) -> &S {
+        &self.slice
+    }
+
+    /// Returns the number of mutations recorded in the journal.
+    pub fn journal_len(&self) -> usize {
+        self.journal.len()
+    }
+
+    /// Makes all mutations recorded in the journal permanent by clearing
+    /// it.
+    pub fn commit(&mut self) {
+        self.journal.clear();
+    }
+
+    /// Undoes all mutations recorded in the journal, in reverse order, and
+    /// clears it.
+    pub fn rollback(&mut self) {
+        self.rollback_to(0);
+    }
+
+    /// Returns a savepoint that can be passed to
+    /// [`rollback_to`](Journaled::rollback_to) to undo the mutations
+    /// performed after this call.
+    ///
+    /// Savepoints are invalidated by [`commit`](Journaled::commit), and by
+    /// rollbacks to previous savepoints.
+    pub fn savepoint(&self) -> usize {
+        self.journal.len()
+    }
+
+    /// Undoes, in reverse order, the mutations recorded in the journal after
+    /// the given savepoint was returned by
+    /// [`savepoint`](Journaled::savepoint).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the savepoint is greater than the length of
+    /// the journal.
+    pub fn rollback_to(&mut self, savepoint: usize) {
+        check!(
+            savepoint <= self.journal.len(),
+            "invalid savepoint",
+            "savepoint {savepoint} is invalid for journal of length {}",
+            self.journal.len()
+        );
+        for (index, old_value) in self.journal.drain(savepoint..).rev() {
+            // SAFETY: the index was within bounds when the mutation was
+            // recorded, and the length of the slice cannot change
+            unsafe { self.slice.set_value_unchecked(index, old_value) };
+        }
+    }
+
+    /// Returns the wrapped slice, discarding the journal.
+    pub fn into_inner(self) -> S {
+        self.slice
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValue for Journaled<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.slice.get_value_unchecked(index) }
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValueMut for Journaled<S> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds
+        let old_value = unsafe { self.slice.replace_value_unchecked(index, value) };
+        self.journal.push((index, old_value));
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe {
+            let old_value = self.slice.get_value_unchecked(index);
+            self.set_value_unchecked(index, value);
+            old_value
+        }
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    /// Returns always an error, as mutable chunks would bypass the journal.
+    ///
+    /// # Errors
+    ///
+    /// Returns always [`ChunksMutNotSupported`].
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+impl<'a, S: SliceByValueMut> SliceByValueSubsliceGat<'a> for Journaled<S> {
+    type Subslice = SubsliceOf<'a, Self>;
+}
+
+impl<'a, S: SliceByValueMut> SliceByValueSubsliceGatMut<'a> for Journaled<S> {
+    type SubsliceMut = SubsliceOfMut<'a, Self>;
+}
+
+impl_subslice_of_ranges!(mut Journaled<S>, S: SliceByValueMut);
+
+impl<'a, S: SliceByValueMut + IterateByValueGat<'a>> IterateByValueGat<'a> for Journaled<S> {
+    type Item = <S as IterateByValueGat<'a>>::Item;
+    type Iter = <S as IterateByValueGat<'a>>::Iter;
+}
+
+impl<S: SliceByValueMut + IterateByValue> IterateByValue for Journaled<S> {
+    #[inline]
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.slice.iter_value()
+    }
+}
+
+impl<'a, S: SliceByValueMut + IterateByValueFromGat<'a>> IterateByValueFromGat<'a>
+    for Journaled<S>
+{
+    type Item = <S as IterateByValueFromGat<'a>>::Item;
+    type IterFrom = <S as IterateByValueFromGat<'a>>::IterFrom;
+}
+
+impl<S: SliceByValueMut + IterateByValueFrom> IterateByValueFrom for Journaled<S> {
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.slice.iter_value_from(from)
+    }
+}
+
+impl<S: SliceByValueMut + ExactSizeIterateByValue> ExactSizeIterateByValue for Journaled<S> {}
@@ -0,0 +1,214 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Lazily computed, memoized by-value slices.
+//!
+//! A [`LazySlice`] is defined, like a [`FnSlice`](super::functional::FnSlice),
+//! by a length and a function from indices to values, but the function is
+//! called at most once per index: the value is computed on first access and
+//! cached in a [`OnceCell`]. This is useful for expensive derived sequences,
+//! such as hashes or ranks, that are accessed sparsely and repeatedly:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use core::cell::Cell;
+//! use value_traits::adapters::lazy::LazySlice;
+//! use value_traits::slices::*;
+//!
+//! let calls = Cell::new(0);
+//! let squares = LazySlice::new(1000, |i| {
+//!     calls.set(calls.get() + 1);
+//!     i * i
+//! });
+//! assert!(!squares.is_cached(10));
+//! assert_eq!(squares.index_value(10), 100);
+//! assert_eq!(squares.index_value(10), 100);
+//! assert!(squares.is_cached(10));
+//! assert_eq!(calls.get(), 1);
+//! # }
+//! ```
+//!
+//! Since the cache uses interior mutability, a [`LazySlice`] is not
+//! [`Sync`]. [`SyncLazySlice`] caches values in a [`OnceLock`] per index
+//! instead, so that multiple threads can compute values concurrently; each
+//! value is still computed at most once.
+//!
+//! [`LazySlice`] is available only if the `alloc` feature is enabled, and
+//! [`SyncLazySlice`] only if the `std` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+use core::cell::OnceCell;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::slices::SliceByValue;
+
+/// A read-only by-value slice whose values are computed on first access
+/// by a function from indices to values, and then cached.
+///
+/// See the [module documentation](self).
+pub struct LazySlice<V, F> {
+    cache: Box<[OnceCell<V>]>,
+    f: F,
+}
+
+impl<V: Clone, F: Fn(usize) -> V> LazySlice<V, F> {
+    /// Creates a new slice of the given length whose value at index `i` is
+    /// `f(i)`, computed on first access.
+    ///
+    /// The function will be called only on indices smaller than `len`, and
+    /// at most once for each index.
+    pub fn new(len: usize, f: F) -> Self {
+        Self {
+            cache: (0..len).map(|_| OnceCell::new()).collect(),
+            f,
+        }
+    }
+
+    /// Returns true if the value at the given index has already been
+    /// computed.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is out of bounds.
+    pub fn is_cached(&self, index: usize) -> bool {
+        self.cache[index].get().is_some()
+    }
+
+    /// Discards all cached values, which will be computed again on the next
+    /// access.
+    pub fn clear(&mut self) {
+        self.cache.iter_mut().for_each(|cell| {
+            cell.take();
+        });
+    }
+
+    /// Returns the function computing the values.
+    pub fn into_inner(self) -> F {
+        self.f
+    }
+}
+
+impl<V: core::fmt::Debug, F> core::fmt::Debug for LazySlice<V, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LazySlice")
+            .field("cache", &self.cache)
+            .finish()
+    }
+}
+
+impl<V: Clone, F: Fn(usize) -> V> SliceByValue for LazySlice<V, F> {
+    type Value = V;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        let cell = unsafe { self.cache.get_unchecked(index) };
+        cell.get_or_init(|| (self.f)(index)).clone()
+    }
+}
+
+/// A thread-safe variant of [`LazySlice`].
+///
+/// Values are cached in a [`OnceLock`] per index, so the slice is [`Sync`]
+/// if the values are [`Send`] and [`Sync`] and the function is [`Sync`].
+/// If several threads access the same uncached index, only one of them
+/// computes the value, and the others wait for it.
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use value_traits::adapters::lazy::SyncLazySlice;
+/// use value_traits::slices::*;
+///
+/// let squares = SyncLazySlice::new(1000, |i| i * i);
+/// std::thread::scope(|s| {
+///     for t in 0..4 {
+///         let squares = &squares;
+///         s.spawn(move || assert_eq!(squares.index_value(t * 10), t * t * 100));
+///     }
+/// });
+/// assert!(squares.is_cached(30));
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub struct SyncLazySlice<V, F> {
+    cache: Box<[OnceLock<V>]>,
+    f: F,
+}
+
+#[cfg(feature = "std")]
+impl<V: Clone, F: Fn(usize) -> V> SyncLazySlice<V, F> {
+    /// Creates a new slice of the given length whose value at index `i` is
+    /// `f(i)`, computed on first access.
+    ///
+    /// The function will be called only on indices smaller than `len`, and
+    /// at most once for each index.
+    pub fn new(len: usize, f: F) -> Self {
+        Self {
+            cache: (0..len).map(|_| OnceLock::new()).collect(),
+            f,
+        }
+    }
+
+    /// Returns true if the value at the given index has already been
+    /// computed.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is out of bounds.
+    pub fn is_cached(&self, index: usize) -> bool {
+        self.cache[index].get().is_some()
+    }
+
+    /// Discards all cached values, which will be computed again on the next
+    /// access.
+    pub fn clear(&mut self) {
+        self.cache.iter_mut().for_each(|cell| {
+            cell.take();
+        });
+    }
+
+    /// Returns the function computing the values.
+    pub fn into_inner(self) -> F {
+        self.f
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: core::fmt::Debug, F> core::fmt::Debug for SyncLazySlice<V, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SyncLazySlice")
+            .field("cache", &self.cache)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: Clone, F: Fn(usize) -> V> SliceByValue for SyncLazySlice<V, F> {
+    type Value = V;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        let cell = unsafe { self.cache.get_unchecked(index) };
+        cell.get_or_init(|| (self.f)(index)).clone()
+    }
+}
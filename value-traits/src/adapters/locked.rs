@@ -0,0 +1,264 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Shared by-value slices with a write path through a read-write lock.
+//!
+//! The by-value traits are implemented for [`Arc`] and [`Rc`](std::rc::Rc)
+//! only in their read-only form. An [`ArcLockedSlice`] wraps a by-value slice
+//! in an [`Arc`] to a [`RwLock`], and implements all by-value traits by
+//! short critical sections: each read acquires the lock for reading, and
+//! each write acquires the lock for writing, so handles can be cloned and
+//! sent to other threads, and structures that are mostly read but
+//! occasionally patched can be shared without additional synchronization.
+//! Subslices and iterators acquire the lock at each access, too.
+//!
+//! To amortize the cost of locking over many accesses,
+//! [`read`](ArcLockedSlice::read) returns a guard dereferencing to the
+//! wrapped slice, and [`write`](ArcLockedSlice::write) returns a guard
+//! implementing the mutable by-value traits:
+//!
+//! ```rust
+//! use std::thread;
+//! use value_traits::adapters::locked::ArcLockedSlice;
+//! use value_traits::slices::*;
+//!
+//! let shared = ArcLockedSlice::new(vec![0_u32; 4]);
+//! let mut writer = shared.clone();
+//! thread::spawn(move || writer.set_value(1, 10)).join().unwrap();
+//! assert_eq!(shared.index_value(1), 10);
+//!
+//! let mut guard = shared.write();
+//! for i in 0..guard.len() {
+//!     guard.set_value(i, i as u32);
+//! }
+//! drop(guard);
+//! assert_eq!(shared.read().as_slice(), [0, 1, 2, 3]);
+//! ```
+//!
+//! Since the length of a by-value slice cannot be changed through the
+//! by-value traits, and no mutable reference to the wrapped slice is ever
+//! returned, the length of an [`ArcLockedSlice`] is fixed.
+//!
+//! Poisoning is ignored, as mutations performed through the by-value traits
+//! modify a single value.
+//!
+//! This module is available only if the `std` feature is enabled.
+
+#![cfg(feature = "std")]
+
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::{
+    adapters::subslice::{SubsliceIter, SubsliceOf, SubsliceOfMut, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::assert_from,
+    slices::{
+        ChunksMutNotSupported, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut,
+    },
+};
+
+/// A shared by-value slice whose values can be read and written through
+/// a read-write lock.
+///
+/// Clones share the same slice.
+///
+/// See the [module documentation](self).
+#[derive(Debug)]
+pub struct ArcLockedSlice<S> {
+    slice: Arc<RwLock<S>>,
+    len: usize,
+}
+
+impl<S> Clone for ArcLockedSlice<S> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<S: SliceByValueMut> ArcLockedSlice<S> {
+    /// Wraps the given slice.
+    pub fn new(slice: S) -> Self {
+        let len = slice.len();
+        Self {
+            slice: Arc::new(RwLock::new(slice)),
+            len,
+        }
+    }
+
+    /// Acquires the lock for reading, and returns a guard dereferencing to
+    /// the wrapped slice.
+    pub fn read(&self) -> RwLockReadGuard<'_, S> {
+        self.slice.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Acquires the lock for writing, and returns a guard implementing the
+    /// mutable by-value traits.
+    pub fn write(&self) -> ArcLockedWrite<'_, S> {
+        ArcLockedWrite(self.slice.write().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Returns the wrapped slice if this is the only handle, or this handle
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns this handle if there are other handles to the slice.
+    pub fn try_into_inner(self) -> Result<S, Self> {
+        let len = self.len;
+        Arc::try_unwrap(self.slice)
+            .map(|lock| lock.into_inner().unwrap_or_else(PoisonError::into_inner))
+            .map_err(|slice| Self { slice, len })
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValue for ArcLockedSlice<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: the length of the wrapped slice is fixed
+        unsafe { self.read().get_value_unchecked(index) }
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValueMut for ArcLockedSlice<S> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: the length of the wrapped slice is fixed
+        unsafe { self.write().0.set_value_unchecked(index, value) }
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: the length of the wrapped slice is fixed
+        unsafe { self.write().0.replace_value_unchecked(index, value) }
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    /// Mutable chunks are not supported, as they would have to hold the
+    /// lock; use [`write`](ArcLockedSlice::write) instead.
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+impl<'a, S: SliceByValueMut> SliceByValueSubsliceGat<'a> for ArcLockedSlice<S> {
+    type Subslice = SubsliceOf<'a, Self>;
+}
+
+impl<'a, S: SliceByValueMut> SliceByValueSubsliceGatMut<'a> for ArcLockedSlice<S> {
+    type SubsliceMut = SubsliceOfMut<'a, Self>;
+}
+
+impl_subslice_of_ranges!(mut ArcLockedSlice<S>, S: SliceByValueMut);
+
+impl<'a, S: SliceByValueMut> IterateByValueGat<'a> for ArcLockedSlice<S> {
+    type Item = S::Value;
+    type Iter = SubsliceIter<'a, Self>;
+}
+
+impl<S: SliceByValueMut> IterateByValue for ArcLockedSlice<S> {
+    /// The lock is acquired for each value.
+    fn iter_value(&self) -> Iter<'_, Self> {
+        SubsliceIter::new(self, 0..self.len)
+    }
+}
+
+impl<'a, S: SliceByValueMut> IterateByValueFromGat<'a> for ArcLockedSlice<S> {
+    type Item = S::Value;
+    type IterFrom = SubsliceIter<'a, Self>;
+}
+
+impl<S: SliceByValueMut> IterateByValueFrom for ArcLockedSlice<S> {
+    /// The lock is acquired for each value.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `from` is greater than the length of the
+    /// slice.
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        assert_from(from, self.len);
+        SubsliceIter::new(self, from..self.len)
+    }
+}
+
+impl<S: SliceByValueMut> ExactSizeIterateByValue for ArcLockedSlice<S> {}
+
+/// A guard holding the lock of an [`ArcLockedSlice`] for writing.
+///
+/// The guard implements the mutable by-value traits by delegation to the
+/// wrapped slice, so many values can be written acquiring the lock once.
+///
+/// See [`ArcLockedSlice::write`].
+#[derive(Debug)]
+pub struct ArcLockedWrite<'a, S>(RwLockWriteGuard<'a, S>);
+
+impl<S: SliceByValueMut> SliceByValue for ArcLockedWrite<'_, S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.0.get_value_unchecked(index) }
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValueMut for ArcLockedWrite<'_, S> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds
+        unsafe { self.0.set_value_unchecked(index, value) }
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.0.replace_value_unchecked(index, value) }
+    }
+
+    type ChunksMut<'b>
+        = S::ChunksMut<'b>
+    where
+        Self: 'b;
+
+    type ChunksMutError = S::ChunksMutError;
+
+    #[inline]
+    fn try_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        self.0.try_chunks_mut(chunk_size)
+    }
+}
@@ -0,0 +1,224 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Adapters implementing [`MatrixByValue`].
+//!
+//! [`RowMajor`] views a by-value slice as a matrix stored in row-major order,
+//! whereas [`Transposed`] swaps the rows and the columns of a matrix. See the
+//! [`matrices`](crate::matrices) module for an example.
+//!
+//! [`CooMatrix`] is a sparse matrix in coordinate format implementing
+//! [`CoordByValue`].
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::{matrices::CoordByValue, panics::check};
+use crate::{matrices::MatrixByValue, slices::SliceByValue};
+
+/// A matrix stored in row-major order in a by-value slice.
+#[derive(Debug, Clone, Copy)]
+pub struct RowMajor<S> {
+    slice: S,
+    num_cols: usize,
+}
+
+impl<S: SliceByValue> RowMajor<S> {
+    /// Creates a new matrix with the given number of columns whose rows are
+    /// stored consecutively in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the length of the slice is not a multiple
+    /// of the number of columns.
+    pub fn new(slice: S, num_cols: usize) -> Self {
+        let len = slice.len();
+        assert!(
+            if num_cols == 0 {
+                len == 0
+            } else {
+                len % num_cols == 0
+            },
+            "the length of the slice is not a multiple of the number of columns"
+        );
+        Self { slice, num_cols }
+    }
+
+    /// Returns the underlying slice.
+    pub fn into_inner(self) -> S {
+        self.slice
+    }
+}
+
+impl<S: SliceByValue> MatrixByValue for RowMajor<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn num_rows(&self) -> usize {
+        self.slice.len().checked_div(self.num_cols).unwrap_or(0)
+    }
+
+    #[inline]
+    fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    #[inline]
+    unsafe fn get_entry_unchecked(&self, row: usize, col: usize) -> Self::Value {
+        // SAFETY: the position is within bounds
+        unsafe { self.slice.get_value_unchecked(row * self.num_cols + col) }
+    }
+}
+
+/// The transpose of a matrix.
+///
+/// Entries are not moved: accesses to the transposed matrix are simply
+/// mapped to the underlying matrix swapping indices.
+#[derive(Debug, Clone, Copy)]
+pub struct Transposed<M>(M);
+
+impl<M: MatrixByValue> Transposed<M> {
+    /// Creates the transpose of the given matrix.
+    pub fn new(matrix: M) -> Self {
+        Self(matrix)
+    }
+
+    /// Returns the underlying matrix.
+    pub fn into_inner(self) -> M {
+        self.0
+    }
+}
+
+impl<M: MatrixByValue> MatrixByValue for Transposed<M> {
+    type Value = M::Value;
+
+    #[inline]
+    fn num_rows(&self) -> usize {
+        self.0.num_cols()
+    }
+
+    #[inline]
+    fn num_cols(&self) -> usize {
+        self.0.num_rows()
+    }
+
+    #[inline]
+    unsafe fn get_entry_unchecked(&self, row: usize, col: usize) -> Self::Value {
+        // SAFETY: the swapped position is within bounds
+        unsafe { self.0.get_entry_unchecked(col, row) }
+    }
+}
+
+/// A sparse matrix in coordinate (COO) format.
+///
+/// Entries are stored as a list of triples sorted by row and column, so
+/// [`get_value`](CoordByValue::get_value) takes logarithmic time in the
+/// number of entries. Coordinates without an entry have no value.
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use value_traits::adapters::matrix::CooMatrix;
+/// use value_traits::matrices::CoordByValue;
+///
+/// let m = CooMatrix::new(1000, 1000, [(3, 7, 1.5), (0, 2, -1.0), (999, 0, 2.0)]);
+/// assert_eq!(m.num_entries(), 3);
+/// assert_eq!(m.get_value((3, 7)), Some(1.5));
+/// assert_eq!(m.get_value((7, 3)), None);
+/// # }
+/// ```
+///
+/// This structure is available only if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CooMatrix<T> {
+    num_rows: usize,
+    num_cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> CooMatrix<T> {
+    /// Creates a new sparse matrix with the given dimensions from triples
+    /// made of a row, a column, and a value.
+    ///
+    /// Triples can be given in any order; if several triples have the same
+    /// coordinates, the last one is kept.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the coordinates of a triple are out of
+    /// bounds.
+    pub fn new(
+        num_rows: usize,
+        num_cols: usize,
+        entries: impl IntoIterator<Item = (usize, usize, T)>,
+    ) -> Self {
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        for &(row, col, _) in &entries {
+            check!(
+                row < num_rows && col < num_cols,
+                "coordinates out of bounds",
+                "coordinates ({row}, {col}) out of bounds for a {num_rows}x{num_cols} matrix"
+            );
+        }
+        // The sort is stable, so after reversing the last of duplicate
+        // triples comes first, and it is kept by deduplication
+        entries.sort_by_key(|&(row, col, _)| (row, col));
+        entries.reverse();
+        entries.dedup_by_key(|&mut (row, col, _)| (row, col));
+        entries.reverse();
+        Self {
+            num_rows,
+            num_cols,
+            entries,
+        }
+    }
+
+    /// Returns the number of rows of the matrix.
+    #[inline]
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the number of columns of the matrix.
+    #[inline]
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    /// Returns the number of entries of the matrix.
+    #[inline]
+    pub fn num_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the entries of the matrix as triples made of a row, a column,
+    /// and a value, sorted by row and column.
+    #[inline]
+    pub fn entries(&self) -> &[(usize, usize, T)] {
+        &self.entries
+    }
+
+    /// Returns the entries of the matrix.
+    pub fn into_inner(self) -> Vec<(usize, usize, T)> {
+        self.entries
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> CoordByValue for CooMatrix<T> {
+    type Value = T;
+
+    fn get_value(&self, coord: (usize, usize)) -> Option<T> {
+        self.entries
+            .binary_search_by_key(&coord, |&(row, col, _)| (row, col))
+            .ok()
+            .map(|pos| self.entries[pos].2.clone())
+    }
+}
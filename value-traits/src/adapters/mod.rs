@@ -0,0 +1,35 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Adapters wrapping other types and exposing them through by-value traits.
+
+pub mod buffered;
+pub mod compact;
+pub mod cow;
+pub mod double_buffered;
+pub mod fallible;
+pub mod ffi;
+pub mod front_coded;
+pub mod frozen;
+pub mod functional;
+pub mod jagged;
+pub mod journaled;
+pub mod lazy;
+pub mod locked;
+pub mod matrix;
+pub mod observed;
+pub mod owned;
+pub mod paged;
+pub mod persistent;
+pub mod shared;
+pub mod shared_mut;
+pub mod subslice;
+pub mod unique;
+pub mod validated;
+pub mod write_once;
+pub mod zip;
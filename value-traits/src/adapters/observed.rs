@@ -0,0 +1,201 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Mutable by-value slices notifying their mutations.
+//!
+//! An [`ObservedSlice`] wraps a mutable by-value slice and calls a
+//! user-provided observer with the index, the previous value, and the new
+//! value of every mutation performed through [`SliceByValueMut`], including
+//! those performed through its mutable subslices. In this way, auxiliary
+//! structures, such as indices or summaries, can be kept in sync with the
+//! edits to the slice:
+//!
+//! ```rust
+//! use value_traits::adapters::observed::ObservedSlice;
+//! use value_traits::slices::*;
+//!
+//! let mut sum = 10;
+//! let mut o = ObservedSlice::new([1, 2, 3, 4], |_, old, new| sum += new - old);
+//! o.set_value(0, 5);
+//! o.index_subslice_mut(2..).set_value(1, 0);
+//! drop(o);
+//! assert_eq!(sum, 10);
+//! ```
+//!
+//! The new value passed to the observer is read back from the slice after
+//! the mutation, so it reflects what has been actually stored, and values
+//! need not be cloneable.
+//!
+//! Since mutable chunks would bypass the observer,
+//! [`try_chunks_mut`](SliceByValueMut::try_chunks_mut) is not supported.
+
+use crate::{
+    adapters::subslice::{SubsliceOf, SubsliceOfMut, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::{
+        ChunksMutNotSupported, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut,
+    },
+};
+
+/// A mutable by-value slice calling an observer on every mutation.
+///
+/// See the [module documentation](self).
+pub struct ObservedSlice<S, F> {
+    slice: S,
+    observer: F,
+}
+
+impl<S: core::fmt::Debug, F> core::fmt::Debug for ObservedSlice<S, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ObservedSlice")
+            .field("slice", &self.slice)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: SliceByValueMut, F: FnMut(usize, S::Value, S::Value)> ObservedSlice<S, F> {
+    /// Wraps the given slice, calling `observer` with the index, the
+    /// previous value, and the new value of every mutation.
+    pub fn new(slice: S, observer: F) -> Self {
+        Self { slice, observer }
+    }
+
+    /// Returns a shared reference to the wrapped slice.
+    pub fn as_inner(&self) -> &S {
+        &self.slice
+    }
+
+    /// Returns the wrapped slice and the observer.
+    pub fn into_parts(self) -> (S, F) {
+        (self.slice, self.observer)
+    }
+
+    /// Returns the wrapped slice, discarding the observer.
+    pub fn into_inner(self) -> S {
+        self.slice
+    }
+}
+
+impl<S: SliceByValueMut, F: FnMut(usize, S::Value, S::Value)> SliceByValue for ObservedSlice<S, F> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.slice.get_value_unchecked(index) }
+    }
+}
+
+impl<S: SliceByValueMut, F: FnMut(usize, S::Value, S::Value)> SliceByValueMut
+    for ObservedSlice<S, F>
+{
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds
+        let (old_value, new_value) = unsafe {
+            (
+                self.slice.replace_value_unchecked(index, value),
+                self.slice.get_value_unchecked(index),
+            )
+        };
+        (self.observer)(index, old_value, new_value);
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe {
+            let old_value = self.slice.get_value_unchecked(index);
+            self.set_value_unchecked(index, value);
+            old_value
+        }
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    /// Returns always an error, as mutable chunks would bypass the observer.
+    ///
+    /// # Errors
+    ///
+    /// Returns always [`ChunksMutNotSupported`].
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+impl<'a, S: SliceByValueMut, F: FnMut(usize, S::Value, S::Value)> SliceByValueSubsliceGat<'a>
+    for ObservedSlice<S, F>
+{
+    type Subslice = SubsliceOf<'a, Self>;
+}
+
+impl<'a, S: SliceByValueMut, F: FnMut(usize, S::Value, S::Value)> SliceByValueSubsliceGatMut<'a>
+    for ObservedSlice<S, F>
+{
+    type SubsliceMut = SubsliceOfMut<'a, Self>;
+}
+
+impl_subslice_of_ranges!(
+    mut ObservedSlice<S, F>,
+    S: SliceByValueMut,
+    F: FnMut(usize, S::Value, S::Value)
+);
+
+impl<'a, S: SliceByValueMut + IterateByValueGat<'a>, F: FnMut(usize, S::Value, S::Value)>
+    IterateByValueGat<'a> for ObservedSlice<S, F>
+{
+    type Item = <S as IterateByValueGat<'a>>::Item;
+    type Iter = <S as IterateByValueGat<'a>>::Iter;
+}
+
+impl<S: SliceByValueMut + IterateByValue, F: FnMut(usize, S::Value, S::Value)> IterateByValue
+    for ObservedSlice<S, F>
+{
+    #[inline]
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.slice.iter_value()
+    }
+}
+
+impl<'a, S: SliceByValueMut + IterateByValueFromGat<'a>, F: FnMut(usize, S::Value, S::Value)>
+    IterateByValueFromGat<'a> for ObservedSlice<S, F>
+{
+    type Item = <S as IterateByValueFromGat<'a>>::Item;
+    type IterFrom = <S as IterateByValueFromGat<'a>>::IterFrom;
+}
+
+impl<S: SliceByValueMut + IterateByValueFrom, F: FnMut(usize, S::Value, S::Value)>
+    IterateByValueFrom for ObservedSlice<S, F>
+{
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.slice.iter_value_from(from)
+    }
+}
+
+impl<S: SliceByValueMut + ExactSizeIterateByValue, F: FnMut(usize, S::Value, S::Value)>
+    ExactSizeIterateByValue for ObservedSlice<S, F>
+{
+}
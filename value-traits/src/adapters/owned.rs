@@ -0,0 +1,118 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Owned subslices of by-value slices behind a pointer.
+//!
+//! An [`OwnedSubslice`] keeps a pointer (usually, an [`Arc`](std::sync::Arc)
+//! or an [`Rc`](std::rc::Rc)) to a by-value slice and a range. It is the type
+//! returned by [`IntoSubslice::into_subslice`] on reference-counted pointers:
+//! since cloning the pointer is cheap, several independently owned pieces of
+//! the same slice can be handed out without lifetimes.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use std::sync::Arc;
+//! use value_traits::slices::*;
+//!
+//! let shared: Arc<[i32]> = Arc::from(vec![0, 1, 2, 3, 4, 5]);
+//! let first = shared.clone().into_subslice(..3);
+//! let second = shared.into_subslice(3..);
+//! assert_eq!(first.index_value(2), 2);
+//! assert_eq!(second.index_value(0), 3);
+//! // Subslices of owned subslices are owned subslices
+//! assert_eq!(second.into_subslice(1..).index_value(0), 4);
+//! # }
+//! ```
+
+use core::ops::{Deref, Range};
+
+use crate::{
+    panics::assert_range,
+    slices::{ComposeRange, IntoSubslice, SliceByValue},
+};
+
+/// An owned subslice of the by-value slice pointed by `P`.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct OwnedSubslice<P> {
+    base: P,
+    range: Range<usize>,
+}
+
+impl<P: Deref<Target: SliceByValue>> OwnedSubslice<P> {
+    /// Creates a new owned subslice of the slice pointed by `base`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    pub fn new(base: P, range: impl ComposeRange) -> Self {
+        assert_range(&range, base.len());
+        let range = range.compose(0..base.len());
+        Self { base, range }
+    }
+
+    /// Returns the range of the underlying slice covered by this subslice.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the pointer to the underlying slice.
+    pub fn into_base(self) -> P {
+        self.base
+    }
+}
+
+impl<P: Deref<Target: SliceByValue>> SliceByValue for OwnedSubslice<P> {
+    type Value = <P::Target as SliceByValue>::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds, and so is the range
+        unsafe { self.base.get_value_unchecked(index + self.range.start) }
+    }
+}
+
+impl<P: Deref<Target: SliceByValue>> IntoSubslice for OwnedSubslice<P> {
+    type IntoSubslice = Self;
+
+    fn into_subslice<R: ComposeRange>(self, range: R) -> Self::IntoSubslice {
+        assert_range(&range, self.len());
+        Self {
+            range: range.compose(self.range),
+            base: self.base,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::*;
+    use std::{rc::Rc, sync::Arc};
+
+    impl<S: SliceByValue + ?Sized> IntoSubslice for Arc<S> {
+        type IntoSubslice = OwnedSubslice<Self>;
+
+        fn into_subslice<R: ComposeRange>(self, range: R) -> Self::IntoSubslice {
+            OwnedSubslice::new(self, range)
+        }
+    }
+
+    impl<S: SliceByValue + ?Sized> IntoSubslice for Rc<S> {
+        type IntoSubslice = OwnedSubslice<Self>;
+
+        fn into_subslice<R: ComposeRange>(self, range: R) -> Self::IntoSubslice {
+            OwnedSubslice::new(self, range)
+        }
+    }
+}
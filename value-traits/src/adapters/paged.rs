@@ -0,0 +1,427 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Growable by-value vectors stored in fixed-size pages.
+//!
+//! A [`PagedVec`] stores its values in a sequence of separately allocated
+//! pages of fixed size, which is a power of two. Pages are allocated when
+//! needed and never reallocated, so growing a [`PagedVec`] never copies
+//! its content, and no giant contiguous allocation is needed even for very
+//! large sequences. Access requires just a shift and a mask to locate the
+//! page and the offset within it.
+//!
+//! [`PagedVec`] implements all by-value traits, including [`VecByValue`];
+//! since its values are contiguous only within a page,
+//! [`try_chunks_mut`](SliceByValueMut::try_chunks_mut) succeeds only if the
+//! chunk size divides the page size:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::paged::PagedVec;
+//! use value_traits::slices::*;
+//! use value_traits::vecs::VecByValue;
+//!
+//! let mut v = PagedVec::with_page_size(4);
+//! for x in 0..10 {
+//!     v.push_value(x);
+//! }
+//! assert_eq!(v.num_pages(), 3);
+//! assert_eq!(v.index_value(9), 9);
+//!
+//! for mut chunk in v.try_chunks_mut(2).unwrap() {
+//!     chunk.set_value(0, 0);
+//! }
+//! assert_eq!(v.index_value(8), 0);
+//! assert!(v.try_chunks_mut(3).is_err());
+//! # }
+//! ```
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::{
+    hash::{Hash, Hasher},
+    iter::{Cloned, Flatten, FusedIterator, Skip},
+};
+
+use crate::{
+    adapters::subslice::{SubsliceOf, SubsliceOfMut, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::{assert_from, assert_range},
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut,
+    },
+    vecs::VecByValue,
+};
+
+/// The default page size of a [`PagedVec`], in values.
+pub const DEFAULT_PAGE_SIZE: usize = 1 << 16;
+
+/// A growable by-value vector storing values in fixed-size pages.
+///
+/// Equality and hashing depend only on the values, and not on the page size.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct PagedVec<T> {
+    /// The pages; all pages but the last one are full, and no page is empty.
+    pages: Vec<Vec<T>>,
+    /// The base-two logarithm of the page size.
+    log2_page_size: u32,
+    /// The number of values.
+    len: usize,
+}
+
+impl<T> PagedVec<T> {
+    /// Creates a new empty vector with [default page
+    /// size](DEFAULT_PAGE_SIZE).
+    pub fn new() -> Self {
+        Self::with_page_size(DEFAULT_PAGE_SIZE)
+    }
+
+    /// Creates a new empty vector with the given page size.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the page size is not a power of two.
+    pub fn with_page_size(page_size: usize) -> Self {
+        assert!(
+            page_size.is_power_of_two(),
+            "the page size must be a power of two"
+        );
+        Self {
+            pages: Vec::new(),
+            log2_page_size: page_size.trailing_zeros(),
+            len: 0,
+        }
+    }
+
+    /// Returns the page size.
+    #[inline(always)]
+    pub fn page_size(&self) -> usize {
+        1 << self.log2_page_size
+    }
+
+    /// Returns the number of allocated pages.
+    #[inline(always)]
+    pub fn num_pages(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns the given page as a standard slice, or `None` if the index
+    /// is out of bounds.
+    #[inline]
+    pub fn page(&self, index: usize) -> Option<&[T]> {
+        self.pages.get(index).map(Vec::as_slice)
+    }
+
+    #[inline(always)]
+    fn offset_mask(&self) -> usize {
+        self.page_size() - 1
+    }
+}
+
+impl<T> Default for PagedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for PagedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let page_size = self.page_size();
+        for value in iter {
+            match self.pages.last_mut() {
+                Some(page) if page.len() < page_size => page.push(value),
+                _ => {
+                    let mut page = Vec::with_capacity(page_size);
+                    page.push(value);
+                    self.pages.push(page);
+                }
+            }
+            self.len += 1;
+        }
+    }
+}
+
+impl<T> FromIterator<T> for PagedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut v = Self::new();
+        v.extend(iter);
+        v
+    }
+}
+
+impl<T: PartialEq> PartialEq for PagedVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.pages.iter().flatten().eq(other.pages.iter().flatten())
+    }
+}
+
+impl<T: Eq> Eq for PagedVec<T> {}
+
+impl<T: Hash> Hash for PagedVec<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.pages.iter().flatten() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: Clone> SliceByValue for PagedVec<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds, so are page and offset
+        unsafe {
+            self.pages
+                .get_unchecked(index >> self.log2_page_size)
+                .get_unchecked(index & self.offset_mask())
+                .clone()
+        }
+    }
+}
+
+/// Error returned by [`try_chunks_mut`](SliceByValueMut::try_chunks_mut) on
+/// a [`PagedVec`] when the chunk size does not divide the page size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnalignedChunkSize {
+    chunk_size: usize,
+    page_size: usize,
+}
+
+impl UnalignedChunkSize {
+    /// Returns the requested chunk size.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the page size.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+}
+
+impl core::fmt::Display for UnalignedChunkSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "chunk size {} does not divide page size {}",
+            self.chunk_size, self.page_size
+        )
+    }
+}
+
+impl core::error::Error for UnalignedChunkSize {}
+
+/// An iterator over mutable chunks of a [`PagedVec`].
+///
+/// Chunks are obtained by splitting each page, so they are standard mutable
+/// slices.
+#[derive(Debug)]
+pub struct PagedChunksMut<'a, T> {
+    pages: core::slice::IterMut<'a, Vec<T>>,
+    chunks: core::slice::ChunksMut<'a, T>,
+    chunk_size: usize,
+}
+
+impl<'a, T> Iterator for PagedChunksMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(chunk) = self.chunks.next() {
+                return Some(chunk);
+            }
+            self.chunks = self.pages.next()?.chunks_mut(self.chunk_size);
+        }
+    }
+}
+
+impl<T> FusedIterator for PagedChunksMut<'_, T> {}
+
+impl<T: Clone> SliceByValueMut for PagedVec<T> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        let mask = self.offset_mask();
+        // SAFETY: index is within bounds, so are page and offset
+        unsafe {
+            *self
+                .pages
+                .get_unchecked_mut(index >> self.log2_page_size)
+                .get_unchecked_mut(index & mask) = value;
+        }
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        let mask = self.offset_mask();
+        // SAFETY: index is within bounds, so are page and offset
+        unsafe {
+            core::mem::replace(
+                self.pages
+                    .get_unchecked_mut(index >> self.log2_page_size)
+                    .get_unchecked_mut(index & mask),
+                value,
+            )
+        }
+    }
+
+    type ChunksMut<'a>
+        = PagedChunksMut<'a, T>
+    where
+        Self: 'a;
+
+    type ChunksMutError = UnalignedChunkSize;
+
+    /// Returns an iterator over mutable chunks of the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnalignedChunkSize`] error if the chunk size is zero or
+    /// does not divide the page size.
+    fn try_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        let page_size = self.page_size();
+        if chunk_size == 0 || page_size % chunk_size != 0 {
+            return Err(UnalignedChunkSize {
+                chunk_size,
+                page_size,
+            });
+        }
+        Ok(PagedChunksMut {
+            pages: self.pages.iter_mut(),
+            chunks: <&mut [T]>::default().chunks_mut(chunk_size),
+            chunk_size,
+        })
+    }
+}
+
+impl<'a, T: Clone> SliceByValueSubsliceGat<'a> for PagedVec<T> {
+    type Subslice = SubsliceOf<'a, Self>;
+}
+
+impl<'a, T: Clone> SliceByValueSubsliceGatMut<'a> for PagedVec<T> {
+    type SubsliceMut = SubsliceOfMut<'a, Self>;
+}
+
+impl_subslice_of_ranges!(mut PagedVec<T>, T: Clone);
+
+impl<'a, T: Clone> IterateByValueGat<'a> for PagedVec<T> {
+    type Item = T;
+    type Iter = Cloned<Flatten<core::slice::Iter<'a, Vec<T>>>>;
+}
+
+impl<T: Clone> IterateByValue for PagedVec<T> {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.pages.iter().flatten().cloned()
+    }
+}
+
+impl<'a, T: Clone> IterateByValueFromGat<'a> for PagedVec<T> {
+    type Item = T;
+    type IterFrom = Cloned<Skip<Flatten<core::slice::Iter<'a, Vec<T>>>>>;
+}
+
+impl<T: Clone> IterateByValueFrom for PagedVec<T> {
+    /// # Panics
+    ///
+    /// This method will panic if `from` is greater than the length of the
+    /// slice.
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        assert_from(from, self.len);
+        // Skip whole pages, and then values within the first page
+        let page = (from >> self.log2_page_size).min(self.pages.len());
+        self.pages[page..]
+            .iter()
+            .flatten()
+            .skip(from - (page << self.log2_page_size))
+            .cloned()
+    }
+}
+
+impl<T: Clone> ExactSizeIterateByValue for PagedVec<T> {}
+
+impl<T: Clone> VecByValue for PagedVec<T> {
+    #[inline]
+    fn push_value(&mut self, value: Self::Value) {
+        self.extend(core::iter::once(value));
+    }
+
+    fn pop_value(&mut self) -> Option<Self::Value> {
+        let page = self.pages.last_mut()?;
+        // SAFETY: pages are never empty
+        let value = unsafe { page.pop().unwrap_unchecked() };
+        if page.is_empty() {
+            self.pages.pop();
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn truncate_values(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let num_pages = len.div_ceil(self.page_size());
+        self.pages.truncate(num_pages);
+        if let Some(page) = self.pages.last_mut() {
+            page.truncate(len - ((num_pages - 1) << self.log2_page_size));
+        }
+        self.len = len;
+    }
+
+    /// Removes the values in the given range, returning them as a by-value
+    /// iterator.
+    ///
+    /// Differently from [`Vec::drain`], the values are removed immediately
+    /// and collected in a vector, and the following values are moved one by
+    /// one to fill the gap.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn drain_values<R: ComposeRange>(
+        &mut self,
+        range: R,
+    ) -> impl Iterator<Item = Self::Value> + '_ {
+        assert_range(&range, self.len);
+        let range = range.compose(0..self.len);
+        let drained: Vec<T> = range
+            .clone()
+            // SAFETY: range is within bounds
+            .map(|i| unsafe { self.get_value_unchecked(i) })
+            .collect();
+        let removed = range.len();
+        for i in range.end..self.len {
+            // SAFETY: i and i - removed are within bounds
+            unsafe {
+                let value = self.get_value_unchecked(i);
+                self.set_value_unchecked(i - removed, value);
+            }
+        }
+        self.truncate_values(self.len - removed);
+        drained.into_iter()
+    }
+}
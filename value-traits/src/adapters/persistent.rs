@@ -0,0 +1,353 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Persistent by-value slices with cheap snapshots.
+//!
+//! A [`PersistentSlice`] stores its values in the leaves of a tree with
+//! branching factor [`BRANCHING`], whose nodes are shared using reference
+//! counting. Cloning a persistent slice takes constant time, and all clones
+//! are immutable versions implementing the read-only by-value traits.
+//!
+//! New versions are created by a [`PersistentBuilder`], which implements the
+//! mutable by-value traits: each write copies only the nodes on the path
+//! from the root to the modified leaf that are shared with other versions,
+//! so it takes time *O*(log *n*), where *n* is the length of the slice, and
+//! all other nodes are shared with the previous version:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::persistent::PersistentSlice;
+//! use value_traits::slices::*;
+//!
+//! let v0: PersistentSlice<u32> = (0..100_000).collect();
+//! let mut builder = v0.builder();
+//! builder.set_value(42, 0);
+//! let v1 = builder.snapshot();
+//! builder.set_value(43, 0);
+//! let v2 = builder.build();
+//!
+//! assert_eq!((v0.index_value(42), v0.index_value(43)), (42, 43));
+//! assert_eq!((v1.index_value(42), v1.index_value(43)), (0, 43));
+//! assert_eq!((v2.index_value(42), v2.index_value(43)), (0, 0));
+//! # }
+//! ```
+//!
+//! Random access takes time *O*(log *n*), too, but since the branching factor
+//! is large, trees are very shallow: a tree with a million values has four
+//! levels.
+//!
+//! As in the case of other by-value slices, the length of a persistent slice
+//! cannot be changed.
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use crate::{
+    adapters::subslice::{SubsliceIter, SubsliceOf, SubsliceOfMut, impl_subslice_of_ranges},
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::{assert_from, assert_index},
+    slices::{
+        ChunksMutNotSupported, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut,
+    },
+};
+
+const BITS: usize = 5;
+
+/// The branching factor of the trees of a [`PersistentSlice`], which is
+/// also the number of values in each leaf.
+pub const BRANCHING: usize = 1 << BITS;
+
+const MASK: usize = BRANCHING - 1;
+
+#[derive(Clone)]
+enum Node<V> {
+    Leaf(Vec<V>),
+    Inner(Vec<Arc<Node<V>>>),
+}
+
+/// An immutable version of a persistent by-value slice.
+///
+/// Persistent slices can be created from vectors or iterators. Clones share
+/// all nodes, so they take constant time.
+///
+/// See the [module documentation](self).
+pub struct PersistentSlice<V> {
+    root: Arc<Node<V>>,
+    len: usize,
+    /// The number of inner levels of the tree.
+    height: usize,
+}
+
+impl<V> Clone for PersistentSlice<V> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+            height: self.height,
+        }
+    }
+}
+
+impl<V: Clone + core::fmt::Debug> core::fmt::Debug for PersistentSlice<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // SAFETY: i is within bounds
+        f.debug_list()
+            .entries((0..self.len).map(|i| unsafe { self.get_value_unchecked(i) }))
+            .finish()
+    }
+}
+
+impl<V> FromIterator<V> for PersistentSlice<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut len = 0;
+        let mut values = iter.into_iter();
+        let mut nodes = Vec::new();
+        loop {
+            let leaf: Vec<V> = values.by_ref().take(BRANCHING).collect();
+            if leaf.is_empty() {
+                break;
+            }
+            len += leaf.len();
+            nodes.push(Arc::new(Node::Leaf(leaf)));
+        }
+        let mut height = 0;
+        while nodes.len() > 1 {
+            let mut children = nodes.into_iter();
+            nodes = Vec::new();
+            loop {
+                let inner: Vec<_> = children.by_ref().take(BRANCHING).collect();
+                if inner.is_empty() {
+                    break;
+                }
+                nodes.push(Arc::new(Node::Inner(inner)));
+            }
+            height += 1;
+        }
+        Self {
+            root: nodes
+                .pop()
+                .unwrap_or_else(|| Arc::new(Node::Leaf(Vec::new()))),
+            len,
+            height,
+        }
+    }
+}
+
+impl<V> From<Vec<V>> for PersistentSlice<V> {
+    fn from(values: Vec<V>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<V: Clone> PersistentSlice<V> {
+    /// Returns a builder for new versions, starting from this version.
+    ///
+    /// This method takes constant time, as the builder shares all nodes
+    /// with this version.
+    pub fn builder(&self) -> PersistentBuilder<V> {
+        PersistentBuilder(self.clone())
+    }
+
+    /// Returns a new version in which the value at the given index has been
+    /// replaced.
+    ///
+    /// This is a shorthand for creating a [builder](PersistentSlice::builder),
+    /// setting a single value, and building the new version.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is out of bounds.
+    pub fn with_value(&self, index: usize, value: V) -> Self {
+        assert_index(index, self.len);
+        let mut new = self.clone();
+        new.replace(index, value);
+        new
+    }
+
+    /// Returns whether the two versions share the root of their tree, in
+    /// which case they contain the same values.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.root, &other.root)
+    }
+
+    /// Replaces the value at the given index, copying the shared nodes on
+    /// the path to its leaf.
+    fn replace(&mut self, index: usize, value: V) -> V {
+        let mut node = Arc::make_mut(&mut self.root);
+        let mut level = self.height;
+        loop {
+            match node {
+                Node::Inner(children) => {
+                    node = Arc::make_mut(&mut children[(index >> (BITS * level)) & MASK]);
+                    level -= 1;
+                }
+                Node::Leaf(values) => return core::mem::replace(&mut values[index & MASK], value),
+            }
+        }
+    }
+}
+
+impl<V: Clone> SliceByValue for PersistentSlice<V> {
+    type Value = V;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> V {
+        let mut node = &*self.root;
+        let mut level = self.height;
+        loop {
+            match node {
+                Node::Inner(children) => {
+                    node = &children[(index >> (BITS * level)) & MASK];
+                    level -= 1;
+                }
+                Node::Leaf(values) => return values[index & MASK].clone(),
+            }
+        }
+    }
+}
+
+/// A builder for new versions of a [`PersistentSlice`].
+///
+/// The builder implements the mutable by-value traits, and writes copy only
+/// the nodes shared with other versions, so a node is copied at most once
+/// during the lifetime of a builder. The current state of the builder can be
+/// turned into a version with [`snapshot`](PersistentBuilder::snapshot),
+/// in constant time.
+///
+/// See the [module documentation](self).
+#[derive(Clone)]
+pub struct PersistentBuilder<V>(PersistentSlice<V>);
+
+impl<V: Clone + core::fmt::Debug> core::fmt::Debug for PersistentBuilder<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PersistentBuilder").field(&self.0).finish()
+    }
+}
+
+impl<V: Clone> PersistentBuilder<V> {
+    /// Returns a version containing the current values of the builder.
+    ///
+    /// This method takes constant time, as the version shares all nodes
+    /// with the builder; subsequent writes will copy them as needed.
+    pub fn snapshot(&self) -> PersistentSlice<V> {
+        self.0.clone()
+    }
+
+    /// Returns a version containing the current values of the builder,
+    /// consuming it.
+    pub fn build(self) -> PersistentSlice<V> {
+        self.0
+    }
+}
+
+impl<V: Clone> SliceByValue for PersistentBuilder<V> {
+    type Value = V;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> V {
+        // SAFETY: index is within bounds
+        unsafe { self.0.get_value_unchecked(index) }
+    }
+}
+
+impl<V: Clone> SliceByValueMut for PersistentBuilder<V> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: V) {
+        self.0.replace(index, value);
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: V) -> V {
+        self.0.replace(index, value)
+    }
+
+    type ChunksMut<'a>
+        = core::iter::Empty<&'a mut Self>
+    where
+        Self: 'a;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    /// Returns always an error, as the values are not stored contiguously.
+    ///
+    /// # Errors
+    ///
+    /// Returns always [`ChunksMutNotSupported`].
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+impl<'a, V: Clone> SliceByValueSubsliceGatMut<'a> for PersistentBuilder<V> {
+    type SubsliceMut = SubsliceOfMut<'a, Self>;
+}
+
+macro_rules! impl_persistent {
+    ($ty:ident) => {
+        impl<'a, V: Clone> SliceByValueSubsliceGat<'a> for $ty<V> {
+            type Subslice = SubsliceOf<'a, Self>;
+        }
+
+        impl<'a, V: Clone> IterateByValueGat<'a> for $ty<V> {
+            type Item = V;
+            type Iter = SubsliceIter<'a, Self>;
+        }
+
+        impl<V: Clone> IterateByValue for $ty<V> {
+            fn iter_value(&self) -> Iter<'_, Self> {
+                SubsliceIter::new(self, 0..self.len())
+            }
+        }
+
+        impl<'a, V: Clone> IterateByValueFromGat<'a> for $ty<V> {
+            type Item = V;
+            type IterFrom = SubsliceIter<'a, Self>;
+        }
+
+        impl<V: Clone> IterateByValueFrom for $ty<V> {
+            /// # Panics
+            ///
+            /// This method will panic if `from` is greater than the length of
+            /// the slice.
+            fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+                assert_from(from, self.len());
+                SubsliceIter::new(self, from..self.len())
+            }
+        }
+
+        impl<V: Clone> ExactSizeIterateByValue for $ty<V> {}
+    };
+}
+
+impl_persistent!(PersistentSlice);
+impl_persistent!(PersistentBuilder);
+
+impl_subslice_of_ranges!(PersistentSlice<V>, V: Clone);
+impl_subslice_of_ranges!(mut PersistentBuilder<V>, V: Clone);
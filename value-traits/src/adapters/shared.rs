@@ -0,0 +1,294 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Shared read-only views with `'static` iterators.
+//!
+//! The by-value iterators of this crate borrow the slice they iterate on, so
+//! they cannot be moved into spawned threads or tasks. [`ArcValues`] wraps an
+//! [`Arc`] to a by-value slice, and its
+//! [`into_iter_value`](ArcValues::into_iter_value) method returns an
+//! iterator holding the [`Arc`], which is `'static` (and [`Send`], if the
+//! slice is [`Send`] and [`Sync`]).
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::thread;
+//! use value_traits::adapters::shared::ArcValues;
+//!
+//! let values = ArcValues::new(Arc::<[u64]>::from(vec![1, 2, 3, 4]));
+//! let handles = (0..2)
+//!     .map(|t| {
+//!         let iter = values.iter_value_shared_from(t * 2);
+//!         thread::spawn(move || iter.take(2).sum::<u64>())
+//!     })
+//!     .collect::<Vec<_>>();
+//! let sums = handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>();
+//! assert_eq!(sums, [3, 7]);
+//! ```
+//!
+//! A [`Snapshot`] is an iteration-stable copy of a by-value slice behind an
+//! [`Arc`] that a writer keeps modifying using [`Arc::make_mut`]: small
+//! slices are cloned, so that the writer is not forced to copy them
+//! later, whereas large slices are shared, and copied by the writer only if
+//! they are modified while the snapshot is alive:
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use value_traits::adapters::shared::Snapshot;
+//! use value_traits::slices::*;
+//!
+//! let mut data = Arc::new(vec![1, 2, 3, 4]);
+//! let snapshot = Snapshot::new(&data, 2);
+//! assert!(snapshot.is_shared());
+//! Arc::make_mut(&mut data).set_value(0, 10);
+//! assert_eq!(snapshot.index_value(0), 1);
+//! assert_eq!(snapshot.into_iter_value().collect::<Vec<_>>(), [1, 2, 3, 4]);
+//! ```
+//!
+//! These adapters are available only if the `std` feature is enabled.
+
+#![cfg(feature = "std")]
+
+use core::{iter::FusedIterator, ops::Range};
+use std::sync::Arc;
+
+use crate::{panics::assert_from, slices::SliceByValue};
+
+/// A shared read-only view on a by-value slice whose iterators own a
+/// reference to the slice.
+///
+/// See the [module documentation](self).
+#[derive(Debug)]
+pub struct ArcValues<S: ?Sized>(Arc<S>);
+
+impl<S: ?Sized> Clone for ArcValues<S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<S: SliceByValue + ?Sized> ArcValues<S> {
+    /// Creates a new shared view on the slice pointed by `ptr`.
+    pub fn new(ptr: Arc<S>) -> Self {
+        Self(ptr)
+    }
+
+    /// Returns the underlying pointer.
+    pub fn into_inner(self) -> Arc<S> {
+        self.0
+    }
+
+    /// Returns an iterator on the values of the slice owning this view.
+    pub fn into_iter_value(self) -> ArcValuesIter<S> {
+        self.into_iter_value_from(0)
+    }
+
+    /// Returns an iterator on the values of the slice starting at the given
+    /// position and owning this view.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `from` is greater than the length of the
+    /// slice.
+    pub fn into_iter_value_from(self, from: usize) -> ArcValuesIter<S> {
+        let len = self.0.len();
+        assert_from(from, len);
+        ArcValuesIter {
+            slice: self.0,
+            range: from..len,
+        }
+    }
+
+    /// Returns an iterator on the values of the slice holding a new
+    /// reference to the slice.
+    ///
+    /// This method is equivalent to `self.clone().into_iter_value()`.
+    pub fn iter_value_shared(&self) -> ArcValuesIter<S> {
+        self.clone().into_iter_value()
+    }
+
+    /// Returns an iterator on the values of the slice starting at the given
+    /// position and holding a new reference to the slice.
+    ///
+    /// This method is equivalent to `self.clone().into_iter_value_from(from)`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `from` is greater than the length of the
+    /// slice.
+    pub fn iter_value_shared_from(&self, from: usize) -> ArcValuesIter<S> {
+        self.clone().into_iter_value_from(from)
+    }
+}
+
+impl<S: SliceByValue + ?Sized> From<Arc<S>> for ArcValues<S> {
+    fn from(ptr: Arc<S>) -> Self {
+        Self::new(ptr)
+    }
+}
+
+impl<S: SliceByValue + ?Sized> SliceByValue for ArcValues<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.0.get_value(index)
+    }
+
+    #[inline]
+    fn index_value(&self, index: usize) -> Self::Value {
+        self.0.index_value(index)
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        unsafe { self.0.get_value_unchecked(index) }
+    }
+}
+
+impl<S: SliceByValue + ?Sized> IntoIterator for ArcValues<S> {
+    type Item = S::Value;
+    type IntoIter = ArcValuesIter<S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_value()
+    }
+}
+
+/// An iterator on the values of a by-value slice owning an [`Arc`] to the
+/// slice.
+///
+/// See [`ArcValues::into_iter_value`].
+#[derive(Debug)]
+pub struct ArcValuesIter<S: ?Sized> {
+    slice: Arc<S>,
+    range: Range<usize>,
+}
+
+impl<S: ?Sized> Clone for ArcValuesIter<S> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<S: SliceByValue + ?Sized> Iterator for ArcValuesIter<S> {
+    type Item = S::Value;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range
+            .next()
+            // SAFETY: index is within bounds
+            .map(|index| unsafe { self.slice.get_value_unchecked(index) })
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range
+            .nth(n)
+            // SAFETY: index is within bounds
+            .map(|index| unsafe { self.slice.get_value_unchecked(index) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<S: SliceByValue + ?Sized> DoubleEndedIterator for ArcValuesIter<S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range
+            .next_back()
+            // SAFETY: index is within bounds
+            .map(|index| unsafe { self.slice.get_value_unchecked(index) })
+    }
+}
+
+impl<S: SliceByValue + ?Sized> ExactSizeIterator for ArcValuesIter<S> {}
+
+impl<S: SliceByValue + ?Sized> FusedIterator for ArcValuesIter<S> {}
+
+/// An iteration-stable snapshot of a by-value slice behind an [`Arc`].
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone)]
+pub enum Snapshot<S> {
+    /// A clone of a small slice.
+    Cloned(S),
+    /// A shared reference to a large slice.
+    Shared(Arc<S>),
+}
+
+impl<S: SliceByValue + Clone> Snapshot<S> {
+    /// Creates a snapshot of the slice pointed by `source`.
+    ///
+    /// If the length of the slice is at most `threshold`, the slice is
+    /// cloned; otherwise, the pointer is cloned.
+    pub fn new(source: &Arc<S>, threshold: usize) -> Self {
+        if source.len() <= threshold {
+            Self::Cloned(S::clone(source))
+        } else {
+            Self::Shared(source.clone())
+        }
+    }
+
+    /// Returns `true` if the snapshot shares the slice with its source.
+    pub fn is_shared(&self) -> bool {
+        matches!(self, Self::Shared(_))
+    }
+
+    /// Returns a shared view on the snapshot.
+    pub fn into_arc_values(self) -> ArcValues<S> {
+        match self {
+            Self::Cloned(slice) => ArcValues::new(Arc::new(slice)),
+            Self::Shared(ptr) => ArcValues::new(ptr),
+        }
+    }
+
+    /// Returns an iterator on the values of the snapshot owning the
+    /// snapshot.
+    ///
+    /// See [`ArcValues::into_iter_value`].
+    pub fn into_iter_value(self) -> ArcValuesIter<S> {
+        self.into_arc_values().into_iter_value()
+    }
+}
+
+impl<S: SliceByValue> SliceByValue for Snapshot<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        match self {
+            Self::Cloned(slice) => slice.len(),
+            Self::Shared(ptr) => ptr.len(),
+        }
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe {
+            match self {
+                Self::Cloned(slice) => slice.get_value_unchecked(index),
+                Self::Shared(ptr) => ptr.get_value_unchecked(index),
+            }
+        }
+    }
+}
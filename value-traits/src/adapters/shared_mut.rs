@@ -0,0 +1,279 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Mutable by-value slices lending disjoint ranges with runtime checks.
+//!
+//! [`try_chunks_mut`](crate::slices::SliceByValueMut::try_chunks_mut) splits
+//! a mutable slice statically into chunks of the same size. When the ranges
+//! to be modified are computed dynamically, as it happens, for example, in
+//! task graphs, a [`SharedMutSlice`] makes it possible to check out mutable
+//! views on arbitrary disjoint ranges from a shared reference, much like a
+//! [`RefCell`] does for a whole value:
+//! [`borrow_mut`](SharedMutSlice::borrow_mut) returns a [`SharedRangeMut`]
+//! guard implementing [`SliceByValueMut`], and panics if the range overlaps
+//! the range of a guard still alive;
+//! [`try_borrow_mut`](SharedMutSlice::try_borrow_mut) returns instead a
+//! [`RangeBorrowed`] error:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::shared_mut::SharedMutSlice;
+//! use value_traits::slices::*;
+//!
+//! let shared = SharedMutSlice::new(vec![0; 10]);
+//! let mut a = shared.borrow_mut(0..4);
+//! let mut b = shared.borrow_mut(6..);
+//! a.set_value(0, 1);
+//! b.set_value(0, 2);
+//! assert!(shared.try_borrow_mut(3..7).is_err());
+//! drop(a);
+//! assert!(shared.try_borrow_mut(3..6).is_ok());
+//! drop(b);
+//! assert_eq!(shared.into_inner(), [1, 0, 0, 0, 0, 0, 2, 0, 0, 0]);
+//! # }
+//! ```
+//!
+//! As for [`RefCell`], checks are not thread safe, so a [`SharedMutSlice`]
+//! is not [`Sync`].
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::{
+    cell::{RefCell, UnsafeCell},
+    ops::Range,
+};
+
+use crate::{
+    panics::{assert_range, fail},
+    slices::{ChunksMutNotSupported, ComposeRange, SliceByValue, SliceByValueMut},
+};
+
+/// Error returned by [`SharedMutSlice::try_borrow_mut`] when the requested
+/// range overlaps a range that is already borrowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeBorrowed {
+    range: Range<usize>,
+}
+
+impl RangeBorrowed {
+    /// Returns the borrowed range overlapping the requested one.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl core::fmt::Display for RangeBorrowed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "range {}..{} is already mutably borrowed",
+            self.range.start, self.range.end
+        )
+    }
+}
+
+impl core::error::Error for RangeBorrowed {}
+
+/// A mutable by-value slice lending mutable views on disjoint ranges from a
+/// shared reference.
+///
+/// See the [module documentation](self).
+pub struct SharedMutSlice<S> {
+    slice: UnsafeCell<S>,
+    borrowed: RefCell<Vec<Range<usize>>>,
+}
+
+impl<S: SliceByValueMut> SharedMutSlice<S> {
+    /// Wraps the given slice.
+    pub fn new(slice: S) -> Self {
+        Self {
+            slice: UnsafeCell::new(slice),
+            borrowed: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the length of the wrapped slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        // SAFETY: no guard holds a reference to the slice across calls
+        unsafe { &*self.slice.get() }.len()
+    }
+
+    /// Returns `true` if the wrapped slice is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of ranges that are currently borrowed.
+    pub fn num_borrowed(&self) -> usize {
+        self.borrowed.borrow().len()
+    }
+
+    /// Returns a mutable view on the given range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds, or if it
+    /// overlaps a range that is already borrowed.
+    pub fn borrow_mut(&self, range: impl ComposeRange) -> SharedRangeMut<'_, S> {
+        match self.try_borrow_mut(range) {
+            Ok(guard) => guard,
+            Err(e) => fail!("range is already mutably borrowed", "{}", e),
+        }
+    }
+
+    /// Returns a mutable view on the given range, or an error if the range
+    /// overlaps a range that is already borrowed.
+    ///
+    /// Empty ranges never overlap other ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RangeBorrowed`] error containing a borrowed range that
+    /// overlaps the requested one.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    pub fn try_borrow_mut(
+        &self,
+        range: impl ComposeRange,
+    ) -> Result<SharedRangeMut<'_, S>, RangeBorrowed> {
+        let len = self.len();
+        assert_range(&range, len);
+        let range = range.compose(0..len);
+        // Empty ranges are not recorded
+        if !range.is_empty() {
+            let mut borrowed = self.borrowed.borrow_mut();
+            if let Some(other) = borrowed
+                .iter()
+                .find(|other| other.start < range.end && range.start < other.end)
+            {
+                return Err(RangeBorrowed {
+                    range: other.clone(),
+                });
+            }
+            borrowed.push(range.clone());
+        }
+        Ok(SharedRangeMut {
+            shared: self,
+            range,
+        })
+    }
+
+    /// Returns a mutable reference to the wrapped slice.
+    ///
+    /// Since this method borrows `self` mutably, no guard can be alive.
+    pub fn get_mut(&mut self) -> &mut S {
+        self.slice.get_mut()
+    }
+
+    /// Returns the wrapped slice.
+    pub fn into_inner(self) -> S {
+        self.slice.into_inner()
+    }
+}
+
+impl<S> core::fmt::Debug for SharedMutSlice<S> {
+    /// Shows only the borrowed ranges, as values might be under modification.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedMutSlice")
+            .field("borrowed", &*self.borrowed.borrow())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A mutable view on a range of a [`SharedMutSlice`].
+///
+/// The range is released when the guard is dropped.
+///
+/// See the [module documentation](self).
+pub struct SharedRangeMut<'a, S: SliceByValueMut> {
+    shared: &'a SharedMutSlice<S>,
+    range: Range<usize>,
+}
+
+impl<S: SliceByValueMut> SharedRangeMut<'_, S> {
+    /// Returns the range of the wrapped slice covered by this view.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl<S: SliceByValueMut> Drop for SharedRangeMut<'_, S> {
+    fn drop(&mut self) {
+        if self.range.is_empty() {
+            return;
+        }
+        let mut borrowed = self.shared.borrowed.borrow_mut();
+        if let Some(pos) = borrowed.iter().position(|r| *r == self.range) {
+            borrowed.swap_remove(pos);
+        }
+    }
+}
+
+impl<S: SliceByValueMut> core::fmt::Debug for SharedRangeMut<'_, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedRangeMut")
+            .field("range", &self.range)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValue for SharedRangeMut<'_, S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: references to the slice do not outlive calls, and index is
+        // within bounds
+        unsafe { (*self.shared.slice.get()).get_value_unchecked(self.range.start + index) }
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValueMut for SharedRangeMut<'_, S> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: references to the slice do not outlive calls, the range is
+        // borrowed exclusively, and index is within bounds
+        unsafe { (*self.shared.slice.get()).set_value_unchecked(self.range.start + index, value) }
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: references to the slice do not outlive calls, the range is
+        // borrowed exclusively, and index is within bounds
+        unsafe {
+            (*self.shared.slice.get()).replace_value_unchecked(self.range.start + index, value)
+        }
+    }
+
+    type ChunksMut<'b>
+        = core::iter::Empty<&'b mut Self>
+    where
+        Self: 'b;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
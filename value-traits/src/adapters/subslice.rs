@@ -0,0 +1,946 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Generic subslices of by-value slices.
+//!
+//! [`SubsliceOf`] and [`SubsliceOfMut`] hold a (mutable) reference to a
+//! by-value slice and a range, and implement the by-value traits by
+//! delegating to the underlying slice; their iterator is [`SubsliceIter`].
+//! These are the subslices used by the derive macros, but they are also
+//! useful when building views manually, for example to implement
+//! subslicing for a type without using the derive macros: the checked
+//! constructors validate the range, and the accessors
+//! [`range`](SubsliceOf::range) and [`base`](SubsliceOf::base) make it
+//! possible to inspect the view.
+//!
+//! ```rust
+//! use value_traits::adapters::subslice::SubsliceOf;
+//! use value_traits::slices::*;
+//!
+//! let v = [0, 1, 2, 3, 4, 5];
+//! let s = SubsliceOf::new(&v, 1..5).unwrap();
+//! assert_eq!(s.range(), 1..5);
+//! assert_eq!(s.index_value(0), 1);
+//! // Subslices of subslices refer to the same base
+//! let t = s.index_subslice(2..);
+//! assert_eq!(t.range(), 3..5);
+//! assert_eq!(t.index_value(0), 3);
+//!
+//! let err = SubsliceOf::new(&v, 4..7).unwrap_err();
+//! assert_eq!(err.to_string(), "range 4..7 out of range for slice of length 6");
+//! ```
+
+use core::{
+    fmt,
+    iter::FusedIterator,
+    num::NonZeroUsize,
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use crate::{
+    iter::{
+        ConsistentIteration, ExactSizeIterateByValue, Iter, IterFrom, IterateByValue,
+        IterateByValueFrom, IterateByValueFromGat, IterateByValueGat, SeekableValueIter,
+    },
+    panics::{assert_dest, assert_from, assert_range},
+    slices::{
+        ChunksMutNotSupported, ComposeRange, InvalidRange, SliceByValue, SliceByValueMut,
+        SliceByValueSubsliceGat, SliceByValueSubsliceGatMut, SliceByValueSubsliceRange,
+        SliceByValueSubsliceRangeMut, Subslice, SubsliceMut,
+    },
+};
+
+/// A read-only subslice of a by-value slice.
+///
+/// See the [module documentation](self).
+pub struct SubsliceOf<'a, S: ?Sized> {
+    slice: &'a S,
+    range: Range<usize>,
+}
+
+impl<S: ?Sized> Clone for SubsliceOf<'_, S> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<'a, S: SliceByValue + ?Sized> SubsliceOf<'a, S> {
+    /// Creates a new subslice of `slice` covering the given range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidRange`] error if the range is not within the
+    /// bounds of the slice.
+    pub fn new(slice: &'a S, range: impl ComposeRange) -> Result<Self, InvalidRange> {
+        let len = slice.len();
+        if range.is_valid(len) {
+            Ok(Self {
+                slice,
+                range: range.compose(0..len),
+            })
+        } else {
+            Err(InvalidRange::new(&range, len))
+        }
+    }
+
+    /// Creates a new subslice of `slice` covering the given range without
+    /// checking it.
+    ///
+    /// # Safety
+    ///
+    /// The range must be within the bounds of the slice.
+    pub unsafe fn new_unchecked(slice: &'a S, range: Range<usize>) -> Self {
+        Self { slice, range }
+    }
+
+    /// Returns the range of the underlying slice covered by this subslice.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the underlying slice.
+    pub fn base(&self) -> &'a S {
+        self.slice
+    }
+
+    /// Returns an iterator over the values of this subslice with index
+    /// smaller than `end`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `end` is greater than the length of the
+    /// subslice.
+    #[inline]
+    pub fn iter_value_to(&self, end: usize) -> SubsliceIter<'a, S> {
+        self.iter_value_range(..end)
+    }
+
+    /// Returns an iterator over the values of this subslice with index in
+    /// the given range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within the bounds of the
+    /// subslice.
+    #[inline]
+    pub fn iter_value_range(&self, range: impl ComposeRange) -> SubsliceIter<'a, S> {
+        assert_range(&range, self.range.len());
+        SubsliceIter {
+            slice: self.slice,
+            range: range.compose(self.range.clone()),
+            offset: self.range.start,
+        }
+    }
+}
+
+/// A mutable subslice of a by-value slice.
+///
+/// See the [module documentation](self).
+pub struct SubsliceOfMut<'a, S: ?Sized> {
+    slice: &'a mut S,
+    range: Range<usize>,
+}
+
+impl<'a, S: SliceByValueMut + ?Sized> SubsliceOfMut<'a, S> {
+    /// Creates a new mutable subslice of `slice` covering the given range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidRange`] error if the range is not within the
+    /// bounds of the slice.
+    pub fn new(slice: &'a mut S, range: impl ComposeRange) -> Result<Self, InvalidRange> {
+        let len = slice.len();
+        if range.is_valid(len) {
+            Ok(Self {
+                slice,
+                range: range.compose(0..len),
+            })
+        } else {
+            Err(InvalidRange::new(&range, len))
+        }
+    }
+
+    /// Creates a new mutable subslice of `slice` covering the given range
+    /// without checking it.
+    ///
+    /// # Safety
+    ///
+    /// The range must be within the bounds of the slice.
+    pub unsafe fn new_unchecked(slice: &'a mut S, range: Range<usize>) -> Self {
+        Self { slice, range }
+    }
+
+    /// Returns the range of the underlying slice covered by this subslice.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the underlying slice.
+    pub fn base(&self) -> &S {
+        self.slice
+    }
+
+    /// Reborrows this mutable subslice as a read-only subslice covering the
+    /// same range.
+    #[inline]
+    pub fn as_subslice(&self) -> SubsliceOf<'_, S> {
+        SubsliceOf {
+            slice: &*self.slice,
+            range: self.range.clone(),
+        }
+    }
+
+    /// Returns an iterator over the values of this subslice with index
+    /// smaller than `end`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `end` is greater than the length of the
+    /// subslice.
+    #[inline]
+    pub fn iter_value_to(&self, end: usize) -> SubsliceIter<'_, S> {
+        self.iter_value_range(..end)
+    }
+
+    /// Returns an iterator over the values of this subslice with index in
+    /// the given range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within the bounds of the
+    /// subslice.
+    #[inline]
+    pub fn iter_value_range(&self, range: impl ComposeRange) -> SubsliceIter<'_, S> {
+        assert_range(&range, self.range.len());
+        SubsliceIter {
+            slice: &*self.slice,
+            range: range.compose(self.range.clone()),
+            offset: self.range.start,
+        }
+    }
+}
+
+impl<S: SliceByValue + ?Sized> SliceByValue for SubsliceOf<'_, S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds, and so is the range
+        unsafe { self.slice.get_value_unchecked(index + self.range.start) }
+    }
+}
+
+impl<S: SliceByValue + ?Sized> SliceByValue for SubsliceOfMut<'_, S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds, and so is the range
+        unsafe { self.slice.get_value_unchecked(index + self.range.start) }
+    }
+}
+
+impl<S: SliceByValueMut + ?Sized> SliceByValueMut for SubsliceOfMut<'_, S> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds, and so is the range
+        unsafe {
+            self.slice
+                .set_value_unchecked(index + self.range.start, value)
+        }
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds, and so is the range
+        unsafe {
+            self.slice
+                .replace_value_unchecked(index + self.range.start, value)
+        }
+    }
+
+    fn for_each_value_mut<R, F>(&mut self, range: R, mut f: F)
+    where
+        R: ComposeRange,
+        F: FnMut(usize, Self::Value) -> Option<Self::Value>,
+    {
+        assert_range(&range, self.range.len());
+        // Delegate to the underlying slice, which might provide an
+        // optimized implementation
+        let start = self.range.start;
+        self.slice
+            .for_each_value_mut(range.compose(self.range.clone()), |index, value| {
+                f(index - start, value)
+            });
+    }
+
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        let len = self.range.len();
+        assert_range(&src, len);
+        let src = src.compose(self.range.clone());
+        assert_dest(dst, src.len(), len);
+        // Delegate to the underlying slice, which might provide an
+        // optimized implementation
+        self.slice.copy_within(src, dst + self.range.start);
+    }
+
+    type ChunksMut<'b>
+        = core::iter::Empty<&'b mut Self>
+    where
+        Self: 'b;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+impl<'b, S: SliceByValue + ?Sized> SliceByValueSubsliceGat<'b> for SubsliceOf<'_, S> {
+    type Subslice = SubsliceOf<'b, S>;
+}
+
+impl<'b, S: SliceByValueMut + ?Sized> SliceByValueSubsliceGat<'b> for SubsliceOfMut<'_, S> {
+    type Subslice = SubsliceOf<'b, S>;
+}
+
+impl<'b, S: SliceByValueMut + ?Sized> SliceByValueSubsliceGatMut<'b> for SubsliceOfMut<'_, S> {
+    type SubsliceMut = SubsliceOfMut<'b, S>;
+}
+
+macro_rules! impl_range_subslice_of {
+    ($range:ty) => {
+        impl<S: SliceByValue + ?Sized> SliceByValueSubsliceRange<$range> for SubsliceOf<'_, S> {
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, range: $range) -> Subslice<'_, Self> {
+                SubsliceOf {
+                    slice: self.slice,
+                    range: range.compose(self.range.clone()),
+                }
+            }
+        }
+
+        impl<S: SliceByValueMut + ?Sized> SliceByValueSubsliceRange<$range>
+            for SubsliceOfMut<'_, S>
+        {
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, range: $range) -> Subslice<'_, Self> {
+                SubsliceOf {
+                    slice: &*self.slice,
+                    range: range.compose(self.range.clone()),
+                }
+            }
+        }
+
+        impl<S: SliceByValueMut + ?Sized> SliceByValueSubsliceRangeMut<$range>
+            for SubsliceOfMut<'_, S>
+        {
+            #[inline]
+            unsafe fn get_subslice_unchecked_mut(
+                &mut self,
+                range: $range,
+            ) -> SubsliceMut<'_, Self> {
+                SubsliceOfMut {
+                    slice: &mut *self.slice,
+                    range: range.compose(self.range.clone()),
+                }
+            }
+        }
+    };
+}
+
+impl_range_subslice_of!(RangeFull);
+impl_range_subslice_of!(RangeFrom<usize>);
+impl_range_subslice_of!(RangeTo<usize>);
+impl_range_subslice_of!(Range<usize>);
+impl_range_subslice_of!(RangeInclusive<usize>);
+impl_range_subslice_of!(RangeToInclusive<usize>);
+
+/// Implements [`SliceByValueSubsliceRange`] for all range types on a slice
+/// whose subslices are [`SubsliceOf`], and, if the type is preceded by
+/// `mut`, also [`SliceByValueSubsliceRangeMut`] with [`SubsliceOfMut`].
+///
+/// The type is followed by the generic parameters of the implementations,
+/// as in `impl_subslice_of_ranges!(mut Journaled<S>, S: SliceByValueMut)`.
+/// The [`SliceByValueSubsliceGat`] and [`SliceByValueSubsliceGatMut`]
+/// implementations must be provided separately.
+macro_rules! impl_subslice_of_ranges {
+    (@range $range:ty, $ty:ty, [$($bounds:tt)*]) => {
+        impl<$($bounds)*> $crate::slices::SliceByValueSubsliceRange<$range> for $ty {
+            #[inline]
+            unsafe fn get_subslice_unchecked(
+                &self,
+                range: $range,
+            ) -> $crate::slices::Subslice<'_, Self> {
+                let range = $crate::slices::ComposeRange::compose(
+                    &range,
+                    0..$crate::slices::SliceByValue::len(self),
+                );
+                // SAFETY: the range is within bounds
+                unsafe { $crate::adapters::subslice::SubsliceOf::new_unchecked(self, range) }
+            }
+        }
+    };
+    (@range_mut $range:ty, $ty:ty, [$($bounds:tt)*]) => {
+        impl<$($bounds)*> $crate::slices::SliceByValueSubsliceRangeMut<$range> for $ty {
+            #[inline]
+            unsafe fn get_subslice_unchecked_mut(
+                &mut self,
+                range: $range,
+            ) -> $crate::slices::SubsliceMut<'_, Self> {
+                let range = $crate::slices::ComposeRange::compose(
+                    &range,
+                    0..$crate::slices::SliceByValue::len(self),
+                );
+                // SAFETY: the range is within bounds
+                unsafe { $crate::adapters::subslice::SubsliceOfMut::new_unchecked(self, range) }
+            }
+        }
+    };
+    (@all $kind:ident, $ty:ty, $bounds:tt) => {
+        $crate::adapters::subslice::impl_subslice_of_ranges!(
+            @each $kind, $ty, $bounds,
+            ::core::ops::RangeFull,
+            ::core::ops::RangeFrom<usize>,
+            ::core::ops::RangeTo<usize>,
+            ::core::ops::Range<usize>,
+            ::core::ops::RangeInclusive<usize>,
+            ::core::ops::RangeToInclusive<usize>
+        );
+    };
+    (@each $kind:ident, $ty:ty, $bounds:tt, $($range:ty),*) => {
+        $(
+            $crate::adapters::subslice::impl_subslice_of_ranges!(@$kind $range, $ty, $bounds);
+        )*
+    };
+    (mut $ty:ty, $($bounds:tt)*) => {
+        $crate::adapters::subslice::impl_subslice_of_ranges!(@all range, $ty, [$($bounds)*]);
+        $crate::adapters::subslice::impl_subslice_of_ranges!(@all range_mut, $ty, [$($bounds)*]);
+    };
+    ($ty:ty, $($bounds:tt)*) => {
+        $crate::adapters::subslice::impl_subslice_of_ranges!(@all range, $ty, [$($bounds)*]);
+    };
+}
+
+pub(crate) use impl_subslice_of_ranges;
+
+macro_rules! impl_eq_debug_subslice_of {
+    ($subslice:ident) => {
+        impl<S: SliceByValue<Value: PartialEq> + ?Sized> PartialEq<[S::Value]>
+            for $subslice<'_, S>
+        {
+            fn eq(&self, other: &[S::Value]) -> bool {
+                self.len() == other.len()
+                    && other
+                        .iter()
+                        .enumerate()
+                        // SAFETY: i is within bounds
+                        .all(|(i, x)| unsafe { self.get_value_unchecked(i) } == *x)
+            }
+        }
+
+        impl<S: SliceByValue<Value: PartialEq> + ?Sized> PartialEq<&[S::Value]>
+            for $subslice<'_, S>
+        {
+            #[inline]
+            fn eq(&self, other: &&[S::Value]) -> bool {
+                *self == **other
+            }
+        }
+
+        impl<S: SliceByValue<Value: PartialEq> + ?Sized, const N: usize> PartialEq<[S::Value; N]>
+            for $subslice<'_, S>
+        {
+            #[inline]
+            fn eq(&self, other: &[S::Value; N]) -> bool {
+                *self == *other.as_slice()
+            }
+        }
+
+        impl<S: SliceByValue<Value: PartialEq> + ?Sized, const N: usize> PartialEq<&[S::Value; N]>
+            for $subslice<'_, S>
+        {
+            #[inline]
+            fn eq(&self, other: &&[S::Value; N]) -> bool {
+                *self == *other.as_slice()
+            }
+        }
+
+        /// Formats the subslice as a list of values.
+        impl<S: SliceByValue<Value: fmt::Debug> + ?Sized> fmt::Debug for $subslice<'_, S> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list()
+                    // SAFETY: i is within bounds
+                    .entries((0..self.len()).map(|i| unsafe { self.get_value_unchecked(i) }))
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_eq_debug_subslice_of!(SubsliceOf);
+impl_eq_debug_subslice_of!(SubsliceOfMut);
+
+/// An iterator over the values of a range of a by-value slice.
+///
+/// This is the iterator of [`SubsliceOf`] and [`SubsliceOfMut`]. Since it
+/// accesses values by index, it is double-ended and exact-size, and it
+/// implements [`SeekableValueIter`]: seeking to an index takes constant time,
+/// and seeking to a value uses an exponential search followed by a binary
+/// search. Cloning is cheap, as only a reference and a range are copied.
+///
+/// Since [`Iterator::advance_by`] is unstable, the iterator has an inherent
+/// method [`skip_values`](SubsliceIter::skip_values) that advances it in
+/// constant time, and an inherent method
+/// [`take_values`](SubsliceIter::take_values) that limits the number of
+/// returned elements without wrapping the iterator in an adapter. Moreover,
+/// the inherent methods [`peek_value`](SubsliceIter::peek_value) and
+/// [`peek_nth`](SubsliceIter::peek_nth) make it possible to look ahead
+/// without buffering.
+pub struct SubsliceIter<'a, S: ?Sized> {
+    slice: &'a S,
+    range: Range<usize>,
+    /// The index in `slice` of the value with index zero for
+    /// [`SeekableValueIter`].
+    offset: usize,
+}
+
+impl<S: ?Sized> Clone for SubsliceIter<'_, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            range: self.range.clone(),
+            offset: self.offset,
+        }
+    }
+}
+
+/// Shows the remaining iteration range.
+impl<S: ?Sized> fmt::Debug for SubsliceIter<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubsliceIter")
+            .field("range", &self.range)
+            .finish()
+    }
+}
+
+impl<'a, S: SliceByValue + ?Sized> SubsliceIter<'a, S> {
+    /// Creates an iterator over the values of `slice` with index in the given
+    /// range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within the bounds of the
+    /// slice.
+    #[inline]
+    pub fn new(slice: &'a S, range: Range<usize>) -> Self {
+        assert_range(&range, slice.len());
+        Self {
+            slice,
+            range,
+            offset: 0,
+        }
+    }
+
+    /// Advances the iterator by `n` elements in constant time.
+    ///
+    /// This method has the same semantics of the (presently unstable)
+    /// [`Iterator::advance_by`] method: it returns `Ok(())` if the iterator
+    /// has been advanced by `n` elements, and otherwise exhausts the iterator
+    /// and returns the number of elements that could not be skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(k)` if only `n - k` elements were available.
+    #[inline]
+    pub fn skip_values(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        let len = self.range.len();
+        if n <= len {
+            self.range.start += n;
+            Ok(())
+        } else {
+            self.range.start = self.range.end;
+            // SAFETY: n > len
+            Err(unsafe { NonZeroUsize::new_unchecked(n - len) })
+        }
+    }
+
+    /// Returns an iterator of the same type yielding at most the first `n`
+    /// remaining elements.
+    ///
+    /// This method is analogous to [`Iterator::take`], but it does not wrap
+    /// the iterator in an adapter.
+    #[inline]
+    pub fn take_values(mut self, n: usize) -> Self {
+        self.range.end = self.range.start + n.min(self.range.len());
+        self
+    }
+
+    /// Returns the next value without advancing the iterator.
+    ///
+    /// Differently from [`Peekable`](core::iter::Peekable), no buffering is
+    /// involved, as the value is simply read from the underlying slice.
+    #[inline]
+    pub fn peek_value(&self) -> Option<S::Value> {
+        self.peek_nth(0)
+    }
+
+    /// Returns the `k`-th next value (zero-based) without advancing the
+    /// iterator.
+    #[inline]
+    pub fn peek_nth(&self, k: usize) -> Option<S::Value> {
+        if k >= self.range.len() {
+            return None;
+        }
+        // SAFETY: the index is within the range
+        Some(unsafe { self.slice.get_value_unchecked(self.range.start + k) })
+    }
+}
+
+// try_fold cannot be overridden on stable Rust, as its bound uses the
+// unstable Try trait, so we override directly the short-circuiting methods
+// that would use it.
+impl<S: SliceByValue + ?Sized> Iterator for SubsliceIter<'_, S> {
+    type Item = S::Value;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.range.is_empty() {
+            return None;
+        }
+        // SAFETY: the index is within the range
+        let value = unsafe { self.slice.get_value_unchecked(self.range.start) };
+        self.range.start += 1;
+        Some(value)
+    }
+
+    /// Since we are indexing into a slice, we can implement
+    /// [`Iterator::nth`] without consuming the first `n` elements.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.range.len() {
+            self.range.start = self.range.end;
+            return None;
+        }
+        // SAFETY: the index is within the range
+        let value = unsafe { self.slice.get_value_unchecked(self.range.start + n) };
+        self.range.start += n + 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.range.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.range.len()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        if self.range.is_empty() {
+            return None;
+        }
+        // SAFETY: the index is within the range
+        Some(unsafe { self.slice.get_value_unchecked(self.range.end - 1) })
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let slice = self.slice;
+        self.range
+            // SAFETY: the index is within the range
+            .fold(init, |acc, i| {
+                f(acc, unsafe { slice.get_value_unchecked(i) })
+            })
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        let slice = self.slice;
+        // SAFETY: the index is within the range
+        self.range
+            .for_each(|i| f(unsafe { slice.get_value_unchecked(i) }));
+    }
+
+    #[inline]
+    fn any<F>(&mut self, mut f: F) -> bool
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        while self.range.start < self.range.end {
+            // SAFETY: the index is within the range
+            let value = unsafe { self.slice.get_value_unchecked(self.range.start) };
+            self.range.start += 1;
+            if f(value) {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[inline]
+    fn all<F>(&mut self, mut f: F) -> bool
+    where
+        F: FnMut(Self::Item) -> bool,
+    {
+        while self.range.start < self.range.end {
+            // SAFETY: the index is within the range
+            let value = unsafe { self.slice.get_value_unchecked(self.range.start) };
+            self.range.start += 1;
+            if !f(value) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    fn find<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        while self.range.start < self.range.end {
+            // SAFETY: the index is within the range
+            let value = unsafe { self.slice.get_value_unchecked(self.range.start) };
+            self.range.start += 1;
+            if predicate(&value) {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn find_map<B, F>(&mut self, mut f: F) -> Option<B>
+    where
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        while self.range.start < self.range.end {
+            // SAFETY: the index is within the range
+            let value = unsafe { self.slice.get_value_unchecked(self.range.start) };
+            self.range.start += 1;
+            if let Some(result) = f(value) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn position<P>(&mut self, mut predicate: P) -> Option<usize>
+    where
+        P: FnMut(Self::Item) -> bool,
+    {
+        let start = self.range.start;
+        while self.range.start < self.range.end {
+            // SAFETY: the index is within the range
+            let value = unsafe { self.slice.get_value_unchecked(self.range.start) };
+            self.range.start += 1;
+            if predicate(value) {
+                return Some(self.range.start - 1 - start);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn rposition<P>(&mut self, mut predicate: P) -> Option<usize>
+    where
+        P: FnMut(Self::Item) -> bool,
+    {
+        let start = self.range.start;
+        while self.range.start < self.range.end {
+            self.range.end -= 1;
+            // SAFETY: the index is within the range
+            let value = unsafe { self.slice.get_value_unchecked(self.range.end) };
+            if predicate(value) {
+                return Some(self.range.end - start);
+            }
+        }
+        None
+    }
+}
+
+impl<S: SliceByValue + ?Sized> DoubleEndedIterator for SubsliceIter<'_, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.range.is_empty() {
+            return None;
+        }
+        self.range.end -= 1;
+        // SAFETY: the index is within the range
+        Some(unsafe { self.slice.get_value_unchecked(self.range.end) })
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.range.len() {
+            self.range.end = self.range.start;
+            return None;
+        }
+        self.range.end -= n + 1;
+        // SAFETY: the index is within the range
+        Some(unsafe { self.slice.get_value_unchecked(self.range.end) })
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let slice = self.slice;
+        self.range
+            // SAFETY: the index is within the range
+            .rfold(init, |acc, i| {
+                f(acc, unsafe { slice.get_value_unchecked(i) })
+            })
+    }
+
+    #[inline]
+    fn rfind<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        while self.range.start < self.range.end {
+            self.range.end -= 1;
+            // SAFETY: the index is within the range
+            let value = unsafe { self.slice.get_value_unchecked(self.range.end) };
+            if predicate(&value) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+impl<S: SliceByValue + ?Sized> ExactSizeIterator for SubsliceIter<'_, S> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl<S: SliceByValue + ?Sized> FusedIterator for SubsliceIter<'_, S> {}
+
+impl<S: SliceByValue + ?Sized> SeekableValueIter for SubsliceIter<'_, S> {
+    #[inline]
+    fn current_index(&self) -> usize {
+        self.range.start - self.offset
+    }
+
+    #[inline]
+    fn seek(&mut self, to_index: usize) {
+        self.range.start = self
+            .offset
+            .saturating_add(to_index)
+            .clamp(self.range.start, self.range.end);
+    }
+
+    /// Since we are indexing into a slice, we can use an exponential search
+    /// followed by a binary search.
+    fn seek_value(&mut self, value: &Self::Item) -> Option<Self::Item>
+    where
+        Self::Item: PartialOrd,
+    {
+        let end = self.range.end;
+        // SAFETY: all indices below are within the range
+        let get = |i| unsafe { self.slice.get_value_unchecked(i) };
+        // Invariant: the values before lo are smaller than value
+        let mut lo = self.range.start;
+        let mut hi = lo;
+        let mut step = 1_usize;
+        while hi < end && get(hi) < *value {
+            lo = hi + 1;
+            hi = hi.saturating_add(step);
+            step = step.saturating_mul(2);
+        }
+        // Now the first value not smaller than value is in [lo..hi]
+        let mut hi = hi.min(end);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if get(mid) < *value {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo == end {
+            self.range.start = end;
+            return None;
+        }
+        self.range.start = lo + 1;
+        Some(get(lo))
+    }
+}
+
+macro_rules! impl_iter_subslice_of {
+    ($subslice:ident) => {
+        impl<'b, S: SliceByValue + ?Sized> IterateByValueGat<'b> for $subslice<'_, S> {
+            type Item = S::Value;
+            type Iter = SubsliceIter<'b, S>;
+        }
+
+        impl<S: SliceByValue + ?Sized> IterateByValue for $subslice<'_, S> {
+            #[inline]
+            fn iter_value(&self) -> Iter<'_, Self> {
+                SubsliceIter {
+                    slice: &*self.slice,
+                    range: self.range.clone(),
+                    offset: self.range.start,
+                }
+            }
+        }
+
+        impl<'b, S: SliceByValue + ?Sized> IterateByValueFromGat<'b> for $subslice<'_, S> {
+            type Item = S::Value;
+            type IterFrom = SubsliceIter<'b, S>;
+        }
+
+        impl<S: SliceByValue + ?Sized> IterateByValueFrom for $subslice<'_, S> {
+            #[inline]
+            fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+                assert_from(from, self.range.len());
+                SubsliceIter {
+                    slice: &*self.slice,
+                    range: (from..).compose(self.range.clone()),
+                    offset: self.range.start,
+                }
+            }
+        }
+
+        impl<S: SliceByValue + ?Sized> ExactSizeIterateByValue for $subslice<'_, S> {}
+        impl<S: SliceByValue + ?Sized> ConsistentIteration for $subslice<'_, S> {}
+    };
+}
+
+impl_iter_subslice_of!(SubsliceOf);
+impl_iter_subslice_of!(SubsliceOfMut);
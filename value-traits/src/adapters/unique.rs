@@ -0,0 +1,278 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Uniquely owned [`Arc`] and [`Rc`] adapters providing mutable by-value
+//! access.
+//!
+//! The implementations for [`Arc`] and [`Rc`] in this crate are read-only, as
+//! the content of a reference-counted pointer can be shared. However, it is
+//! common to build a structure in place before sharing it. [`UniqueArc`] and
+//! [`UniqueRc`] wrap a reference-counted pointer that is guaranteed to be the
+//! only reference to its content, and thus implement the mutable traits, too.
+//! When construction is complete, [`into_shared`](UniqueArc::into_shared)
+//! returns the underlying pointer, which can then be cloned freely.
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use value_traits::adapters::unique::UniqueArc;
+//! use value_traits::slices::*;
+//!
+//! let mut unique = UniqueArc::<[i32]>::try_from(Arc::from(vec![0; 4])).unwrap();
+//! for i in 0..unique.len() {
+//!     unique.set_value(i, i as i32 * 2);
+//! }
+//! let shared: Arc<[i32]> = unique.into_shared();
+//! assert_eq!(shared.index_value(3), 6);
+//! ```
+//!
+//! These adapters are available only if the `std` feature is enabled.
+
+#![cfg(feature = "std")]
+
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::{rc::Rc, sync::Arc};
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut, SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut,
+        Subslice, SubsliceMut,
+    },
+};
+
+macro_rules! impl_unique {
+    ($unique:ident, $ptr:ident) => {
+        #[doc = concat!("A [`", stringify!($ptr), "`] that is guaranteed to be the only reference to its content.")]
+        ///
+        /// Uniqueness is checked at construction time, and it is preserved by
+        /// never exposing the underlying pointer until
+        #[doc = concat!("[`into_shared`](", stringify!($unique), "::into_shared) is called.")]
+        /// Thus, this structure can implement the mutable by-value traits by
+        /// delegation.
+        #[derive(Debug)]
+        pub struct $unique<S: ?Sized>($ptr<S>);
+
+        impl<S> $unique<S> {
+            /// Creates a new uniquely owned pointer with the given content.
+            pub fn new(value: S) -> Self {
+                Self($ptr::new(value))
+            }
+
+            /// Makes the given pointer unique, cloning its content if it is
+            /// shared.
+            #[doc = concat!("See [`", stringify!($ptr), "::make_mut`].")]
+            pub fn make_unique(mut ptr: $ptr<S>) -> Self
+            where
+                S: Clone,
+            {
+                $ptr::make_mut(&mut ptr);
+                Self(ptr)
+            }
+        }
+
+        impl<S: ?Sized> $unique<S> {
+            /// Returns the underlying pointer, which can then be shared.
+            pub fn into_shared(self) -> $ptr<S> {
+                self.0
+            }
+
+            #[inline(always)]
+            fn inner_mut(&mut self) -> &mut S {
+                // SAFETY: the pointer is unique by construction
+                unsafe { $ptr::get_mut(&mut self.0).unwrap_unchecked() }
+            }
+        }
+
+        impl<S: ?Sized> TryFrom<$ptr<S>> for $unique<S> {
+            type Error = $ptr<S>;
+
+            /// Wraps the given pointer, or returns it if it is shared (i.e.,
+            /// there are other strong or weak references to its content).
+            fn try_from(mut ptr: $ptr<S>) -> Result<Self, Self::Error> {
+                if $ptr::get_mut(&mut ptr).is_some() {
+                    Ok(Self(ptr))
+                } else {
+                    Err(ptr)
+                }
+            }
+        }
+
+        impl<S: ?Sized> From<$unique<S>> for $ptr<S> {
+            fn from(unique: $unique<S>) -> Self {
+                unique.into_shared()
+            }
+        }
+
+        impl<S: ?Sized> AsRef<S> for $unique<S> {
+            fn as_ref(&self) -> &S {
+                &self.0
+            }
+        }
+
+        impl<S: ?Sized> AsMut<S> for $unique<S> {
+            fn as_mut(&mut self) -> &mut S {
+                self.inner_mut()
+            }
+        }
+
+        impl<S: SliceByValue + ?Sized> SliceByValue for $unique<S> {
+            type Value = S::Value;
+
+            #[inline]
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            fn get_value(&self, index: usize) -> Option<Self::Value> {
+                self.0.get_value(index)
+            }
+            fn index_value(&self, index: usize) -> Self::Value {
+                self.0.index_value(index)
+            }
+            unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+                unsafe { self.0.get_value_unchecked(index) }
+            }
+        }
+
+        impl<S: SliceByValueMut + ?Sized> SliceByValueMut for $unique<S> {
+            fn set_value(&mut self, index: usize, value: Self::Value) {
+                self.inner_mut().set_value(index, value);
+            }
+            unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+                unsafe {
+                    self.inner_mut().set_value_unchecked(index, value);
+                }
+            }
+            fn replace_value(&mut self, index: usize, value: Self::Value) -> Self::Value {
+                self.inner_mut().replace_value(index, value)
+            }
+            unsafe fn replace_value_unchecked(
+                &mut self,
+                index: usize,
+                value: Self::Value,
+            ) -> Self::Value {
+                unsafe { self.inner_mut().replace_value_unchecked(index, value) }
+            }
+            fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+                self.inner_mut().copy_within(src, dst);
+            }
+            fn for_each_value_mut<R: ComposeRange, F>(&mut self, range: R, f: F)
+            where
+                F: FnMut(usize, Self::Value) -> Option<Self::Value>,
+            {
+                self.inner_mut().for_each_value_mut(range, f);
+            }
+
+            type ChunksMut<'a>
+                = S::ChunksMut<'a>
+            where
+                Self: 'a;
+
+            type ChunksMutError = S::ChunksMutError;
+
+            fn try_chunks_mut(
+                &mut self,
+                chunk_size: usize,
+            ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+                self.inner_mut().try_chunks_mut(chunk_size)
+            }
+        }
+
+        impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> SliceByValueSubsliceGat<'a>
+            for $unique<S>
+        {
+            type Subslice = S::Subslice;
+        }
+
+        impl<'a, S: SliceByValueSubsliceGatMut<'a> + ?Sized> SliceByValueSubsliceGatMut<'a>
+            for $unique<S>
+        {
+            type SubsliceMut = S::SubsliceMut;
+        }
+
+        impl_unique!(@range $unique, RangeFull);
+        impl_unique!(@range $unique, RangeFrom<usize>);
+        impl_unique!(@range $unique, RangeTo<usize>);
+        impl_unique!(@range $unique, Range<usize>);
+        impl_unique!(@range $unique, RangeInclusive<usize>);
+        impl_unique!(@range $unique, RangeToInclusive<usize>);
+
+        impl<'a, S: IterateByValueGat<'a> + ?Sized> IterateByValueGat<'a> for $unique<S> {
+            type Item = S::Item;
+            type Iter = S::Iter;
+        }
+
+        impl<S: IterateByValue + ?Sized> IterateByValue for $unique<S> {
+            fn iter_value(&self) -> Iter<'_, Self> {
+                self.0.iter_value()
+            }
+        }
+
+        impl<'a, S: IterateByValueFromGat<'a> + ?Sized> IterateByValueFromGat<'a>
+            for $unique<S>
+        {
+            type Item = S::Item;
+            type IterFrom = S::IterFrom;
+        }
+
+        impl<S: IterateByValueFrom + ?Sized> IterateByValueFrom for $unique<S> {
+            fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+                self.0.iter_value_from(from)
+            }
+        }
+
+        impl<S: ExactSizeIterateByValue + ?Sized> ExactSizeIterateByValue for $unique<S> {}
+    };
+
+    (@range $unique:ident, $range:ty) => {
+        impl<S: SliceByValueSubsliceRange<$range> + ?Sized> SliceByValueSubsliceRange<$range>
+            for $unique<S>
+        {
+            #[inline]
+            fn get_subslice(&self, index: $range) -> Option<Subslice<'_, Self>> {
+                self.0.get_subslice(index)
+            }
+
+            #[inline]
+            fn index_subslice(&self, index: $range) -> Subslice<'_, Self> {
+                self.0.index_subslice(index)
+            }
+
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
+                unsafe { self.0.get_subslice_unchecked(index) }
+            }
+        }
+
+        impl<S: SliceByValueSubsliceRangeMut<$range> + ?Sized> SliceByValueSubsliceRangeMut<$range>
+            for $unique<S>
+        {
+            #[inline]
+            fn get_subslice_mut(&mut self, index: $range) -> Option<SubsliceMut<'_, Self>> {
+                self.inner_mut().get_subslice_mut(index)
+            }
+
+            #[inline]
+            fn index_subslice_mut(&mut self, index: $range) -> SubsliceMut<'_, Self> {
+                self.inner_mut().index_subslice_mut(index)
+            }
+
+            #[inline]
+            unsafe fn get_subslice_unchecked_mut(&mut self, index: $range) -> SubsliceMut<'_, Self> {
+                unsafe { self.inner_mut().get_subslice_unchecked_mut(index) }
+            }
+        }
+    };
+}
+
+impl_unique!(UniqueArc, Arc);
+impl_unique!(UniqueRc, Rc);
@@ -0,0 +1,302 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Read-only by-value slices satisfying an invariant checked at
+//! construction.
+//!
+//! A [`Validated`] wraps a by-value slice after checking that it satisfies
+//! an [`Invariant`], and implements only the read-only by-value traits, as
+//! [`Frozen`](crate::adapters::frozen::Frozen) does, so that the invariant
+//! cannot be broken afterwards. The invariant is part of the type, so
+//! functions can require, for example, a `Validated<S, Sorted>` to be sure
+//! that data coming from an untrusted source has been checked exactly once.
+//!
+//! Methods taking advantage of the invariant are available for the
+//! corresponding types: for example, slices whose values are
+//! [nondecreasing](Nondecreasing) provide searches that access values
+//! without bounds checks:
+//!
+//! ```rust
+//! use value_traits::adapters::validated::{Sorted, Validated};
+//!
+//! let offsets = Validated::<_, Sorted>::new([0, 3, 3, 7, 12]).unwrap();
+//! assert_eq!(offsets.binary_search_value(&7), Ok(3));
+//! assert_eq!(offsets.partition_point_value(|&x| x <= 5), 3);
+//! assert!(Validated::<_, Sorted>::new([0, 3, 2]).is_err());
+//! ```
+//!
+//! User-defined invariants are types implementing [`Invariant`].
+//!
+//! Note that the invariant might be broken if the wrapped slice has interior
+//! mutability.
+
+use core::marker::PhantomData;
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueSubsliceGat, SliceByValueSubsliceRange, Subslice,
+    },
+};
+
+/// An invariant of a by-value slice.
+///
+/// Implementors are usually marker types, which are used as type parameter
+/// of [`Validated`].
+pub trait Invariant<S: SliceByValue + ?Sized> {
+    /// Checks that the invariant holds for the given slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of a value violating the invariant.
+    fn check(slice: &S) -> Result<(), usize>;
+}
+
+/// A marker trait for invariants implying that the values of the slice are
+/// nondecreasing.
+pub trait Nondecreasing {}
+
+/// The invariant stating that the values of the slice are nondecreasing.
+///
+/// The index of the first value smaller than its predecessor is reported as
+/// violating the invariant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Sorted;
+
+/// The invariant stating that the values of the slice are strictly
+/// increasing.
+///
+/// The index of the first value smaller than or equal to its predecessor is
+/// reported as violating the invariant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct StrictlySorted;
+
+impl Nondecreasing for Sorted {}
+impl Nondecreasing for StrictlySorted {}
+
+/// Returns the first index at which `pred` does not hold for a value and its
+/// predecessor.
+fn check_pairs<S: SliceByValue + ?Sized>(
+    slice: &S,
+    mut pred: impl FnMut(&S::Value, &S::Value) -> bool,
+) -> Result<(), usize> {
+    let len = slice.len();
+    if len == 0 {
+        return Ok(());
+    }
+    // SAFETY: the slice is not empty
+    let mut prev = unsafe { slice.get_value_unchecked(0) };
+    for i in 1..len {
+        // SAFETY: i is within bounds
+        let value = unsafe { slice.get_value_unchecked(i) };
+        if !pred(&prev, &value) {
+            return Err(i);
+        }
+        prev = value;
+    }
+    Ok(())
+}
+
+impl<S: SliceByValue<Value: PartialOrd> + ?Sized> Invariant<S> for Sorted {
+    fn check(slice: &S) -> Result<(), usize> {
+        check_pairs(slice, |a, b| a <= b)
+    }
+}
+
+impl<S: SliceByValue<Value: PartialOrd> + ?Sized> Invariant<S> for StrictlySorted {
+    fn check(slice: &S) -> Result<(), usize> {
+        check_pairs(slice, |a, b| a < b)
+    }
+}
+
+/// Error returned by [`Validated::new`] when the invariant does not hold.
+///
+/// The error contains the slice, which can be recovered with
+/// [`into_inner`](InvariantViolated::into_inner).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvariantViolated<S> {
+    slice: S,
+    index: usize,
+}
+
+impl<S> InvariantViolated<S> {
+    /// Returns the index of a value violating the invariant.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the slice that failed validation.
+    pub fn into_inner(self) -> S {
+        self.slice
+    }
+}
+
+impl<S> core::fmt::Display for InvariantViolated<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the invariant is violated at index {}", self.index)
+    }
+}
+
+impl<S: core::fmt::Debug> core::error::Error for InvariantViolated<S> {}
+
+/// A read-only by-value slice satisfying the invariant `I`.
+///
+/// See the [module documentation](self).
+pub struct Validated<S, I> {
+    slice: S,
+    _marker: PhantomData<fn() -> I>,
+}
+
+impl<S: Clone, I> Clone for Validated<S, I> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S: core::fmt::Debug, I> core::fmt::Debug for Validated<S, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Validated").field(&self.slice).finish()
+    }
+}
+
+impl<S: SliceByValue, I: Invariant<S>> Validated<S, I> {
+    /// Checks the invariant on the given slice, and wraps it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvariantViolated`] error containing the slice if the
+    /// invariant does not hold.
+    pub fn new(slice: S) -> Result<Self, InvariantViolated<S>> {
+        match I::check(&slice) {
+            Ok(()) => Ok(Self {
+                slice,
+                _marker: PhantomData,
+            }),
+            Err(index) => Err(InvariantViolated { slice, index }),
+        }
+    }
+}
+
+impl<S, I> Validated<S, I> {
+    /// Returns a shared reference to the wrapped slice.
+    pub fn as_inner(&self) -> &S {
+        &self.slice
+    }
+
+    /// Returns the wrapped slice.
+    pub fn into_inner(self) -> S {
+        self.slice
+    }
+}
+
+impl<S: SliceByValue, I: Nondecreasing> Validated<S, I> {
+    /// Returns the number of initial values satisfying the given predicate,
+    /// which must hold for a prefix of the slice, as in the case of
+    /// [`slice::partition_point`].
+    ///
+    /// The search is binary, and values are accessed without bounds checks.
+    pub fn partition_point_value<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&S::Value) -> bool,
+    {
+        let (mut lo, mut hi) = (0, self.slice.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            // SAFETY: mid is smaller than hi, which is within bounds
+            if pred(&unsafe { self.slice.get_value_unchecked(mid) }) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Searches for the given value, with the same semantics as
+    /// [`slice::binary_search`].
+    ///
+    /// The search is binary, and values are accessed without bounds checks.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index where the value could be inserted keeping the
+    /// values sorted if the value is not found.
+    pub fn binary_search_value(&self, value: &S::Value) -> Result<usize, usize>
+    where
+        S::Value: Ord,
+    {
+        let pos = self.partition_point_value(|x| x < value);
+        // SAFETY: pos is within bounds
+        if pos < self.slice.len() && unsafe { self.slice.get_value_unchecked(pos) } == *value {
+            Ok(pos)
+        } else {
+            Err(pos)
+        }
+    }
+}
+
+impl<S: SliceByValue, I> SliceByValue for Validated<S, I> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.slice.get_value_unchecked(index) }
+    }
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a>, I> SliceByValueSubsliceGat<'a> for Validated<S, I> {
+    type Subslice = S::Subslice;
+}
+
+impl<R: ComposeRange, S: SliceByValueSubsliceRange<R>, I> SliceByValueSubsliceRange<R>
+    for Validated<S, I>
+{
+    #[inline]
+    unsafe fn get_subslice_unchecked(&self, range: R) -> Subslice<'_, Self> {
+        // SAFETY: range is within bounds
+        unsafe { self.slice.get_subslice_unchecked(range) }
+    }
+}
+
+impl<'a, S: IterateByValueGat<'a>, I> IterateByValueGat<'a> for Validated<S, I> {
+    type Item = S::Item;
+    type Iter = S::Iter;
+}
+
+impl<S: IterateByValue, I> IterateByValue for Validated<S, I> {
+    #[inline]
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.slice.iter_value()
+    }
+}
+
+impl<'a, S: IterateByValueFromGat<'a>, I> IterateByValueFromGat<'a> for Validated<S, I> {
+    type Item = S::Item;
+    type IterFrom = S::IterFrom;
+}
+
+impl<S: IterateByValueFrom, I> IterateByValueFrom for Validated<S, I> {
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.slice.iter_value_from(from)
+    }
+}
+
+impl<S: ExactSizeIterateByValue, I> ExactSizeIterateByValue for Validated<S, I> {}
@@ -0,0 +1,176 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Mutable by-value slices whose values can be set exactly once.
+//!
+//! A [`WriteOnce`] wraps a mutable by-value slice and keeps track, with a
+//! bitmap, of the indices that have been set.
+//! [`try_set_value`](WriteOnce::try_set_value) returns an [`AlreadyWritten`]
+//! error on double writes, and reading is possible only for indices that
+//! have been set. Once all indices have been set,
+//! [`try_freeze`](WriteOnce::try_freeze) returns the wrapped slice as a
+//! read-only [`Frozen`] view:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::adapters::write_once::WriteOnce;
+//! use value_traits::slices::*;
+//!
+//! let mut w = WriteOnce::new([0; 3]);
+//! w.try_set_value(2, 30).unwrap();
+//! assert_eq!(w.get_value(2), Some(30));
+//! assert_eq!(w.get_value(0), None);
+//! assert!(w.try_set_value(2, 40).is_err());
+//!
+//! w.try_set_value(0, 10).unwrap();
+//! w.try_set_value(1, 20).unwrap();
+//! let frozen = w.try_freeze().unwrap();
+//! assert_eq!(frozen.index_value(1), 20);
+//! # }
+//! ```
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec};
+
+use crate::{adapters::frozen::Frozen, panics::assert_index, slices::SliceByValueMut};
+
+/// Error returned by [`WriteOnce::try_set_value`] when a value has already
+/// been set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlreadyWritten {
+    index: usize,
+}
+
+impl AlreadyWritten {
+    /// Returns the index that has already been set.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl core::fmt::Display for AlreadyWritten {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the value at index {} has already been set", self.index)
+    }
+}
+
+impl core::error::Error for AlreadyWritten {}
+
+/// A mutable by-value slice whose values can be set exactly once.
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct WriteOnce<S> {
+    slice: S,
+    written: Box<[u64]>,
+    num_written: usize,
+}
+
+impl<S: SliceByValueMut> WriteOnce<S> {
+    /// Wraps the given slice, whose values are all considered not set.
+    pub fn new(slice: S) -> Self {
+        let words = slice.len().div_ceil(64);
+        Self {
+            slice,
+            written: vec![0; words].into_boxed_slice(),
+            num_written: 0,
+        }
+    }
+
+    /// Returns the length of the wrapped slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the wrapped slice is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns `true` if the value at the given index has been set.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    #[inline]
+    pub fn is_written(&self, index: usize) -> bool {
+        assert_index(index, self.len());
+        self.written[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Returns the number of values that have been set.
+    #[inline]
+    pub fn num_written(&self) -> usize {
+        self.num_written
+    }
+
+    /// Returns `true` if all values have been set.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.num_written == self.len()
+    }
+
+    /// Sets the value at the given index, if it has not been set yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AlreadyWritten`] error, leaving the slice unchanged, if
+    /// the value at the given index has already been set.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    pub fn try_set_value(&mut self, index: usize, value: S::Value) -> Result<(), AlreadyWritten> {
+        if self.is_written(index) {
+            return Err(AlreadyWritten { index });
+        }
+        self.written[index / 64] |= 1 << (index % 64);
+        self.num_written += 1;
+        // SAFETY: index is within bounds
+        unsafe { self.slice.set_value_unchecked(index, value) };
+        Ok(())
+    }
+
+    /// Returns the value at the given index, or `None` if the index is out
+    /// of bounds or the value has not been set yet.
+    #[inline]
+    pub fn get_value(&self, index: usize) -> Option<S::Value> {
+        if index < self.len() && self.is_written(index) {
+            // SAFETY: index is within bounds
+            Some(unsafe { self.slice.get_value_unchecked(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a read-only view of the wrapped slice if all values have been
+    /// set, or returns `self` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` if some value has not been set.
+    pub fn try_freeze(self) -> Result<Frozen<S>, Self> {
+        if self.is_initialized() {
+            Ok(Frozen::new(self.slice))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Returns the wrapped slice, irrespective of which values have been
+    /// set.
+    pub fn into_inner(self) -> S {
+        self.slice
+    }
+}
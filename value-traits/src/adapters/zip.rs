@@ -0,0 +1,218 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Elementwise combination of two by-value slices.
+//!
+//! [`zip_with`] returns a read-only by-value slice whose value at index `i`
+//! is `f(a[i], b[i])`, computed on demand, so two slices can be combined
+//! (e.g., added elementwise) without materializing the result:
+//!
+//! ```rust
+//! use value_traits::adapters::zip::zip_with;
+//! use value_traits::iter::*;
+//! use value_traits::slices::*;
+//!
+//! let a = [1, 2, 3];
+//! let b = [10, 20, 30];
+//! let sum = zip_with(&a, &b, |x, y| x + y);
+//! assert_eq!(sum.index_value(1), 22);
+//! assert_eq!(sum.iter_value().collect::<Vec<_>>(), [11, 22, 33]);
+//! ```
+//!
+//! Iteration is delegated to the by-value iterators of the two slices, which
+//! might be faster than random access, as it happens, for example, with
+//! compressed representations.
+
+use core::iter::FusedIterator;
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::SliceByValue,
+};
+
+/// A read-only by-value slice combining elementwise the values of two
+/// by-value slices.
+///
+/// The length of the slice is the minimum of the lengths of the two
+/// slices, as in the case of [`Iterator::zip`].
+///
+/// See the [module documentation](self) and [`zip_with`].
+#[derive(Clone, Copy)]
+pub struct ZipWith<A, B, F> {
+    a: A,
+    b: B,
+    f: F,
+}
+
+/// Returns a by-value slice whose value at index `i` is `f(a[i], b[i])`.
+///
+/// See the [module documentation](self).
+pub fn zip_with<A, B, V, F>(a: A, b: B, f: F) -> ZipWith<A, B, F>
+where
+    A: SliceByValue,
+    B: SliceByValue,
+    F: Fn(A::Value, B::Value) -> V,
+{
+    ZipWith { a, b, f }
+}
+
+impl<A, B, F> ZipWith<A, B, F> {
+    /// Returns the two underlying slices.
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A: core::fmt::Debug, B: core::fmt::Debug, F> core::fmt::Debug for ZipWith<A, B, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ZipWith")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<A, B, V, F> SliceByValue for ZipWith<A, B, F>
+where
+    A: SliceByValue,
+    B: SliceByValue,
+    F: Fn(A::Value, B::Value) -> V,
+{
+    type Value = V;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.a.len().min(self.b.len())
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds for both slices
+        unsafe {
+            (self.f)(
+                self.a.get_value_unchecked(index),
+                self.b.get_value_unchecked(index),
+            )
+        }
+    }
+}
+
+/// The iterator returned by the by-value iteration methods of [`ZipWith`].
+#[derive(Debug, Clone)]
+pub struct ZipWithIter<I, J, F> {
+    a: I,
+    b: J,
+    f: F,
+}
+
+impl<I: Iterator, J: Iterator, V, F: Fn(I::Item, J::Item) -> V> Iterator for ZipWithIter<I, J, F> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.a.next()?;
+        let y = self.b.next()?;
+        Some((self.f)(x, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (x, y) => x.or(y),
+        };
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+impl<I, J, V, F> ExactSizeIterator for ZipWithIter<I, J, F>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+    F: Fn(I::Item, J::Item) -> V,
+{
+}
+
+impl<I, J, V, F> FusedIterator for ZipWithIter<I, J, F>
+where
+    I: FusedIterator,
+    J: FusedIterator,
+    F: Fn(I::Item, J::Item) -> V,
+{
+}
+
+impl<'a, A, B, V, F> IterateByValueGat<'a> for ZipWith<A, B, F>
+where
+    A: IterateByValueGat<'a>,
+    B: IterateByValueGat<'a>,
+    F: Fn(A::Item, B::Item) -> V,
+{
+    type Item = V;
+    type Iter = ZipWithIter<Iter<'a, A>, Iter<'a, B>, &'a F>;
+}
+
+impl<A, B, V, F> IterateByValue for ZipWith<A, B, F>
+where
+    A: IterateByValue,
+    B: IterateByValue,
+    F: for<'a> Fn(<A as IterateByValueGat<'a>>::Item, <B as IterateByValueGat<'a>>::Item) -> V,
+{
+    fn iter_value(&self) -> Iter<'_, Self> {
+        ZipWithIter {
+            a: self.a.iter_value(),
+            b: self.b.iter_value(),
+            f: &self.f,
+        }
+    }
+}
+
+impl<'a, A, B, V, F> IterateByValueFromGat<'a> for ZipWith<A, B, F>
+where
+    A: IterateByValueFromGat<'a>,
+    B: IterateByValueFromGat<'a>,
+    F: Fn(A::Item, B::Item) -> V,
+{
+    type Item = V;
+    type IterFrom = ZipWithIter<IterFrom<'a, A>, IterFrom<'a, B>, &'a F>;
+}
+
+impl<A, B, V, F> IterateByValueFrom for ZipWith<A, B, F>
+where
+    A: IterateByValueFrom,
+    B: IterateByValueFrom,
+    F: for<'a> Fn(
+        <A as IterateByValueFromGat<'a>>::Item,
+        <B as IterateByValueFromGat<'a>>::Item,
+    ) -> V,
+{
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        ZipWithIter {
+            a: self.a.iter_value_from(from),
+            b: self.b.iter_value_from(from),
+            f: &self.f,
+        }
+    }
+}
+
+impl<A, B, V, F> ExactSizeIterateByValue for ZipWith<A, B, F>
+where
+    A: ExactSizeIterateByValue,
+    B: ExactSizeIterateByValue,
+    F: Fn(A::Value, B::Value) -> V,
+    F: for<'a> Fn(<A as IterateByValueGat<'a>>::Item, <B as IterateByValueGat<'a>>::Item) -> V,
+    F: for<'a> Fn(
+        <A as IterateByValueFromGat<'a>>::Item,
+        <B as IterateByValueFromGat<'a>>::Item,
+    ) -> V,
+{
+}
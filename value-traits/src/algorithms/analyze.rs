@@ -0,0 +1,230 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::fmt::{self, Display};
+#[cfg(feature = "hll")]
+use core::hash::{Hash, Hasher};
+
+#[cfg(feature = "hll")]
+use crate::algorithms::FingerprintHasher;
+use crate::slices::SliceByValue;
+
+/// Statistics about the values of a by-value slice, returned by
+/// [`AnalyzeValues::analyze_values`].
+///
+/// The [`Display`] implementation prints a one-line human-readable summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueStats<V> {
+    /// The number of values in the slice.
+    pub len: usize,
+    /// The number of maximal runs of equal consecutive values.
+    pub runs: usize,
+    /// The minimum value, or `None` if the slice is empty.
+    pub min: Option<V>,
+    /// The maximum value, or `None` if the slice is empty.
+    pub max: Option<V>,
+    /// An estimate of the number of distinct values, if computed.
+    pub distinct_estimate: Option<usize>,
+}
+
+impl<V: BitWidth> ValueStats<V> {
+    /// Returns the number of bits needed to represent all values between
+    /// the minimum and the maximum, as defined by [`BitWidth`].
+    ///
+    /// Returns zero if the slice is empty.
+    pub fn bit_width(&self) -> u32 {
+        Ord::max(
+            self.min.as_ref().map_or(0, BitWidth::min_bit_width),
+            self.max.as_ref().map_or(0, BitWidth::min_bit_width),
+        )
+    }
+}
+
+impl<V: Display> Display for ValueStats<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} values, {} runs", self.len, self.runs)?;
+        if let (Some(min), Some(max)) = (&self.min, &self.max) {
+            write!(f, ", min {min}, max {max}")?;
+        }
+        if let Some(distinct) = self.distinct_estimate {
+            write!(f, ", ~{distinct} distinct values")?;
+        }
+        Ok(())
+    }
+}
+
+/// Values having a binary representation of minimum width.
+///
+/// For unsigned integers, the width is the number of bits of the value
+/// without leading zeros (so it is zero for zero). For signed integers,
+/// it is the minimum width of a two's complement representation (so it is
+/// one for zero and minus one).
+///
+/// For integers, the maximum width of two values bounds the width of all
+/// values between them.
+pub trait BitWidth {
+    /// Returns the minimum number of bits needed to represent this value.
+    fn min_bit_width(&self) -> u32;
+}
+
+macro_rules! impl_bit_width {
+    ($($ty:ty),*; $($signed:ty),*) => {
+        $(
+            impl BitWidth for $ty {
+                #[inline]
+                fn min_bit_width(&self) -> u32 {
+                    <$ty>::BITS - self.leading_zeros()
+                }
+            }
+        )*
+        $(
+            impl BitWidth for $signed {
+                #[inline]
+                fn min_bit_width(&self) -> u32 {
+                    <$signed>::BITS - (self ^ (self >> (<$signed>::BITS - 1))).leading_zeros() + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_width!(u8, u16, u32, u64, u128, usize; i8, i16, i32, i64, i128, isize);
+
+/// Statistics about the values of a by-value slice, useful to choose a
+/// representation or to log what a sequence looks like.
+///
+/// This trait is implemented for all types implementing [`SliceByValue`].
+///
+/// ```rust
+/// use value_traits::algorithms::AnalyzeValues;
+///
+/// let stats = [3_u32, 3, 3, 7, 7, 100].analyze_values();
+/// assert_eq!(stats.len, 6);
+/// assert_eq!(stats.runs, 3);
+/// assert_eq!((stats.min, stats.max), (Some(3), Some(100)));
+/// assert_eq!(stats.bit_width(), 7);
+/// assert_eq!(stats.to_string(), "6 values, 3 runs, min 3, max 100");
+/// ```
+pub trait AnalyzeValues: SliceByValue {
+    /// Scans the slice and returns statistics about its values.
+    ///
+    /// The [`distinct_estimate`](ValueStats::distinct_estimate) field of the
+    /// result is `None`; see
+    /// [`analyze_values_with_distinct`](AnalyzeValues::analyze_values_with_distinct).
+    ///
+    /// Values that are not comparable with the current minimum or maximum
+    /// (e.g., a floating-point NaN) do not replace them.
+    fn analyze_values(&self) -> ValueStats<Self::Value>
+    where
+        Self::Value: PartialOrd + Clone,
+    {
+        analyze(self, |_| {})
+    }
+
+    /// Scans the slice and returns statistics about its values, including an
+    /// estimate of the number of distinct values.
+    ///
+    /// The estimate is computed by a HyperLogLog counter with 4096
+    /// registers, using values hashed by a [`FingerprintHasher`], so its
+    /// relative standard error is about 1.6%. Small counts are estimated
+    /// using linear counting, and they are usually exact.
+    ///
+    /// This method is available only if the `hll` feature is enabled.
+    #[cfg(feature = "hll")]
+    fn analyze_values_with_distinct(&self) -> ValueStats<Self::Value>
+    where
+        Self::Value: PartialOrd + Clone + Hash,
+    {
+        let mut hll = HyperLogLog::new();
+        let mut stats = analyze(self, |value| hll.insert(value));
+        stats.distinct_estimate = Some(hll.estimate());
+        stats
+    }
+}
+
+impl<S: SliceByValue + ?Sized> AnalyzeValues for S {}
+
+fn analyze<S, F>(s: &S, mut f: F) -> ValueStats<S::Value>
+where
+    S: SliceByValue<Value: PartialOrd + Clone> + ?Sized,
+    F: FnMut(&S::Value),
+{
+    let mut stats = ValueStats {
+        len: s.len(),
+        runs: 0,
+        min: None,
+        max: None,
+        distinct_estimate: None,
+    };
+    let mut prev = None;
+    for i in 0..s.len() {
+        // SAFETY: i is within bounds
+        let value = unsafe { s.get_value_unchecked(i) };
+        f(&value);
+        if prev.as_ref() != Some(&value) {
+            stats.runs += 1;
+        }
+        if stats.min.as_ref().is_none_or(|min| value < *min) {
+            stats.min = Some(value.clone());
+        }
+        if stats.max.as_ref().is_none_or(|max| value > *max) {
+            stats.max = Some(value.clone());
+        }
+        prev = Some(value);
+    }
+    stats
+}
+
+#[cfg(feature = "hll")]
+const LOG2_REGISTERS: u32 = 12;
+
+#[cfg(feature = "hll")]
+const REGISTERS: usize = 1 << LOG2_REGISTERS;
+
+/// A HyperLogLog counter.
+#[cfg(feature = "hll")]
+struct HyperLogLog {
+    registers: [u8; REGISTERS],
+}
+
+#[cfg(feature = "hll")]
+impl HyperLogLog {
+    fn new() -> Self {
+        Self {
+            registers: [0; REGISTERS],
+        }
+    }
+
+    fn insert<V: Hash>(&mut self, value: &V) {
+        let mut hasher = FingerprintHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+        let register = (hash >> (64 - LOG2_REGISTERS)) as usize;
+        // The sentinel bit bounds the rank if the remaining bits are zero
+        let rank = ((hash << LOG2_REGISTERS) | (1 << (LOG2_REGISTERS - 1))).leading_zeros() + 1;
+        self.registers[register] = Ord::max(self.registers[register], rank as u8);
+    }
+
+    fn estimate(&self) -> usize {
+        let m = REGISTERS as f64;
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&r| f64::powi(2.0, -i32::from(r)))
+            .sum();
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let raw = alpha * m * m / sum;
+        let estimate = if raw <= 2.5 * m && zeros != 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        };
+        estimate.round() as usize
+    }
+}
@@ -0,0 +1,287 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::{iter::Sum, ops::Range};
+
+use crate::{
+    panics::assert_range,
+    slices::{ComposeRange, SliceByValue},
+};
+
+/// Summaries of consecutive blocks of a by-value slice.
+///
+/// A slice implementing this trait is divided into consecutive blocks of
+/// [`block_size`](BlockSummaries::block_size) values (the last block might
+/// be shorter), and it can return the minimum, maximum, and sum of the
+/// values of a block. The default implementations scan the block; backends
+/// storing such summaries (e.g., columnar formats with zone maps) override
+/// them so that scans can skip whole blocks.
+///
+/// The provided methods [`min_value`](BlockSummaries::min_value),
+/// [`max_value`](BlockSummaries::max_value), and
+/// [`sum_values`](BlockSummaries::sum_values) combine the summaries of all
+/// blocks, whereas
+/// [`range_sum_by_blocks`](BlockSummaries::range_sum_by_blocks) and its
+/// siblings combine the summaries of the blocks contained in a range, and
+/// scan the partial blocks at its ends. The latter can be used to implement
+/// [`RangeQueries`](super::RangeQueries).
+///
+/// ```rust
+/// use value_traits::algorithms::BlockSummaries;
+/// use value_traits::slices::SliceByValue;
+///
+/// /// A slice storing the sum of each block of four values.
+/// struct Summed {
+///     values: Vec<u64>,
+///     sums: Vec<u64>,
+/// }
+///
+/// impl SliceByValue for Summed {
+///     type Value = u64;
+///     fn len(&self) -> usize {
+///         self.values.len()
+///     }
+///     unsafe fn get_value_unchecked(&self, index: usize) -> u64 {
+///         self.values[index]
+///     }
+/// }
+///
+/// impl BlockSummaries for Summed {
+///     fn block_size(&self) -> usize {
+///         4
+///     }
+///     fn block_sum(&self, block: usize) -> u64 {
+///         self.sums[block]
+///     }
+/// }
+///
+/// let values: Vec<u64> = (0..10).collect();
+/// let sums = values.chunks(4).map(|c| c.iter().sum()).collect();
+/// let s = Summed { values, sums };
+/// assert_eq!(s.num_blocks(), 3);
+/// assert_eq!(s.sum_values(), 45);
+/// assert_eq!(s.max_value(), Some(9));
+/// ```
+pub trait BlockSummaries: SliceByValue {
+    /// Returns the number of values in a block.
+    ///
+    /// The block size must be positive.
+    fn block_size(&self) -> usize;
+
+    /// Returns the number of blocks, that is, the length of the slice
+    /// divided by the block size, rounded up.
+    fn num_blocks(&self) -> usize {
+        self.len().div_ceil(self.block_size())
+    }
+
+    /// Returns the range of indices of the given block.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the block is out of bounds.
+    fn block_range(&self, block: usize) -> Range<usize> {
+        assert!(block < self.num_blocks(), "block out of bounds");
+        let block_size = self.block_size();
+        block * block_size..Ord::min((block + 1) * block_size, self.len())
+    }
+
+    /// Returns the minimum value of the given block.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the block is out of bounds.
+    fn block_min(&self, block: usize) -> Self::Value
+    where
+        Self::Value: Ord,
+    {
+        self.block_range(block)
+            // SAFETY: the range of the block is within bounds
+            .map(|i| unsafe { self.get_value_unchecked(i) })
+            .min()
+            .expect("blocks are nonempty")
+    }
+
+    /// Returns the maximum value of the given block.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the block is out of bounds.
+    fn block_max(&self, block: usize) -> Self::Value
+    where
+        Self::Value: Ord,
+    {
+        self.block_range(block)
+            // SAFETY: the range of the block is within bounds
+            .map(|i| unsafe { self.get_value_unchecked(i) })
+            .max()
+            .expect("blocks are nonempty")
+    }
+
+    /// Returns the sum of the values of the given block.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the block is out of bounds.
+    fn block_sum(&self, block: usize) -> Self::Value
+    where
+        Self::Value: Sum,
+    {
+        self.block_range(block)
+            // SAFETY: the range of the block is within bounds
+            .map(|i| unsafe { self.get_value_unchecked(i) })
+            .sum()
+    }
+
+    /// Returns the minimum value of the slice, or `None` if the slice is
+    /// empty, using [`block_min`](BlockSummaries::block_min).
+    fn min_value(&self) -> Option<Self::Value>
+    where
+        Self::Value: Ord,
+    {
+        (0..self.num_blocks()).map(|b| self.block_min(b)).min()
+    }
+
+    /// Returns the maximum value of the slice, or `None` if the slice is
+    /// empty, using [`block_max`](BlockSummaries::block_max).
+    fn max_value(&self) -> Option<Self::Value>
+    where
+        Self::Value: Ord,
+    {
+        (0..self.num_blocks()).map(|b| self.block_max(b)).max()
+    }
+
+    /// Returns the sum of the values of the slice, using
+    /// [`block_sum`](BlockSummaries::block_sum).
+    fn sum_values(&self) -> Self::Value
+    where
+        Self::Value: Sum,
+    {
+        (0..self.num_blocks()).map(|b| self.block_sum(b)).sum()
+    }
+
+    /// Returns the sum of the values in the given range, using
+    /// [`block_sum`](BlockSummaries::block_sum) on the blocks contained in
+    /// the range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn range_sum_by_blocks<R: ComposeRange>(&self, range: R) -> Self::Value
+    where
+        Self::Value: Sum,
+    {
+        let (head, blocks, tail) = split_range(self, range);
+        // SAFETY: head and tail are within bounds
+        let scan = |r: Range<usize>| r.map(|i| unsafe { self.get_value_unchecked(i) });
+        scan(head)
+            .chain(blocks.map(|b| self.block_sum(b)))
+            .chain(scan(tail))
+            .sum()
+    }
+
+    /// Returns the minimum value in the given range, or `None` if the range
+    /// is empty, using [`block_min`](BlockSummaries::block_min) on the
+    /// blocks contained in the range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn range_min_by_blocks<R: ComposeRange>(&self, range: R) -> Option<Self::Value>
+    where
+        Self::Value: Ord,
+    {
+        let (head, blocks, tail) = split_range(self, range);
+        // SAFETY: head and tail are within bounds
+        let scan = |r: Range<usize>| r.map(|i| unsafe { self.get_value_unchecked(i) });
+        scan(head)
+            .chain(blocks.map(|b| self.block_min(b)))
+            .chain(scan(tail))
+            .min()
+    }
+
+    /// Returns the maximum value in the given range, or `None` if the range
+    /// is empty, using [`block_max`](BlockSummaries::block_max) on the
+    /// blocks contained in the range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn range_max_by_blocks<R: ComposeRange>(&self, range: R) -> Option<Self::Value>
+    where
+        Self::Value: Ord,
+    {
+        let (head, blocks, tail) = split_range(self, range);
+        // SAFETY: head and tail are within bounds
+        let scan = |r: Range<usize>| r.map(|i| unsafe { self.get_value_unchecked(i) });
+        scan(head)
+            .chain(blocks.map(|b| self.block_max(b)))
+            .chain(scan(tail))
+            .max()
+    }
+}
+
+/// Splits a range into a head of indices preceding the first block contained
+/// in the range, a range of blocks contained in the range, and a tail of
+/// indices following the last block contained in the range.
+fn split_range<S: BlockSummaries + ?Sized>(
+    slice: &S,
+    range: impl ComposeRange,
+) -> (Range<usize>, Range<usize>, Range<usize>) {
+    let len = slice.len();
+    assert_range(&range, len);
+    let Range { start, end } = range.compose(0..len);
+    let block_size = slice.block_size();
+    let first_block = start.div_ceil(block_size);
+    // The last block might be shorter, and it is contained in the range if
+    // the range ends at the end of the slice
+    let end_block = if end == len {
+        slice.num_blocks()
+    } else {
+        end / block_size
+    };
+    if first_block >= end_block {
+        (start..end, 0..0, end..end)
+    } else {
+        (
+            start..first_block * block_size,
+            first_block..end_block,
+            Ord::min(end_block * block_size, len)..end,
+        )
+    }
+}
+
+impl<S: BlockSummaries + ?Sized> BlockSummaries for &S {
+    #[inline]
+    fn block_size(&self) -> usize {
+        (**self).block_size()
+    }
+
+    #[inline]
+    fn block_min(&self, block: usize) -> Self::Value
+    where
+        Self::Value: Ord,
+    {
+        (**self).block_min(block)
+    }
+
+    #[inline]
+    fn block_max(&self, block: usize) -> Self::Value
+    where
+        Self::Value: Ord,
+    {
+        (**self).block_max(block)
+    }
+
+    #[inline]
+    fn block_sum(&self, block: usize) -> Self::Value
+    where
+        Self::Value: Sum,
+    {
+        (**self).block_sum(block)
+    }
+}
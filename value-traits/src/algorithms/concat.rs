@@ -0,0 +1,79 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::slices::SliceByValue;
+
+/// Appends all values of a slice to a vector.
+fn extend_values<S: SliceByValue>(v: &mut Vec<S::Value>, s: &S) {
+    for i in 0..s.len() {
+        // SAFETY: i is within bounds
+        v.push(unsafe { s.get_value_unchecked(i) });
+    }
+}
+
+/// Concatenates the values of a sequence of by-value slices into a vector.
+///
+/// The vector is allocated once using the total length of the slices. This
+/// is the by-value analogue of
+/// [`concat`](https://doc.rust-lang.org/std/primitive.slice.html#method.concat).
+///
+/// ```rust
+/// use value_traits::algorithms::concat_values;
+///
+/// let a = [1, 2];
+/// let b = vec![3, 4, 5];
+/// assert_eq!(concat_values([&a[..], &b[..], &[]]), vec![1, 2, 3, 4, 5]);
+/// ```
+///
+/// This function is available only if the `alloc` feature is enabled.
+pub fn concat_values<S: SliceByValue>(slices: impl IntoIterator<Item = S>) -> Vec<S::Value> {
+    let slices = slices.into_iter().collect::<Vec<_>>();
+    let mut v = Vec::with_capacity(slices.iter().map(|s| s.len()).sum());
+    for s in &slices {
+        extend_values(&mut v, s);
+    }
+    v
+}
+
+/// Concatenates the values of a sequence of by-value slices into a vector,
+/// placing a separator value between consecutive slices.
+///
+/// The vector is allocated once using the total length of the slices and
+/// the number of separators. This is the by-value analogue of
+/// [`join`](https://doc.rust-lang.org/std/primitive.slice.html#method.join).
+///
+/// ```rust
+/// use value_traits::algorithms::join_values;
+///
+/// let a = [1, 2];
+/// let b = vec![3, 4, 5];
+/// assert_eq!(join_values([&a[..], &b[..], &[]], 0), vec![1, 2, 0, 3, 4, 5, 0]);
+/// ```
+///
+/// This function is available only if the `alloc` feature is enabled.
+pub fn join_values<S: SliceByValue>(
+    slices: impl IntoIterator<Item = S>,
+    sep: S::Value,
+) -> Vec<S::Value>
+where
+    S::Value: Clone,
+{
+    let slices = slices.into_iter().collect::<Vec<_>>();
+    let len = slices.iter().map(|s| s.len()).sum::<usize>() + slices.len().saturating_sub(1);
+    let mut v = Vec::with_capacity(len);
+    for (i, s) in slices.iter().enumerate() {
+        if i != 0 {
+            v.push(sep.clone());
+        }
+        extend_values(&mut v, s);
+    }
+    v
+}
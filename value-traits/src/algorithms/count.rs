@@ -0,0 +1,88 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::hash::Hash;
+use std::{boxed::Box, collections::HashMap, collections::VecDeque, rc::Rc, sync::Arc, vec::Vec};
+
+use crate::slices::SliceByValue;
+
+/// Frequency analysis of the values of a by-value slice.
+///
+/// The default implementations scan the whole slice; implementations that
+/// maintain frequency metadata (e.g., dictionary-based representations) can
+/// override them with faster ones.
+///
+/// ```rust
+/// use value_traits::algorithms::CountValues;
+///
+/// let v = vec![1, 2, 2, 3, 3, 3];
+/// assert_eq!(v.count_value(&3), 3);
+/// let counts = v.count_values();
+/// assert_eq!(counts[&2], 2);
+/// assert_eq!(counts.len(), 3);
+/// ```
+///
+/// This trait is available only if the `std` feature is enabled.
+pub trait CountValues: SliceByValue {
+    /// Returns a map from each distinct value of the slice to the number of
+    /// its occurrences.
+    fn count_values(&self) -> HashMap<Self::Value, usize>
+    where
+        Self::Value: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        for i in 0..self.len() {
+            // SAFETY: i is within bounds
+            let value = unsafe { self.get_value_unchecked(i) };
+            *counts.entry(value).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns the number of occurrences of the given value in the slice.
+    fn count_value(&self, value: &Self::Value) -> usize
+    where
+        Self::Value: PartialEq,
+    {
+        (0..self.len())
+            // SAFETY: i is within bounds
+            .filter(|&i| unsafe { self.get_value_unchecked(i) } == *value)
+            .count()
+    }
+}
+
+impl<T: Clone> CountValues for [T] {}
+impl<T: Clone, const N: usize> CountValues for [T; N] {}
+impl<T: Clone> CountValues for Vec<T> {}
+impl<T: Clone> CountValues for VecDeque<T> {}
+
+macro_rules! impl_count_values_delegation {
+    ($ty:ty) => {
+        impl<S: CountValues + ?Sized> CountValues for $ty {
+            fn count_values(&self) -> HashMap<Self::Value, usize>
+            where
+                Self::Value: Eq + Hash,
+            {
+                (**self).count_values()
+            }
+
+            fn count_value(&self, value: &Self::Value) -> usize
+            where
+                Self::Value: PartialEq,
+            {
+                (**self).count_value(value)
+            }
+        }
+    };
+}
+
+impl_count_values_delegation!(&S);
+impl_count_values_delegation!(&mut S);
+impl_count_values_delegation!(Box<S>);
+impl_count_values_delegation!(Arc<S>);
+impl_count_values_delegation!(Rc<S>);
@@ -0,0 +1,98 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::{iter::FusedIterator, ops::Range};
+
+use crate::slices::SliceByValue;
+
+/// Returns an iterator over the mismatches between two by-value slices.
+///
+/// The iterator returns, in increasing order of index, a triple containing
+/// the index, the value of `a`, and the value of `b` for each index smaller
+/// than the length of both slices at which the values differ. Values past
+/// the end of the shorter slice are not compared, so a difference in length
+/// must be checked separately.
+///
+/// ```rust
+/// use value_traits::algorithms::diff_values;
+///
+/// let a = [1, 2, 3, 4];
+/// let b = [1, 0, 3, 5, 6];
+/// let mut diff = diff_values(&a, &b);
+/// assert_eq!(diff.next(), Some((1, 2, 0)));
+/// assert_eq!(diff.next(), Some((3, 4, 5)));
+/// assert_eq!(diff.next(), None);
+/// ```
+pub fn diff_values<'a, A, B>(a: &'a A, b: &'a B) -> DiffValues<'a, A, B>
+where
+    A: SliceByValue + ?Sized,
+    B: SliceByValue + ?Sized,
+    A::Value: PartialEq<B::Value>,
+{
+    DiffValues {
+        a,
+        b,
+        range: 0..a.len().min(b.len()),
+    }
+}
+
+/// The iterator returned by [`diff_values`].
+#[derive(Debug)]
+pub struct DiffValues<'a, A: ?Sized, B: ?Sized> {
+    a: &'a A,
+    b: &'a B,
+    range: Range<usize>,
+}
+
+impl<A: ?Sized, B: ?Sized> Clone for DiffValues<'_, A, B> {
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a,
+            b: self.b,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<A, B> Iterator for DiffValues<'_, A, B>
+where
+    A: SliceByValue + ?Sized,
+    B: SliceByValue + ?Sized,
+    A::Value: PartialEq<B::Value>,
+{
+    type Item = (usize, A::Value, B::Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for index in self.range.by_ref() {
+            // SAFETY: index is within the bounds of both slices
+            let (a, b) = unsafe {
+                (
+                    self.a.get_value_unchecked(index),
+                    self.b.get_value_unchecked(index),
+                )
+            };
+            if a != b {
+                return Some((index, a, b));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.range.len()))
+    }
+}
+
+impl<A, B> FusedIterator for DiffValues<'_, A, B>
+where
+    A: SliceByValue + ?Sized,
+    B: SliceByValue + ?Sized,
+    A::Value: PartialEq<B::Value>,
+{
+}
@@ -0,0 +1,183 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::hash::{Hash, Hasher};
+
+use crate::slices::SliceByValue;
+
+/// Fingerprints of the sequence of values of a by-value slice.
+///
+/// The fingerprint depends only on the length of the slice and on its
+/// values, as fed to a [`Hasher`] by their [`Hash`] implementation, and not
+/// on the representation of the slice: two different backends holding the
+/// same values have the same fingerprint, which can thus be used to compare
+/// them cheaply, possibly across processes.
+///
+/// This trait is implemented for all types implementing [`SliceByValue`].
+///
+/// ```rust
+/// use value_traits::adapters::functional::FnSlice;
+/// use value_traits::algorithms::FingerprintValues;
+///
+/// let a = [0_usize, 1, 4, 9, 16];
+/// let f = FnSlice::new(5, |i| i * i);
+/// assert_eq!(a.fingerprint_values_128(), f.fingerprint_values_128());
+/// assert_ne!(a.fingerprint_values_128(), a[1..].fingerprint_values_128());
+/// ```
+pub trait FingerprintValues: SliceByValue {
+    /// Feeds the length and the values of the slice to the given hasher, and
+    /// returns its [`finish`](Hasher::finish) value.
+    ///
+    /// The fingerprint is stable across processes and platforms if the
+    /// hasher and the [`Hash`] implementation of the values are. The length
+    /// is fed as a `u64`.
+    fn fingerprint_values<H: Hasher>(&self, mut hasher: H) -> u64
+    where
+        Self::Value: Hash,
+    {
+        hash_values(self, &mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns a 128-bit fingerprint of the slice computed with a
+    /// [`FingerprintHasher`].
+    ///
+    /// The fingerprint is stable across processes and platforms if the
+    /// [`Hash`] implementation of the values is (as it happens for integers,
+    /// strings, and their tuples and slices).
+    fn fingerprint_values_128(&self) -> u128
+    where
+        Self::Value: Hash,
+    {
+        let mut hasher = FingerprintHasher::new();
+        hash_values(self, &mut hasher);
+        hasher.finish128()
+    }
+}
+
+impl<S: SliceByValue + ?Sized> FingerprintValues for S {}
+
+fn hash_values<S: SliceByValue<Value: Hash> + ?Sized, H: Hasher>(s: &S, hasher: &mut H) {
+    hasher.write_u64(s.len() as u64);
+    for i in 0..s.len() {
+        // SAFETY: i is within bounds
+        unsafe { s.get_value_unchecked(i) }.hash(hasher);
+    }
+}
+
+/// The SplitMix64 mixing function.
+#[inline(always)]
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A deterministic [`Hasher`] with 128 bits of state, used by
+/// [`FingerprintValues::fingerprint_values_128`].
+///
+/// The hasher processes integers in a platform-independent way (in
+/// particular, `usize` and `isize` are processed as 64-bit integers, and
+/// endianness does not matter), so the resulting digests are stable across
+/// processes and platforms. It is not a cryptographic hash function, and it
+/// is not resistant to collision attacks.
+#[derive(Debug, Clone)]
+pub struct FingerprintHasher {
+    lanes: [u64; 2],
+    words: u64,
+}
+
+impl FingerprintHasher {
+    const SEEDS: [u64; 2] = [0x243F_6A88_85A3_08D3, 0x1319_8A2E_0370_7344];
+
+    /// Creates a new hasher.
+    pub const fn new() -> Self {
+        Self {
+            lanes: Self::SEEDS,
+            words: 0,
+        }
+    }
+
+    #[inline(always)]
+    fn write_word(&mut self, word: u64) {
+        self.lanes[0] = mix(self.lanes[0] ^ word);
+        self.lanes[1] = mix(self.lanes[1].wrapping_add(word).rotate_left(23));
+        self.words += 1;
+    }
+
+    /// Returns the 128-bit digest of the values written so far.
+    pub fn finish128(&self) -> u128 {
+        let low = mix(self.lanes[0] ^ self.words);
+        let high = mix(self.lanes[1] ^ low);
+        ((high as u128) << 64) | low as u128
+    }
+}
+
+impl Default for FingerprintHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for FingerprintHasher {
+    /// Returns the lower 64 bits of [`finish128`](FingerprintHasher::finish128).
+    fn finish(&self) -> u64 {
+        self.finish128() as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            // SAFETY: chunks have length 8
+            self.write_word(u64::from_le_bytes(unsafe {
+                chunk.try_into().unwrap_unchecked()
+            }));
+        }
+        let mut last = [0; 8];
+        last[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+        self.write_word(u64::from_le_bytes(last));
+        self.write_word(bytes.len() as u64);
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write_word(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write_word(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write_word(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.write_word(i);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write_word(i as u64);
+        self.write_word((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_word(i as u64);
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        // Sign-extend, so that the result does not depend on the platform
+        self.write_word(i as i64 as u64);
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::{
+    panics::{assert_from, assert_index},
+    slices::SliceByValueMut,
+};
+
+/// Moves `value` down from the hole at `pos` in the heap `slice[..len]`.
+///
+/// # Safety
+///
+/// `pos` must be smaller than `len`, which must not be greater than the
+/// length of the slice.
+unsafe fn sift_down<S: SliceByValueMut<Value: Ord> + ?Sized>(
+    slice: &mut S,
+    mut pos: usize,
+    len: usize,
+    value: S::Value,
+) {
+    loop {
+        let mut child = 2 * pos + 1;
+        if child >= len {
+            break;
+        }
+        // SAFETY: child is smaller than len
+        let mut child_value = unsafe { slice.get_value_unchecked(child) };
+        if child + 1 < len {
+            // SAFETY: child + 1 is smaller than len
+            let right_value = unsafe { slice.get_value_unchecked(child + 1) };
+            if right_value > child_value {
+                child += 1;
+                child_value = right_value;
+            }
+        }
+        if value >= child_value {
+            break;
+        }
+        // SAFETY: pos is smaller than len
+        unsafe { slice.set_value_unchecked(pos, child_value) };
+        pos = child;
+    }
+    // SAFETY: pos is smaller than len
+    unsafe { slice.set_value_unchecked(pos, value) };
+}
+
+/// Rearranges the first `len` values of a mutable by-value slice into a
+/// binary max-heap, in linear time.
+///
+/// Together with [`push_heap_value`] and [`pop_heap_value`], this function
+/// makes it possible to keep a priority queue directly inside packed
+/// storage, rather than in a separate `BinaryHeap`. As the length of a
+/// by-value slice is fixed, the heap occupies a prefix of the slice whose
+/// length is kept by the caller, and the length of the slice acts as the
+/// capacity of the queue:
+///
+/// ```rust
+/// use value_traits::algorithms::{heapify_values, pop_heap_value, push_heap_value};
+///
+/// let mut v = [3, 1, 4, 1, 5, 0, 0];
+/// heapify_values(&mut v, 5);
+/// push_heap_value(&mut v, 5, 9);
+/// assert_eq!(pop_heap_value(&mut v, 6), Some(9));
+/// assert_eq!(pop_heap_value(&mut v, 5), Some(5));
+/// assert_eq!(pop_heap_value(&mut v, 4), Some(4));
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `len` is greater than the length of the
+/// slice.
+pub fn heapify_values<S>(slice: &mut S, len: usize)
+where
+    S: SliceByValueMut<Value: Ord> + ?Sized,
+{
+    assert_from(len, slice.len());
+    for pos in (0..len / 2).rev() {
+        // SAFETY: pos is smaller than len, which is within bounds
+        unsafe {
+            let value = slice.get_value_unchecked(pos);
+            sift_down(slice, pos, len, value);
+        }
+    }
+}
+
+/// Pushes a value on the binary max-heap stored in the first `len` values of
+/// a mutable by-value slice, which then occupies the first `len + 1` values.
+///
+/// The operation takes logarithmic time.
+///
+/// # Panics
+///
+/// This function will panic if `len` is not smaller than the length of the
+/// slice, that is, if the heap is full.
+pub fn push_heap_value<S>(slice: &mut S, len: usize, value: S::Value)
+where
+    S: SliceByValueMut<Value: Ord> + ?Sized,
+{
+    assert_index(len, slice.len());
+    let mut pos = len;
+    while pos > 0 {
+        let parent = (pos - 1) / 2;
+        // SAFETY: parent is smaller than pos, which is within bounds
+        let parent_value = unsafe { slice.get_value_unchecked(parent) };
+        if value <= parent_value {
+            break;
+        }
+        // SAFETY: pos is within bounds
+        unsafe { slice.set_value_unchecked(pos, parent_value) };
+        pos = parent;
+    }
+    // SAFETY: pos is within bounds
+    unsafe { slice.set_value_unchecked(pos, value) };
+}
+
+/// Removes and returns the maximum of the binary max-heap stored in the first
+/// `len` values of a mutable by-value slice, which then occupies the first
+/// `len - 1` values, or returns `None` if `len` is zero.
+///
+/// The value at position `len - 1` is left unspecified. The operation takes
+/// logarithmic time.
+///
+/// # Panics
+///
+/// This function will panic if `len` is greater than the length of the
+/// slice.
+pub fn pop_heap_value<S>(slice: &mut S, len: usize) -> Option<S::Value>
+where
+    S: SliceByValueMut<Value: Ord> + ?Sized,
+{
+    assert_from(len, slice.len());
+    if len == 0 {
+        return None;
+    }
+    // SAFETY: 0 and len - 1 are within bounds
+    let (max, last) = unsafe {
+        (
+            slice.get_value_unchecked(0),
+            slice.get_value_unchecked(len - 1),
+        )
+    };
+    if len > 1 {
+        // SAFETY: 0 is smaller than len - 1, which is within bounds
+        unsafe { sift_down(slice, 0, len - 1, last) };
+    }
+    Some(max)
+}
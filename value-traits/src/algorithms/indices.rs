@@ -0,0 +1,243 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{borrow::Cow, borrow::ToOwned, boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::{borrow::Cow, collections::VecDeque, rc::Rc, sync::Arc};
+
+use crate::{
+    adapters::{
+        fallible::Fallible,
+        frozen::Frozen,
+        functional::{ConstSlice, FnSlice},
+        owned::OwnedSubslice,
+        subslice::{SubsliceOf, SubsliceOfMut},
+        validated::Validated,
+        zip::ZipWith,
+    },
+    matrices::{ColView, MatrixByValue, RowView},
+    slices::SliceByValue,
+};
+
+/// Positions of the values of a by-value slice satisfying a predicate.
+///
+/// Filtering positions and then gathering values is the standard scan
+/// pattern. The default implementation tests every value; implementations
+/// able to evaluate predicates more efficiently (e.g., bitmap indices, or
+/// representations with few distinct values) can override it.
+///
+/// This trait is implemented for all by-value slices of this crate,
+/// including subslices, and by the [`Subslices`](crate::Subslices) derive
+/// macro. Other types can use the default implementation with an empty
+/// `impl` block.
+///
+/// ```rust
+/// use value_traits::algorithms::FilterIndices;
+/// use value_traits::slices::SliceByValue;
+///
+/// let v = [3, 8, 1, 9, 4];
+/// let large: Vec<usize> = v.indices_where(|&x| x > 3).collect();
+/// assert_eq!(large, [1, 3, 4]);
+/// let sum: i32 = v.indices_where(|&x| x > 3).map(|i| v.index_value(i)).sum();
+/// assert_eq!(sum, 21);
+/// ```
+pub trait FilterIndices: SliceByValue {
+    /// Returns an iterator over the positions, in increasing order, of the
+    /// values satisfying the given predicate.
+    fn indices_where<P>(&self, mut pred: P) -> impl Iterator<Item = usize>
+    where
+        P: FnMut(&Self::Value) -> bool,
+    {
+        // SAFETY: i is within bounds
+        (0..self.len()).filter(move |&i| pred(&unsafe { self.get_value_unchecked(i) }))
+    }
+}
+
+impl<T: Clone> FilterIndices for [T] {}
+impl<T: Clone, const N: usize> FilterIndices for [T; N] {}
+#[cfg(feature = "alloc")]
+impl<T: Clone> FilterIndices for Vec<T> {}
+#[cfg(feature = "std")]
+impl<T: Clone> FilterIndices for VecDeque<T> {}
+
+macro_rules! impl_filter_indices_delegation {
+    ($ty:ty) => {
+        impl<S: FilterIndices + ?Sized> FilterIndices for $ty {
+            fn indices_where<P>(&self, pred: P) -> impl Iterator<Item = usize>
+            where
+                P: FnMut(&Self::Value) -> bool,
+            {
+                (**self).indices_where(pred)
+            }
+        }
+    };
+}
+
+impl_filter_indices_delegation!(&S);
+impl_filter_indices_delegation!(&mut S);
+#[cfg(feature = "alloc")]
+impl_filter_indices_delegation!(Box<S>);
+#[cfg(feature = "std")]
+impl_filter_indices_delegation!(Arc<S>);
+#[cfg(feature = "std")]
+impl_filter_indices_delegation!(Rc<S>);
+
+#[cfg(feature = "alloc")]
+impl<S: FilterIndices + ToOwned + ?Sized> FilterIndices for Cow<'_, S> {
+    fn indices_where<P>(&self, pred: P) -> impl Iterator<Item = usize>
+    where
+        P: FnMut(&Self::Value) -> bool,
+    {
+        (**self).indices_where(pred)
+    }
+}
+
+// Backends and adapters of this crate use the default implementation
+impl<S: SliceByValue> FilterIndices for Option<S> {}
+impl<M: MatrixByValue + ?Sized> FilterIndices for RowView<'_, M> {}
+impl<M: MatrixByValue + ?Sized> FilterIndices for ColView<'_, M> {}
+impl<S: SliceByValue + ?Sized> FilterIndices for SubsliceOf<'_, S> {}
+impl<S: SliceByValue + ?Sized> FilterIndices for SubsliceOfMut<'_, S> {}
+impl<P: core::ops::Deref<Target: SliceByValue>> FilterIndices for OwnedSubslice<P> {}
+impl<V, F: Fn(usize) -> V> FilterIndices for FnSlice<F> {}
+impl<T: Clone> FilterIndices for ConstSlice<T> {}
+impl<S: SliceByValue> FilterIndices for Fallible<S> {}
+impl<S: SliceByValue> FilterIndices for Frozen<S> {}
+impl<S: SliceByValue, I> FilterIndices for Validated<S, I> {}
+impl<A, B, V, F> FilterIndices for ZipWith<A, B, F>
+where
+    A: SliceByValue,
+    B: SliceByValue,
+    F: Fn(A::Value, B::Value) -> V,
+{
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::FilterIndices;
+    use crate::{
+        adapters::{
+            buffered::BufferedSlice,
+            compact::{Compacted, RunLength, Sparse},
+            cow::SubsliceCow,
+            double_buffered::DoubleBuffered,
+            front_coded::FrontCoded,
+            journaled::Journaled,
+            lazy::LazySlice,
+            observed::ObservedSlice,
+            paged::PagedVec,
+            persistent::{PersistentBuilder, PersistentSlice},
+            shared_mut::SharedRangeMut,
+        },
+        slices::{SliceByValueMut, SliceByValueSubsliceGat},
+    };
+
+    impl<I: Iterator<Item: Clone>> FilterIndices for BufferedSlice<I> {}
+    impl<V: Clone> FilterIndices for RunLength<V> {}
+    impl<V: Clone> FilterIndices for Sparse<V> {}
+    impl<V: Clone> FilterIndices for Compacted<V> {}
+    impl<'a, S: SliceByValueSubsliceGat<'a, Value: Clone> + ?Sized> FilterIndices
+        for SubsliceCow<'a, S>
+    {
+    }
+    impl<S: SliceByValueMut> FilterIndices for DoubleBuffered<S> {}
+    impl FilterIndices for FrontCoded {}
+    impl<S: SliceByValueMut> FilterIndices for Journaled<S> {}
+    impl<V: Clone, F: Fn(usize) -> V> FilterIndices for LazySlice<V, F> {}
+    impl<S: SliceByValueMut, F: FnMut(usize, S::Value, S::Value)> FilterIndices
+        for ObservedSlice<S, F>
+    {
+    }
+    impl<T: Clone> FilterIndices for PagedVec<T> {}
+    impl<V: Clone> FilterIndices for PersistentSlice<V> {}
+    impl<V: Clone> FilterIndices for PersistentBuilder<V> {}
+    impl<S: SliceByValueMut> FilterIndices for SharedRangeMut<'_, S> {}
+}
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::FilterIndices;
+    use crate::{
+        adapters::{
+            lazy::SyncLazySlice,
+            locked::{ArcLockedSlice, ArcLockedWrite},
+            shared::{ArcValues, Snapshot},
+            unique::{UniqueArc, UniqueRc},
+        },
+        slices::{SliceByValue, SliceByValueMut},
+    };
+
+    macro_rules! impl_filter_indices_unique {
+        ($ty:ident) => {
+            impl<S: FilterIndices + ?Sized> FilterIndices for $ty<S> {
+                fn indices_where<P>(&self, pred: P) -> impl Iterator<Item = usize>
+                where
+                    P: FnMut(&Self::Value) -> bool,
+                {
+                    self.as_ref().indices_where(pred)
+                }
+            }
+        };
+    }
+
+    impl_filter_indices_unique!(UniqueArc);
+    impl_filter_indices_unique!(UniqueRc);
+
+    impl<V: Clone, F: Fn(usize) -> V> FilterIndices for SyncLazySlice<V, F> {}
+    impl<S: SliceByValueMut> FilterIndices for ArcLockedSlice<S> {}
+    impl<S: SliceByValueMut> FilterIndices for ArcLockedWrite<'_, S> {}
+    impl<S: SliceByValue + ?Sized> FilterIndices for ArcValues<S> {}
+    impl<S: SliceByValue> FilterIndices for Snapshot<S> {}
+}
+
+#[cfg(feature = "ndarray")]
+impl<A: Clone, S: ndarray::Data<Elem = A>> FilterIndices for ndarray::ArrayBase<S, ndarray::Ix1> {}
+
+#[cfg(feature = "either")]
+impl<L: SliceByValue, R: SliceByValue<Value = L::Value>> FilterIndices for either::Either<L, R> {}
+
+#[cfg(feature = "arrow")]
+impl<T: arrow_array::ArrowPrimitiveType> FilterIndices for arrow_array::PrimitiveArray<T> {}
+
+#[cfg(feature = "bitvec")]
+mod bitvec_impls {
+    use super::FilterIndices;
+    use bitvec::{order::BitOrder, slice::BitSlice, store::BitStore};
+
+    /// Evaluates the predicate once for each boolean value, and then
+    /// iterates over ones or zeros using the word-level operations of
+    /// [`BitSlice`].
+    impl<T: BitStore, O: BitOrder> FilterIndices for BitSlice<T, O> {
+        fn indices_where<P>(&self, mut pred: P) -> impl Iterator<Item = usize>
+        where
+            P: FnMut(&bool) -> bool,
+        {
+            let (one, zero) = (pred(&true), pred(&false));
+            // At most one of the three iterators is present
+            let all = (one && zero).then(|| 0..self.len());
+            let ones = (one && !zero).then(|| self.iter_ones());
+            let zeros = (zero && !one).then(|| self.iter_zeros());
+            all.into_iter()
+                .flatten()
+                .chain(ones.into_iter().flatten())
+                .chain(zeros.into_iter().flatten())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T: BitStore, O: BitOrder> FilterIndices for bitvec::vec::BitVec<T, O> {
+        #[inline]
+        fn indices_where<P>(&self, pred: P) -> impl Iterator<Item = usize>
+        where
+            P: FnMut(&bool) -> bool,
+        {
+            self.as_bitslice().indices_where(pred)
+        }
+    }
+}
@@ -0,0 +1,192 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::{collections::VecDeque, rc::Rc, sync::Arc};
+
+use crate::slices::SliceByValue;
+
+/// Returns the index of the first mismatch between two by-value slices, or
+/// `None` if they are equal.
+///
+/// The first mismatch is the first index at which the values differ or, if
+/// one slice is a proper prefix of the other, the length of the shorter
+/// slice.
+///
+/// The comparison is performed value by value; when comparing two slices of
+/// the same type, [`MismatchValues::mismatch_value`] might be faster.
+///
+/// ```rust
+/// use value_traits::algorithms::mismatch_value;
+///
+/// assert_eq!(mismatch_value(&[1, 2, 3], &[1, 5, 3]), Some(1));
+/// assert_eq!(mismatch_value(&[1, 2], &[1, 2, 3]), Some(2));
+/// assert_eq!(mismatch_value(&[1, 2, 3], &[1, 2, 3]), None);
+/// ```
+pub fn mismatch_value<A, B>(a: &A, b: &B) -> Option<usize>
+where
+    A: SliceByValue + ?Sized,
+    B: SliceByValue + ?Sized,
+    A::Value: PartialEq<B::Value>,
+{
+    let len = a.len().min(b.len());
+    (0..len)
+        // SAFETY: i is within the bounds of both slices
+        .find(|&i| unsafe { a.get_value_unchecked(i) != b.get_value_unchecked(i) })
+        .or((a.len() != b.len()).then_some(len))
+}
+
+/// Returns the length of the longest common prefix of two by-value slices.
+///
+/// The comparison is performed value by value; when comparing two slices of
+/// the same type, [`MismatchValues::common_prefix_len`] might be faster.
+///
+/// ```rust
+/// use value_traits::algorithms::common_prefix_len;
+///
+/// assert_eq!(common_prefix_len(b"apple", b"apply"), 4);
+/// assert_eq!(common_prefix_len(b"app", b"apple"), 3);
+/// ```
+pub fn common_prefix_len<A, B>(a: &A, b: &B) -> usize
+where
+    A: SliceByValue + ?Sized,
+    B: SliceByValue + ?Sized,
+    A::Value: PartialEq<B::Value>,
+{
+    mismatch_value(a, b).unwrap_or(a.len())
+}
+
+/// Overridable mismatch computations between by-value slices of the same
+/// type.
+///
+/// The default implementations delegate to the [`mismatch_value`] and
+/// [`common_prefix_len`] functions, which compare values one by one;
+/// implementations can override them with word-level or bulk comparisons.
+/// The implementations for slices, arrays, and vectors compare blocks of
+/// values first, so comparisons of, say, bytes use `memcmp`.
+///
+/// ```rust
+/// use value_traits::algorithms::MismatchValues;
+///
+/// let a = [0_u8; 1000];
+/// let mut b = a;
+/// b[700] = 1;
+/// assert_eq!(a.mismatch_value(&b), Some(700));
+/// assert_eq!(a.common_prefix_len(&b), 700);
+/// ```
+pub trait MismatchValues: SliceByValue {
+    /// Returns the index of the first mismatch with another slice, or `None`
+    /// if the slices are equal.
+    ///
+    /// See [`mismatch_value`].
+    fn mismatch_value(&self, other: &Self) -> Option<usize>
+    where
+        Self::Value: PartialEq,
+    {
+        mismatch_value(self, other)
+    }
+
+    /// Returns the length of the longest common prefix with another slice.
+    ///
+    /// See [`common_prefix_len`].
+    fn common_prefix_len(&self, other: &Self) -> usize
+    where
+        Self::Value: PartialEq,
+    {
+        self.mismatch_value(other).unwrap_or(self.len())
+    }
+}
+
+/// Block size used by the implementations for slices.
+const BLOCK_SIZE: usize = 64;
+
+/// Returns the index of the first mismatch between two standard slices,
+/// comparing whole blocks first.
+fn mismatch_blocks<T: PartialEq>(a: &[T], b: &[T]) -> Option<usize> {
+    let len = a.len().min(b.len());
+    let (a_prefix, b_prefix) = (&a[..len], &b[..len]);
+    let mut start = 0;
+    for (a_block, b_block) in a_prefix.chunks(BLOCK_SIZE).zip(b_prefix.chunks(BLOCK_SIZE)) {
+        if a_block != b_block {
+            return a_block
+                .iter()
+                .zip(b_block)
+                .position(|(x, y)| x != y)
+                .map(|i| start + i);
+        }
+        start += BLOCK_SIZE;
+    }
+    (a.len() != b.len()).then_some(len)
+}
+
+impl<T: Clone> MismatchValues for [T] {
+    #[inline]
+    fn mismatch_value(&self, other: &Self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        mismatch_blocks(self, other)
+    }
+}
+
+impl<T: Clone, const N: usize> MismatchValues for [T; N] {
+    #[inline]
+    fn mismatch_value(&self, other: &Self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        mismatch_blocks(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> MismatchValues for Vec<T> {
+    #[inline]
+    fn mismatch_value(&self, other: &Self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        mismatch_blocks(self, other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Clone> MismatchValues for VecDeque<T> {}
+
+macro_rules! impl_mismatch_values_delegation {
+    ($ty:ty) => {
+        impl<S: MismatchValues + ?Sized> MismatchValues for $ty {
+            #[inline]
+            fn mismatch_value(&self, other: &Self) -> Option<usize>
+            where
+                Self::Value: PartialEq,
+            {
+                (**self).mismatch_value(&**other)
+            }
+
+            #[inline]
+            fn common_prefix_len(&self, other: &Self) -> usize
+            where
+                Self::Value: PartialEq,
+            {
+                (**self).common_prefix_len(&**other)
+            }
+        }
+    };
+}
+
+impl_mismatch_values_delegation!(&S);
+impl_mismatch_values_delegation!(&mut S);
+#[cfg(feature = "alloc")]
+impl_mismatch_values_delegation!(Box<S>);
+#[cfg(feature = "std")]
+impl_mismatch_values_delegation!(Arc<S>);
+#[cfg(feature = "std")]
+impl_mismatch_values_delegation!(Rc<S>);
@@ -0,0 +1,68 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Generic algorithms on by-value slices.
+
+mod analyze;
+pub use analyze::*;
+
+mod blocks;
+pub use blocks::*;
+
+mod diff;
+pub use diff::*;
+
+mod fingerprint;
+pub use fingerprint::*;
+
+mod heap;
+pub use heap::*;
+
+mod indices;
+pub use indices::*;
+
+mod mismatch;
+pub use mismatch::*;
+
+mod partition;
+pub use partition::*;
+
+mod rank_select;
+pub use rank_select::*;
+
+mod ranges;
+pub use ranges::*;
+
+mod rechunk;
+pub use rechunk::*;
+
+#[cfg(feature = "alloc")]
+mod concat;
+#[cfg(feature = "alloc")]
+pub use concat::*;
+
+#[cfg(feature = "alloc")]
+mod sort;
+#[cfg(feature = "alloc")]
+pub use sort::*;
+
+#[cfg(feature = "alloc")]
+mod top_k;
+#[cfg(feature = "alloc")]
+pub use top_k::*;
+
+#[cfg(feature = "std")]
+mod count;
+#[cfg(feature = "std")]
+pub use count::*;
+
+mod stats;
+pub use stats::*;
+
+mod validate;
+pub use validate::*;
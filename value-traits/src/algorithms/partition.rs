@@ -0,0 +1,116 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::slices::SliceByValueMut;
+
+/// Reorders the values of a mutable by-value slice so that all values
+/// satisfying a predicate precede all values that do not, and returns the
+/// number of values satisfying the predicate.
+///
+/// The relative order of the values is not preserved; see
+/// [`stable_partition_values`] for a stable, allocating variant. The
+/// predicate is called exactly once on each value, and each value is moved
+/// at most once.
+///
+/// ```rust
+/// use value_traits::algorithms::partition_in_place_values;
+///
+/// let mut v = [1, 2, 3, 4, 5, 6, 7];
+/// let split = partition_in_place_values(&mut v, |&x| x % 2 == 0);
+/// assert_eq!(split, 3);
+/// assert!(v[..split].iter().all(|&x| x % 2 == 0));
+/// assert!(v[split..].iter().all(|&x| x % 2 != 0));
+/// ```
+pub fn partition_in_place_values<S, P>(slice: &mut S, mut pred: P) -> usize
+where
+    S: SliceByValueMut + ?Sized,
+    P: FnMut(&S::Value) -> bool,
+{
+    let (mut front, mut back) = (0, slice.len());
+    loop {
+        // Find the first value not satisfying the predicate
+        let left = loop {
+            if front == back {
+                return front;
+            }
+            // SAFETY: front is smaller than back, which is within bounds
+            let value = unsafe { slice.get_value_unchecked(front) };
+            if !pred(&value) {
+                break value;
+            }
+            front += 1;
+        };
+        // Find the last value satisfying the predicate
+        let right = loop {
+            back -= 1;
+            if front == back {
+                return front;
+            }
+            // SAFETY: back is smaller than the length
+            let value = unsafe { slice.get_value_unchecked(back) };
+            if pred(&value) {
+                break value;
+            }
+        };
+        // SAFETY: front < back, which are both within bounds
+        unsafe {
+            slice.set_value_unchecked(front, right);
+            slice.set_value_unchecked(back, left);
+        }
+        front += 1;
+    }
+}
+
+/// Reorders the values of a mutable by-value slice so that all values
+/// satisfying a predicate precede all values that do not, preserving their
+/// relative order, and returns the number of values satisfying the
+/// predicate.
+///
+/// The values not satisfying the predicate are buffered in a vector; the
+/// predicate is called exactly once on each value.
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use value_traits::algorithms::stable_partition_values;
+///
+/// let mut v = [1, 2, 3, 4, 5, 6, 7];
+/// assert_eq!(stable_partition_values(&mut v, |&x| x % 2 == 0), 3);
+/// assert_eq!(v, [2, 4, 6, 1, 3, 5, 7]);
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn stable_partition_values<S, P>(slice: &mut S, mut pred: P) -> usize
+where
+    S: SliceByValueMut + ?Sized,
+    P: FnMut(&S::Value) -> bool,
+{
+    let len = slice.len();
+    let mut rejected = Vec::new();
+    let mut split = 0;
+    for i in 0..len {
+        // SAFETY: i is within bounds
+        let value = unsafe { slice.get_value_unchecked(i) };
+        if pred(&value) {
+            if split != i {
+                // SAFETY: split < i, which is within bounds
+                unsafe { slice.set_value_unchecked(split, value) };
+            }
+            split += 1;
+        } else {
+            rejected.push(value);
+        }
+    }
+    for (i, value) in (split..len).zip(rejected) {
+        // SAFETY: i is within bounds
+        unsafe { slice.set_value_unchecked(i, value) };
+    }
+    split
+}
@@ -0,0 +1,135 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+use core::iter::Sum;
+#[cfg(feature = "std")]
+use std::{collections::VecDeque, rc::Rc, sync::Arc};
+
+use crate::{
+    panics::assert_range,
+    slices::{ComposeRange, SliceByValue},
+};
+
+/// Sum, minimum, and maximum queries on ranges of a by-value slice.
+///
+/// The default implementations scan the range; implementations that
+/// maintain suitable indices (e.g., succinct range-minimum structures) can
+/// override them with faster ones. Implementations of
+/// [`BlockSummaries`](super::BlockSummaries) can override them by delegating
+/// to the corresponding methods of that trait, such as
+/// [`range_sum_by_blocks`](super::BlockSummaries::range_sum_by_blocks),
+/// which scan only the partial blocks at the ends of the range.
+///
+/// ```rust
+/// use value_traits::algorithms::RangeQueries;
+///
+/// let v = [5, 1, 4, 2, 3];
+/// assert_eq!(v.range_sum(1..4), 7);
+/// assert_eq!(v.range_min(2..), Some(2));
+/// assert_eq!(v.range_max(..=1), Some(5));
+/// assert_eq!(v.range_max(3..3), None);
+/// ```
+pub trait RangeQueries: SliceByValue {
+    /// Returns the sum of the values in the given range.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn range_sum<R: ComposeRange>(&self, range: R) -> Self::Value
+    where
+        Self::Value: Sum,
+    {
+        assert_range(&range, self.len());
+        range
+            .compose(0..self.len())
+            // SAFETY: the range is within bounds
+            .map(|i| unsafe { self.get_value_unchecked(i) })
+            .sum()
+    }
+
+    /// Returns the minimum value in the given range, or `None` if the range
+    /// is empty.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn range_min<R: ComposeRange>(&self, range: R) -> Option<Self::Value>
+    where
+        Self::Value: Ord,
+    {
+        assert_range(&range, self.len());
+        range
+            .compose(0..self.len())
+            // SAFETY: the range is within bounds
+            .map(|i| unsafe { self.get_value_unchecked(i) })
+            .min()
+    }
+
+    /// Returns the maximum value in the given range, or `None` if the range
+    /// is empty.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn range_max<R: ComposeRange>(&self, range: R) -> Option<Self::Value>
+    where
+        Self::Value: Ord,
+    {
+        assert_range(&range, self.len());
+        range
+            .compose(0..self.len())
+            // SAFETY: the range is within bounds
+            .map(|i| unsafe { self.get_value_unchecked(i) })
+            .max()
+    }
+}
+
+impl<T: Clone> RangeQueries for [T] {}
+impl<T: Clone, const N: usize> RangeQueries for [T; N] {}
+#[cfg(feature = "alloc")]
+impl<T: Clone> RangeQueries for Vec<T> {}
+#[cfg(feature = "std")]
+impl<T: Clone> RangeQueries for VecDeque<T> {}
+
+macro_rules! impl_range_queries_delegation {
+    ($ty:ty) => {
+        impl<S: RangeQueries + ?Sized> RangeQueries for $ty {
+            fn range_sum<R: ComposeRange>(&self, range: R) -> Self::Value
+            where
+                Self::Value: Sum,
+            {
+                (**self).range_sum(range)
+            }
+
+            fn range_min<R: ComposeRange>(&self, range: R) -> Option<Self::Value>
+            where
+                Self::Value: Ord,
+            {
+                (**self).range_min(range)
+            }
+
+            fn range_max<R: ComposeRange>(&self, range: R) -> Option<Self::Value>
+            where
+                Self::Value: Ord,
+            {
+                (**self).range_max(range)
+            }
+        }
+    };
+}
+
+impl_range_queries_delegation!(&S);
+impl_range_queries_delegation!(&mut S);
+#[cfg(feature = "alloc")]
+impl_range_queries_delegation!(Box<S>);
+#[cfg(feature = "std")]
+impl_range_queries_delegation!(Arc<S>);
+#[cfg(feature = "std")]
+impl_range_queries_delegation!(Rc<S>);
@@ -0,0 +1,122 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::{collections::VecDeque, rc::Rc, sync::Arc};
+
+use crate::{panics::assert_index, slices::SliceByValue};
+
+/// Rank and select operations on by-value slices of booleans.
+///
+/// The _rank_ of a position is the number of `true` values before it, and
+/// selecting the `k`-th `true` value returns its position. The default
+/// implementations scan the slice; indexed implementations (e.g., succinct
+/// bit vectors with rank and select structures) can override them with
+/// faster ones.
+///
+/// ```rust
+/// use value_traits::algorithms::RankSelect;
+///
+/// let mask = [true, false, false, true, true];
+/// assert_eq!(mask.count_ones_before(4), 2);
+/// assert_eq!(mask.select_one(2), Some(4));
+/// assert_eq!(mask.select_one(3), None);
+/// ```
+pub trait RankSelect: SliceByValue<Value = bool> {
+    /// Returns the number of `true` values before the given position.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the position is greater than the length of
+    /// the slice.
+    fn count_ones_before(&self, pos: usize) -> usize {
+        if pos != self.len() {
+            assert_index(pos, self.len());
+        }
+        (0..pos)
+            // SAFETY: i is within bounds
+            .filter(|&i| unsafe { self.get_value_unchecked(i) })
+            .count()
+    }
+
+    /// Returns the position of the `true` value of given rank (i.e., the
+    /// position `p` such that the value at `p` is `true` and
+    /// [`count_ones_before(p)`](RankSelect::count_ones_before) is `rank`), or
+    /// `None` if there are not enough `true` values.
+    fn select_one(&self, rank: usize) -> Option<usize> {
+        (0..self.len())
+            // SAFETY: i is within bounds
+            .filter(|&i| unsafe { self.get_value_unchecked(i) })
+            .nth(rank)
+    }
+}
+
+impl RankSelect for [bool] {}
+impl<const N: usize> RankSelect for [bool; N] {}
+#[cfg(feature = "alloc")]
+impl RankSelect for Vec<bool> {}
+#[cfg(feature = "std")]
+impl RankSelect for VecDeque<bool> {}
+
+macro_rules! impl_rank_select_delegation {
+    ($ty:ty) => {
+        impl<S: RankSelect + ?Sized> RankSelect for $ty {
+            fn count_ones_before(&self, pos: usize) -> usize {
+                (**self).count_ones_before(pos)
+            }
+
+            fn select_one(&self, rank: usize) -> Option<usize> {
+                (**self).select_one(rank)
+            }
+        }
+    };
+}
+
+impl_rank_select_delegation!(&S);
+impl_rank_select_delegation!(&mut S);
+#[cfg(feature = "alloc")]
+impl_rank_select_delegation!(Box<S>);
+#[cfg(feature = "std")]
+impl_rank_select_delegation!(Arc<S>);
+#[cfg(feature = "std")]
+impl_rank_select_delegation!(Rc<S>);
+
+#[cfg(feature = "bitvec")]
+mod bitvec_impls {
+    use super::RankSelect;
+    use bitvec::{order::BitOrder, slice::BitSlice, store::BitStore};
+
+    /// Counts and searches bits using the word-level operations of
+    /// [`BitSlice`].
+    impl<T: BitStore, O: BitOrder> RankSelect for BitSlice<T, O> {
+        #[inline]
+        fn count_ones_before(&self, pos: usize) -> usize {
+            self[..pos].count_ones()
+        }
+
+        #[inline]
+        fn select_one(&self, rank: usize) -> Option<usize> {
+            self.iter_ones().nth(rank)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T: BitStore, O: BitOrder> RankSelect for bitvec::vec::BitVec<T, O> {
+        #[inline]
+        fn count_ones_before(&self, pos: usize) -> usize {
+            self.as_bitslice().count_ones_before(pos)
+        }
+
+        #[inline]
+        fn select_one(&self, rank: usize) -> Option<usize> {
+            self.as_bitslice().select_one(rank)
+        }
+    }
+}
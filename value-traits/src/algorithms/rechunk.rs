@@ -0,0 +1,213 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::iter::{Iter, IterateByValue};
+
+/// An iterator regrouping the values returned by another iterator into
+/// vectors of a given length.
+///
+/// All vectors have the requested length, except possibly the last one,
+/// which contains the remaining values. If the underlying iterator
+/// implements [`ExactSizeIterator`], so does this iterator.
+///
+/// See [`rechunk_values`].
+///
+/// This structure is available only if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct Rechunk<I> {
+    iter: I,
+    chunk_size: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Rechunk<I> {
+    /// Creates a new iterator regrouping the values returned by `iter` into
+    /// vectors of length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `chunk_size` is zero.
+    pub fn new(iter: I, chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be positive");
+        Self { iter, chunk_size }
+    }
+
+    /// Returns the length of the chunks.
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Iterator for Rechunk<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        chunk.push(first);
+        chunk.extend(self.iter.by_ref().take(self.chunk_size - 1));
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.div_ceil(self.chunk_size),
+            upper.map(|upper| upper.div_ceil(self.chunk_size)),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: ExactSizeIterator> ExactSizeIterator for Rechunk<I> {}
+
+#[cfg(feature = "alloc")]
+impl<I: FusedIterator> FusedIterator for Rechunk<I> {}
+
+/// Returns an iterator regrouping the values of a by-value sequence into
+/// vectors of length `chunk_size`, except possibly the last one.
+///
+/// This is the simplest way to feed batch APIs, such as buffered writers or
+/// device uploads, from any by-value sequence. To regroup an arbitrary
+/// iterator, use [`Rechunk::new`].
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use value_traits::algorithms::rechunk_values;
+/// use value_traits::adapters::functional::FnSlice;
+///
+/// let f = FnSlice::new(7, |i| i * i);
+/// let chunks = rechunk_values(&f, 3);
+/// assert_eq!(chunks.len(), 3);
+/// assert_eq!(
+///     chunks.collect::<Vec<_>>(),
+///     [vec![0, 1, 4], vec![9, 16, 25], vec![36]]
+/// );
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `chunk_size` is zero.
+///
+/// This function is available only if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn rechunk_values<S>(source: &S, chunk_size: usize) -> Rechunk<Iter<'_, S>>
+where
+    S: IterateByValue + ?Sized,
+{
+    Rechunk::new(source.iter_value(), chunk_size)
+}
+
+/// An iterator regrouping the values returned by another iterator into
+/// arrays of length `N`.
+///
+/// If the number of values is not a multiple of `N`, the last values are
+/// not returned by the iterator, and they are available from
+/// [`into_remainder`](RechunkArrays::into_remainder) once the iterator has
+/// been exhausted. If the underlying iterator implements
+/// [`ExactSizeIterator`], so does this iterator.
+///
+/// See [`rechunk_values_array`].
+#[derive(Debug, Clone)]
+pub struct RechunkArrays<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: [Option<I::Item>; N],
+}
+
+impl<I: Iterator, const N: usize> RechunkArrays<I, N> {
+    /// Creates a new iterator regrouping the values returned by `iter` into
+    /// arrays of length `N`.
+    ///
+    /// Using this method with `N` equal to zero is a compile-time error.
+    pub fn new(iter: I) -> Self {
+        const { assert!(N != 0, "chunk size must be nonzero") };
+        Self {
+            iter,
+            remainder: [const { None }; N],
+        }
+    }
+
+    /// Returns an iterator over the values that did not fill an array.
+    ///
+    /// The iterator is empty if this iterator has not been exhausted yet.
+    pub fn into_remainder(self) -> core::iter::Flatten<core::array::IntoIter<Option<I::Item>, N>> {
+        self.remainder.into_iter().flatten()
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for RechunkArrays<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = [const { None }; N];
+        for (i, slot) in chunk.iter_mut().enumerate() {
+            match self.iter.next() {
+                Some(value) => *slot = Some(value),
+                None => {
+                    if i != 0 {
+                        self.remainder = chunk;
+                    }
+                    return None;
+                }
+            }
+        }
+        Some(chunk.map(|value| value.unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|upper| upper / N))
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeIterator for RechunkArrays<I, N> {}
+
+impl<I: FusedIterator, const N: usize> FusedIterator for RechunkArrays<I, N> {}
+
+/// Returns an iterator regrouping the values of a by-value sequence into
+/// arrays of length `N`.
+///
+/// This function does not allocate. If the number of values is not a
+/// multiple of `N`, the last values are not returned by the iterator, and
+/// they are available from
+/// [`into_remainder`](RechunkArrays::into_remainder). By-value slices can
+/// use [`array_chunks_value`](crate::slices::SliceByValueExt::array_chunks_value)
+/// instead, which accesses directly the values of each chunk.
+///
+/// ```rust
+/// use value_traits::algorithms::rechunk_values_array;
+///
+/// let v = [1, 2, 3, 4, 5, 6, 7];
+/// let mut chunks = rechunk_values_array::<_, 3>(&v);
+/// assert_eq!(chunks.len(), 2);
+/// assert_eq!(chunks.next(), Some([1, 2, 3]));
+/// assert_eq!(chunks.next(), Some([4, 5, 6]));
+/// assert_eq!(chunks.next(), None);
+/// assert!(chunks.into_remainder().eq([7]));
+/// ```
+///
+/// Using this function with `N` equal to zero is a compile-time error.
+pub fn rechunk_values_array<S, const N: usize>(source: &S) -> RechunkArrays<Iter<'_, S>, N>
+where
+    S: IterateByValue + ?Sized,
+{
+    RechunkArrays::new(source.iter_value())
+}
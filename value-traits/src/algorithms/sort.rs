@@ -0,0 +1,64 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+use crate::slices::SliceByValueMut;
+
+/// Sorts a mutable by-value slice of small values with a counting sort.
+///
+/// Values are mapped to buckets using their [`Into<usize>`] implementation,
+/// which must be order-preserving and injective (as it happens for unsigned
+/// integers and booleans). The slice is read once to count the values in
+/// each bucket, and then written once sequentially, so sorting takes time
+/// *O*(*n* + *k*) and space *O*(*k*), where *n* is the length of the slice
+/// and *k* is `max_value`. This is much faster than a comparison sort when
+/// *k* is not much larger than *n*, and sequential writes are efficient
+/// on packed storage.
+///
+/// ```rust
+/// use value_traits::algorithms::sort_values_counting;
+///
+/// let mut v = [3_u8, 0, 2, 3, 1, 0];
+/// sort_values_counting(&mut v, 3);
+/// assert_eq!(v, [0, 0, 1, 2, 3, 3]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if a value is greater than `max_value`; in that
+/// case, the slice is left unchanged.
+pub fn sort_values_counting<S>(slice: &mut S, max_value: usize)
+where
+    S: SliceByValueMut<Value: Into<usize> + Clone> + ?Sized,
+{
+    let num_buckets = max_value.checked_add(1).expect("too many buckets");
+    let mut counts = vec![0_usize; num_buckets];
+    // A value for each non-empty bucket, used to write the bucket back
+    let mut values: Vec<Option<S::Value>> = Vec::new();
+    values.resize_with(num_buckets, || None);
+    for i in 0..slice.len() {
+        // SAFETY: i is within bounds
+        let value = unsafe { slice.get_value_unchecked(i) };
+        let bucket: usize = value.clone().into();
+        assert!(bucket <= max_value, "value greater than the maximum value");
+        counts[bucket] += 1;
+        values[bucket].get_or_insert(value);
+    }
+    let mut pos = 0;
+    for (count, value) in counts.into_iter().zip(values) {
+        if let Some(value) = value {
+            for i in pos..pos + count {
+                // SAFETY: counts sum to the length of the slice
+                unsafe { slice.set_value_unchecked(i, value.clone()) };
+            }
+            pos += count;
+        }
+    }
+}
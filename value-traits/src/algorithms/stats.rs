@@ -0,0 +1,151 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::fmt::{self, Display};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::{collections::VecDeque, rc::Rc, sync::Arc};
+
+use crate::slices::SliceByValue;
+
+/// Diagnostic information about a by-value slice, returned by
+/// [`DescribeSliceByValue::stats`].
+///
+/// The [`Display`] implementation prints a one-line human-readable summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SliceStats {
+    /// The number of values in the slice.
+    pub len: usize,
+    /// The number of bits used to store each value, if known.
+    pub value_size_hint: Option<usize>,
+    /// The name of the representation backing the slice.
+    pub backend_name: &'static str,
+}
+
+impl Display for SliceStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} values", self.backend_name, self.len)?;
+        match self.value_size_hint {
+            Some(bits) => write!(f, ", {bits} bits/value"),
+            None => write!(f, ", variable size"),
+        }
+    }
+}
+
+/// Description of the representation backing a by-value slice.
+///
+/// This trait makes it possible to report which representation backs a
+/// by-value slice without downcasting, for example for monitoring or
+/// logging purposes. Both methods have default implementations, which
+/// implementations can override with more precise information.
+///
+/// ```rust
+/// use value_traits::algorithms::DescribeSliceByValue;
+///
+/// let stats = [1_u16, 2, 3].stats();
+/// assert_eq!(stats.len, 3);
+/// assert_eq!(stats.value_size_hint, Some(16));
+/// assert_eq!(stats.backend_name, "[u16; 3]");
+/// ```
+pub trait DescribeSliceByValue: SliceByValue {
+    /// Returns the name of the representation backing the slice.
+    ///
+    /// The default implementation returns [`core::any::type_name`] of the
+    /// implementing type.
+    fn backend_name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
+    /// Returns the number of bits used to store each value, or `None` if it
+    /// is not known, or not the same for all values.
+    ///
+    /// The default implementation returns `None`.
+    fn value_size_hint(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns diagnostic information about the slice.
+    fn stats(&self) -> SliceStats {
+        SliceStats {
+            len: self.len(),
+            value_size_hint: self.value_size_hint(),
+            backend_name: self.backend_name(),
+        }
+    }
+}
+
+macro_rules! impl_describe_sized {
+    ($ty:ty $(, $const:ident)?) => {
+        impl<T: Clone $(, const $const: usize)?> DescribeSliceByValue for $ty {
+            #[inline]
+            fn value_size_hint(&self) -> Option<usize> {
+                Some(8 * size_of::<T>())
+            }
+        }
+    };
+}
+
+impl_describe_sized!([T]);
+impl_describe_sized!([T; N], N);
+#[cfg(feature = "alloc")]
+impl_describe_sized!(Vec<T>);
+#[cfg(feature = "std")]
+impl_describe_sized!(VecDeque<T>);
+
+macro_rules! impl_describe_delegation {
+    ($ty:ty) => {
+        impl<S: DescribeSliceByValue + ?Sized> DescribeSliceByValue for $ty {
+            #[inline]
+            fn backend_name(&self) -> &'static str {
+                (**self).backend_name()
+            }
+
+            #[inline]
+            fn value_size_hint(&self) -> Option<usize> {
+                (**self).value_size_hint()
+            }
+
+            #[inline]
+            fn stats(&self) -> SliceStats {
+                (**self).stats()
+            }
+        }
+    };
+}
+
+impl_describe_delegation!(&S);
+impl_describe_delegation!(&mut S);
+#[cfg(feature = "alloc")]
+impl_describe_delegation!(Box<S>);
+#[cfg(feature = "std")]
+impl_describe_delegation!(Arc<S>);
+#[cfg(feature = "std")]
+impl_describe_delegation!(Rc<S>);
+
+#[cfg(feature = "bitvec")]
+mod bitvec_impls {
+    use super::DescribeSliceByValue;
+    use bitvec::{order::BitOrder, slice::BitSlice, store::BitStore};
+
+    impl<T: BitStore, O: BitOrder> DescribeSliceByValue for BitSlice<T, O> {
+        #[inline]
+        fn value_size_hint(&self) -> Option<usize> {
+            Some(1)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T: BitStore, O: BitOrder> DescribeSliceByValue for bitvec::vec::BitVec<T, O> {
+        #[inline]
+        fn value_size_hint(&self) -> Option<usize> {
+            Some(1)
+        }
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::{collections::VecDeque, rc::Rc, sync::Arc};
+
+use crate::slices::SliceByValue;
+
+/// Selection of the largest values of a by-value slice.
+///
+/// The default implementations delegate to the [`top_k_values`] and
+/// [`top_k_values_by`] functions, which scan the slice keeping the largest
+/// values found so far in a bounded heap, so they take time
+/// *O*(*n* log *k*) and space *O*(*k*), where *n* is the length of the
+/// slice; implementations that keep block maxima or other summaries can
+/// override them with faster ones.
+///
+/// ```rust
+/// use value_traits::algorithms::TopKValues;
+///
+/// let scores = [12, 7, 99, 3, 42, 57];
+/// assert_eq!(scores.top_k_values(3), vec![99, 57, 42]);
+/// // The smallest values
+/// assert_eq!(scores.top_k_values_by(2, |a, b| b.cmp(a)), vec![3, 7]);
+/// ```
+///
+/// This trait is available only if the `alloc` feature is enabled.
+pub trait TopKValues: SliceByValue {
+    /// Returns the `k` largest values of the slice in decreasing order, or
+    /// all values of the slice if `k` is larger than its length.
+    ///
+    /// See [`top_k_values`].
+    fn top_k_values(&self, k: usize) -> Vec<Self::Value>
+    where
+        Self::Value: Ord,
+    {
+        self.top_k_values_by(k, Ord::cmp)
+    }
+
+    /// Returns the `k` largest values of the slice with respect to the given
+    /// comparison function in decreasing order, or all values of the slice if
+    /// `k` is larger than its length.
+    ///
+    /// See [`top_k_values_by`].
+    fn top_k_values_by<F>(&self, k: usize, compare: F) -> Vec<Self::Value>
+    where
+        F: FnMut(&Self::Value, &Self::Value) -> Ordering,
+    {
+        top_k_values_by(self, k, compare)
+    }
+}
+
+/// Moves down the value at `pos` of a heap whose root is its minimum.
+fn sift_down<T>(heap: &mut [T], mut pos: usize, compare: &mut impl FnMut(&T, &T) -> Ordering) {
+    loop {
+        let mut child = 2 * pos + 1;
+        if child >= heap.len() {
+            return;
+        }
+        if child + 1 < heap.len() && compare(&heap[child + 1], &heap[child]).is_lt() {
+            child += 1;
+        }
+        if compare(&heap[pos], &heap[child]).is_le() {
+            return;
+        }
+        heap.swap(pos, child);
+        pos = child;
+    }
+}
+
+/// Returns the `k` largest values of a by-value slice in decreasing order,
+/// or all values of the slice if `k` is larger than its length.
+///
+/// The order of equal values is unspecified. This function works on any
+/// by-value slice; [`TopKValues::top_k_values`] might be faster.
+///
+/// ```rust
+/// use value_traits::adapters::functional::FnSlice;
+/// use value_traits::algorithms::top_k_values;
+///
+/// let f = FnSlice::new(10, |i| (i * 3) % 10);
+/// assert_eq!(top_k_values(&f, 3), vec![9, 8, 7]);
+/// ```
+pub fn top_k_values<S>(s: &S, k: usize) -> Vec<S::Value>
+where
+    S: SliceByValue<Value: Ord> + ?Sized,
+{
+    top_k_values_by(s, k, Ord::cmp)
+}
+
+/// Returns the `k` largest values of a by-value slice with respect to the
+/// given comparison function in decreasing order, or all values of the
+/// slice if `k` is larger than its length.
+///
+/// The `k` smallest values can be obtained by reversing the comparison
+/// function. The order of equal values is unspecified. This function works
+/// on any by-value slice; [`TopKValues::top_k_values_by`] might be faster.
+pub fn top_k_values_by<S, F>(s: &S, k: usize, mut compare: F) -> Vec<S::Value>
+where
+    S: SliceByValue + ?Sized,
+    F: FnMut(&S::Value, &S::Value) -> Ordering,
+{
+    let k = k.min(s.len());
+    let mut heap = Vec::with_capacity(k);
+    if k == 0 {
+        return heap;
+    }
+    for i in 0..k {
+        // SAFETY: i is within bounds
+        heap.push(unsafe { s.get_value_unchecked(i) });
+    }
+    for pos in (0..k / 2).rev() {
+        sift_down(&mut heap, pos, &mut compare);
+    }
+    for i in k..s.len() {
+        // SAFETY: i is within bounds
+        let value = unsafe { s.get_value_unchecked(i) };
+        if compare(&value, &heap[0]).is_gt() {
+            heap[0] = value;
+            sift_down(&mut heap, 0, &mut compare);
+        }
+    }
+    heap.sort_unstable_by(|a, b| compare(b, a));
+    heap
+}
+
+impl<T: Clone> TopKValues for [T] {}
+impl<T: Clone, const N: usize> TopKValues for [T; N] {}
+impl<T: Clone> TopKValues for Vec<T> {}
+#[cfg(feature = "std")]
+impl<T: Clone> TopKValues for VecDeque<T> {}
+
+macro_rules! impl_top_k_values_delegation {
+    ($ty:ty) => {
+        impl<S: TopKValues + ?Sized> TopKValues for $ty {
+            fn top_k_values(&self, k: usize) -> Vec<Self::Value>
+            where
+                Self::Value: Ord,
+            {
+                (**self).top_k_values(k)
+            }
+
+            fn top_k_values_by<F>(&self, k: usize, compare: F) -> Vec<Self::Value>
+            where
+                F: FnMut(&Self::Value, &Self::Value) -> Ordering,
+            {
+                (**self).top_k_values_by(k, compare)
+            }
+        }
+    };
+}
+
+impl_top_k_values_delegation!(&S);
+impl_top_k_values_delegation!(&mut S);
+impl_top_k_values_delegation!(Box<S>);
+#[cfg(feature = "std")]
+impl_top_k_values_delegation!(Arc<S>);
+#[cfg(feature = "std")]
+impl_top_k_values_delegation!(Rc<S>);
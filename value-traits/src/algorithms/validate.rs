@@ -0,0 +1,269 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use core::fmt::Debug;
+
+use crate::{
+    iter::{ExactSizeIterateByValue, IterateByValueFromGat, IterateByValueGat},
+    panics::{check, check_eq},
+    slices::{SliceByValue, SliceByValueSubslice, SliceByValueSubsliceRange},
+};
+
+/// The number of indices checked by the validation functions on long
+/// slices.
+const SAMPLES: usize = 1024;
+
+/// Returns the indices to check on a slice of given length: all indices if
+/// the slice is short, and otherwise the first index, the last index, and
+/// pseudorandom indices.
+fn sample_indices(len: usize) -> impl Iterator<Item = usize> {
+    let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+    let sampled = len > SAMPLES;
+    (0..len.min(SAMPLES)).map(move |i| {
+        if !sampled || i == 0 {
+            i
+        } else if i == 1 {
+            len - 1
+        } else {
+            // xorshift64*
+            state ^= state >> 12;
+            state ^= state << 25;
+            state ^= state >> 27;
+            (state.wrapping_mul(0x2545_F491_4F6C_DD1D) % len as u64) as usize
+        }
+    })
+}
+
+/// Checks the internal consistency of the [`SliceByValue`] implementation
+/// of a slice.
+///
+/// The function checks that [`is_empty`](SliceByValue::is_empty) agrees with
+/// [`len`](SliceByValue::len), that
+/// [`get_value`](SliceByValue::get_value),
+/// [`index_value`](SliceByValue::index_value), and
+/// [`get_value_unchecked`](SliceByValue::get_value_unchecked) return the
+/// same values on a sample of indices (all indices if the slice is short),
+/// and that [`get_value`](SliceByValue::get_value) returns `None` out of
+/// bounds.
+///
+/// This function is meant to be used in tests and debug assertions of
+/// implementations:
+///
+/// ```rust
+/// use value_traits::algorithms::validate;
+///
+/// validate(&[1, 2, 3]);
+/// ```
+///
+/// See also [`validate_subslices`] and [`validate_iter`].
+///
+/// # Panics
+///
+/// This function will panic if an inconsistency is found.
+pub fn validate<S: SliceByValue + ?Sized>(slice: &S)
+where
+    S::Value: PartialEq + Debug,
+{
+    let len = slice.len();
+    check_eq!(
+        slice.is_empty(),
+        len == 0,
+        "is_empty disagrees with len",
+        "is_empty disagrees with len"
+    );
+    for index in sample_indices(len) {
+        let value = slice.get_value(index);
+        check!(
+            value.is_some(),
+            "get_value is None within bounds",
+            "get_value({index}) is None within bounds"
+        );
+        check_eq!(
+            value.as_ref(),
+            Some(&slice.index_value(index)),
+            "get_value disagrees with index_value",
+            "get_value({index}) disagrees with index_value({index})"
+        );
+        // SAFETY: index is within bounds
+        let unchecked = unsafe { slice.get_value_unchecked(index) };
+        check_eq!(
+            value,
+            Some(unchecked),
+            "get_value disagrees with get_value_unchecked",
+            "get_value({index}) disagrees with get_value_unchecked({index})"
+        );
+    }
+    check!(
+        slice.get_value(len).is_none(),
+        "get_value is not None out of bounds",
+        "get_value({len}) is not None out of bounds"
+    );
+    check!(
+        slice.get_value(usize::MAX).is_none(),
+        "get_value is not None out of bounds",
+        "get_value(usize::MAX) is not None out of bounds"
+    );
+}
+
+/// Checks the coherence of the subslices of a slice with the slice itself.
+///
+/// The function checks, on a sample of ranges, that subslices have the
+/// expected length and contain the expected values, that subslices of
+/// subslices compose correctly, and that
+/// [`get_subslice`](crate::slices::SliceByValueSubsliceRange::get_subslice)
+/// returns `None` on invalid ranges. The subslices are in turn checked
+/// with [`validate`].
+///
+/// ```rust
+/// use value_traits::algorithms::validate_subslices;
+///
+/// validate_subslices(&[1, 2, 3, 4, 5]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if an inconsistency is found.
+pub fn validate_subslices<S: SliceByValueSubslice + ?Sized>(slice: &S)
+where
+    S::Value: PartialEq + Debug,
+{
+    let len = slice.len();
+    let full = slice.index_subslice(..);
+    check_eq!(
+        full.len(),
+        len,
+        "the full subslice has the wrong length",
+        "the full subslice has the wrong length"
+    );
+    validate(&full);
+    check!(
+        slice.get_subslice(..len + 1).is_none(),
+        "get_subslice is not None out of bounds",
+        "get_subslice(..{}) is not None out of bounds",
+        len + 1
+    );
+    check!(
+        slice.get_subslice(len + 1..).is_none(),
+        "get_subslice is not None out of bounds",
+        "get_subslice({}..) is not None out of bounds",
+        len + 1
+    );
+
+    let starts = sample_indices(len).take(32);
+    let ends = sample_indices(len + 1).skip(1).take(32);
+    for (a, b) in starts.zip(ends) {
+        let (start, end) = (a.min(b), a.max(b));
+        let sub = slice.index_subslice(start..end);
+        check_eq!(
+            sub.len(),
+            end - start,
+            "subslice has the wrong length",
+            "subslice {start}..{end} has the wrong length"
+        );
+        validate(&sub);
+        for i in sample_indices(end - start).take(32) {
+            check_eq!(
+                sub.index_value(i),
+                slice.index_value(start + i),
+                "subslice disagrees with the slice",
+                "subslice {start}..{end} disagrees with the slice at index {i}"
+            );
+        }
+        let mid = start + (end - start) / 2;
+        let nested = sub.index_subslice(mid - start..);
+        let direct = slice.index_subslice(mid..end);
+        check_eq!(
+            nested.len(),
+            direct.len(),
+            "nested subslice has the wrong length",
+            "nested subslice {mid}..{end} has the wrong length"
+        );
+        for i in sample_indices(nested.len()).take(32) {
+            check_eq!(
+                nested.index_value(i),
+                direct.index_value(i),
+                "nested subslice disagrees with the slice",
+                "nested subslice {mid}..{end} disagrees with the slice at index {i}"
+            );
+        }
+    }
+}
+
+/// Checks that the by-value iterators of a slice return the values of the
+/// slice.
+///
+/// The function checks that [`iter_value`](crate::iter::IterateByValue::iter_value)
+/// returns exactly the values of the slice, and that
+/// [`iter_value_from`](crate::iter::IterateByValueFrom::iter_value_from)
+/// does the same starting from a sample of positions.
+///
+/// ```rust
+/// use value_traits::algorithms::validate_iter;
+///
+/// validate_iter(&[1, 2, 3]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if an inconsistency is found.
+pub fn validate_iter<S>(slice: &S)
+where
+    S: ExactSizeIterateByValue + ?Sized,
+    S::Value: PartialEq + Debug,
+    for<'a> S: IterateByValueGat<'a, Item = S::Value> + IterateByValueFromGat<'a, Item = S::Value>,
+{
+    let len = slice.len();
+    let mut count = 0;
+    for (index, value) in slice.iter_value().enumerate() {
+        check!(
+            index < len,
+            "iter_value returns too many values",
+            "iter_value returns more than {len} values"
+        );
+        check_eq!(
+            value,
+            slice.index_value(index),
+            "iter_value disagrees with the slice",
+            "iter_value disagrees with the slice at index {index}"
+        );
+        count += 1;
+    }
+    check_eq!(
+        count,
+        len,
+        "iter_value returns the wrong number of values",
+        "iter_value returns {count} values instead of {len}"
+    );
+
+    for from in sample_indices(len + 1).take(32) {
+        let mut count = 0;
+        for (i, value) in slice.iter_value_from(from).enumerate() {
+            check!(
+                from + i < len,
+                "iter_value_from returns too many values",
+                "iter_value_from({from}) returns more than {} values",
+                len - from
+            );
+            check_eq!(
+                value,
+                slice.index_value(from + i),
+                "iter_value_from disagrees with the slice",
+                "iter_value_from({from}) disagrees with the slice at index {}",
+                from + i
+            );
+            count += 1;
+        }
+        check_eq!(
+            count,
+            len - from,
+            "iter_value_from returns the wrong number of values",
+            "iter_value_from({from}) returns {count} values instead of {}",
+            len - from
+        );
+    }
+}
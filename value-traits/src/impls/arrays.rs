@@ -15,12 +15,13 @@ use core::{
 
 use crate::{
     iter::{
-        Iter, IterFrom, IterateByValue, IterateByValueFrom, IterateByValueFromGat,
-        IterateByValueGat,
+        ConsistentIteration, ExactSizeIterateByValue, Iter, IterFrom, IterateByValue,
+        IterateByValueFrom, IterateByValueFromGat, IterateByValueGat,
     },
     slices::{
-        SliceByValue, SliceByValueMut, SliceByValueSubsliceGat, SliceByValueSubsliceGatMut,
-        SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut, Subslice, SubsliceMut,
+        ComposeRange, FixedLenSliceByValue, SliceByValue, SliceByValueGetInto, SliceByValueMut,
+        SliceByValueSubsliceGat, SliceByValueSubsliceGatMut, SliceByValueSubsliceRange,
+        SliceByValueSubsliceRangeMut, Subslice, SubsliceMut,
     },
 };
 
@@ -49,6 +50,21 @@ impl<T: Clone, const N: usize> SliceByValue for [T; N] {
     }
 }
 
+impl<T: Clone, const N: usize> FixedLenSliceByValue<N> for [T; N] {}
+
+impl<T: Clone, const N: usize> SliceByValueGetInto for [T; N] {
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut T) {
+        // SAFETY: index is within bounds
+        unsafe { self.as_slice().get_value_into_unchecked(index, out) }
+    }
+
+    #[inline]
+    fn get_value_into(&self, index: usize, out: &mut T) {
+        out.clone_from(&self[index]);
+    }
+}
+
 impl<T: Clone, const N: usize> SliceByValueMut for [T; N] {
     #[inline]
     fn set_value(&mut self, index: usize, value: Self::Value) {
@@ -74,6 +90,11 @@ impl<T: Clone, const N: usize> SliceByValueMut for [T; N] {
         core::mem::replace(val_mut, value)
     }
 
+    #[inline]
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        SliceByValueMut::copy_within(self.as_mut_slice(), src, dst);
+    }
+
     type ChunksMut<'a>
         = core::slice::ChunksMut<'a, T>
     where
@@ -167,3 +188,6 @@ impl<T: Clone, const N: usize> IterateByValueFrom for [T; N] {
         self.iter().skip(from).cloned()
     }
 }
+
+impl<T: Clone, const N: usize> ExactSizeIterateByValue for [T; N] {}
+impl<T: Clone, const N: usize> ConsistentIteration for [T; N] {}
@@ -0,0 +1,181 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Implementations of by-value traits for Arrow primitive arrays, and
+//! conversions from by-value slices to Arrow primitive arrays.
+//!
+//! The read-only by-value traits are implemented for [`PrimitiveArray`] with
+//! value type the native type of the array. Subslices are zero-copy slices
+//! of the array. Values are read from the underlying buffer without looking
+//! at the null mask, so the value of a null slot is unspecified (usually
+//! zero).
+//!
+//! Conversely, [`to_primitive_array`] and [`to_primitive_arrays`] build
+//! primitive arrays from the values of any by-value slice, in the second
+//! case as a sequence of chunks, so that large compressed structures can be
+//! fed to columnar engines without materializing them fully:
+//!
+//! ```rust
+//! # #[cfg(feature = "arrow")] {
+//! use arrow_array::{PrimitiveArray, types::UInt64Type};
+//! use value_traits::adapters::functional::FnSlice;
+//! use value_traits::impls::arrow_arrays::*;
+//! use value_traits::slices::*;
+//!
+//! let squares = FnSlice::new(10, |i| (i * i) as u64);
+//! let array: PrimitiveArray<UInt64Type> = to_primitive_array(&squares);
+//! assert_eq!(array.index_value(3), 9);
+//! assert_eq!(array.index_subslice(2..5).index_value(0), 4);
+//!
+//! let chunks: Vec<PrimitiveArray<UInt64Type>> = to_primitive_arrays(&squares, 4).collect();
+//! assert_eq!(chunks.len(), 3);
+//! assert_eq!(chunks[2].len(), 2);
+//! # }
+//! ```
+//!
+//! These implementations are available only if the `arrow` feature is
+//! enabled.
+
+#![cfg(feature = "arrow")]
+
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use arrow_array::{Array, ArrowPrimitiveType, PrimitiveArray};
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::assert_range,
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueSubsliceGat, SliceByValueSubsliceRange, Subslice,
+    },
+};
+
+impl<T: ArrowPrimitiveType> SliceByValue for PrimitiveArray<T> {
+    type Value = T::Native;
+
+    #[inline]
+    fn len(&self) -> usize {
+        Array::len(self)
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.values().get(index).copied()
+    }
+
+    #[inline]
+    fn index_value(&self, index: usize) -> Self::Value {
+        self.values()[index]
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.value_unchecked(index) }
+    }
+}
+
+impl<T: ArrowPrimitiveType> SliceByValueSubsliceGat<'_> for PrimitiveArray<T> {
+    type Subslice = PrimitiveArray<T>;
+}
+
+macro_rules! impl_range_arrow_arrays {
+    ($range:ty) => {
+        impl<T: ArrowPrimitiveType> SliceByValueSubsliceRange<$range> for PrimitiveArray<T> {
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
+                let range = index.compose(0..Array::len(self));
+                self.slice(range.start, range.len())
+            }
+        }
+    };
+}
+
+impl_range_arrow_arrays!(RangeFull);
+impl_range_arrow_arrays!(RangeFrom<usize>);
+impl_range_arrow_arrays!(RangeTo<usize>);
+impl_range_arrow_arrays!(Range<usize>);
+impl_range_arrow_arrays!(RangeInclusive<usize>);
+impl_range_arrow_arrays!(RangeToInclusive<usize>);
+
+impl<'a, T: ArrowPrimitiveType> IterateByValueGat<'a> for PrimitiveArray<T> {
+    type Item = T::Native;
+    type Iter = core::iter::Copied<core::slice::Iter<'a, T::Native>>;
+}
+
+impl<T: ArrowPrimitiveType> IterateByValue for PrimitiveArray<T> {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.values().iter().copied()
+    }
+}
+
+impl<'a, T: ArrowPrimitiveType> IterateByValueFromGat<'a> for PrimitiveArray<T> {
+    type Item = T::Native;
+    type IterFrom = core::iter::Copied<core::slice::Iter<'a, T::Native>>;
+}
+
+impl<T: ArrowPrimitiveType> IterateByValueFrom for PrimitiveArray<T> {
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        let values: &[T::Native] = self.values();
+        values[from.min(values.len())..].iter().copied()
+    }
+}
+
+impl<T: ArrowPrimitiveType> ExactSizeIterateByValue for PrimitiveArray<T> {}
+
+/// Returns a primitive array without nulls containing the values of a
+/// by-value slice in the given range.
+///
+/// # Panics
+///
+/// This function will panic if the range is not within bounds.
+pub fn to_primitive_array_range<T, S>(slice: &S, range: impl ComposeRange) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    S: SliceByValue<Value = T::Native> + ?Sized,
+{
+    assert_range(&range, slice.len());
+    let range = range.compose(0..slice.len());
+    // SAFETY: the range is within bounds
+    PrimitiveArray::from_iter_values(range.map(|i| unsafe { slice.get_value_unchecked(i) }))
+}
+
+/// Returns a primitive array without nulls containing the values of a
+/// by-value slice.
+pub fn to_primitive_array<T, S>(slice: &S) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    S: SliceByValue<Value = T::Native> + ?Sized,
+{
+    to_primitive_array_range(slice, ..)
+}
+
+/// Returns an iterator over primitive arrays without nulls containing
+/// consecutive chunks of `chunk_size` values of a by-value slice (the last
+/// chunk might be shorter).
+///
+/// # Panics
+///
+/// This function will panic if `chunk_size` is zero.
+pub fn to_primitive_arrays<T, S>(
+    slice: &S,
+    chunk_size: usize,
+) -> impl Iterator<Item = PrimitiveArray<T>> + '_
+where
+    T: ArrowPrimitiveType,
+    S: SliceByValue<Value = T::Native> + ?Sized,
+{
+    assert!(chunk_size != 0, "chunk size must be positive");
+    let len = slice.len();
+    (0..len)
+        .step_by(chunk_size)
+        .map(move |start| to_primitive_array_range(slice, start..(start + chunk_size).min(len)))
+}
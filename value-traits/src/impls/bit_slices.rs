@@ -0,0 +1,368 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Implementations of by-value traits for the [`BitSlice`] and [`BitVec`]
+//! types of the [`bitvec`] crate.
+//!
+//! Bits are returned as [`bool`] values, so existing code based on
+//! [`bitvec`] can be consumed by generic by-value algorithms without
+//! conversion:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use bitvec::prelude::*;
+//! use value_traits::slices::*;
+//!
+//! fn count_ones(s: impl SliceByValue<Value = bool>) -> usize {
+//!     (0..s.len()).filter(|&i| s.index_value(i)).count()
+//! }
+//!
+//! let mut bits = bitvec![u8, Lsb0; 1, 0, 1, 1, 0];
+//! assert_eq!(count_ones(&bits), 3);
+//! bits.set_value(1, true);
+//! assert_eq!(count_ones(bits.index_subslice(..2)), 2);
+//! # }
+//! ```
+//!
+//! These implementations are available only if the `bitvec` feature is
+//! enabled; the implementations for [`BitVec`] require also the `alloc`
+//! feature.
+
+#![cfg(feature = "bitvec")]
+
+use core::{
+    iter::Skip,
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use bitvec::{order::BitOrder, slice::BitSlice, store::BitStore};
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::{assert_dest, assert_range},
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut, SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut,
+        Subslice, SubsliceMut,
+    },
+};
+
+impl<T: BitStore, O: BitOrder> SliceByValue for BitSlice<T, O> {
+    type Value = bool;
+
+    #[inline]
+    fn len(&self) -> usize {
+        BitSlice::len(self)
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.get(index).map(|bit| *bit)
+    }
+
+    #[inline]
+    fn index_value(&self, index: usize) -> Self::Value {
+        self[index]
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { *self.get_unchecked(index) }
+    }
+}
+
+impl<T: BitStore, O: BitOrder> SliceByValueMut for BitSlice<T, O> {
+    #[inline]
+    fn set_value(&mut self, index: usize, value: Self::Value) {
+        self.set(index, value);
+    }
+
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds
+        unsafe { self.set_unchecked(index, value) }
+    }
+
+    #[inline]
+    fn replace_value(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        self.replace(index, value)
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.replace_unchecked(index, value) }
+    }
+
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        let len = BitSlice::len(self);
+        assert_range(&src, len);
+        let src = src.compose(0..len);
+        assert_dest(dst, src.len(), len);
+        // SAFETY: both ranges are within bounds
+        unsafe { self.copy_within_unchecked(src, dst) }
+    }
+
+    type ChunksMut<'a>
+        = bitvec::slice::ChunksMut<'a, T, O>
+    where
+        Self: 'a;
+
+    type ChunksMutError = core::convert::Infallible;
+
+    #[inline]
+    fn try_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Ok(self.chunks_mut(chunk_size))
+    }
+}
+
+impl<'a, T: BitStore, O: BitOrder> SliceByValueSubsliceGat<'a> for BitSlice<T, O> {
+    type Subslice = &'a BitSlice<T, O>;
+}
+
+impl<'a, T: BitStore, O: BitOrder> SliceByValueSubsliceGatMut<'a> for BitSlice<T, O> {
+    type SubsliceMut = &'a mut BitSlice<T, O>;
+}
+
+macro_rules! impl_range_bit_slices {
+    ($range:ty) => {
+        impl<T: BitStore, O: BitOrder> SliceByValueSubsliceRange<$range> for BitSlice<T, O> {
+            #[inline]
+            fn get_subslice(&self, index: $range) -> Option<Subslice<'_, Self>> {
+                self.get(index)
+            }
+
+            #[inline]
+            fn index_subslice(&self, index: $range) -> Subslice<'_, Self> {
+                &self[index]
+            }
+
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
+                unsafe { self.get_unchecked(index) }
+            }
+        }
+
+        impl<T: BitStore, O: BitOrder> SliceByValueSubsliceRangeMut<$range> for BitSlice<T, O> {
+            #[inline]
+            fn get_subslice_mut(&mut self, index: $range) -> Option<SubsliceMut<'_, Self>> {
+                self.get_mut(index)
+            }
+
+            #[inline]
+            fn index_subslice_mut(&mut self, index: $range) -> SubsliceMut<'_, Self> {
+                &mut self[index]
+            }
+
+            #[inline]
+            unsafe fn get_subslice_unchecked_mut(
+                &mut self,
+                index: $range,
+            ) -> SubsliceMut<'_, Self> {
+                unsafe { self.get_unchecked_mut(index) }
+            }
+        }
+    };
+}
+
+impl_range_bit_slices!(RangeFull);
+impl_range_bit_slices!(RangeFrom<usize>);
+impl_range_bit_slices!(RangeTo<usize>);
+impl_range_bit_slices!(Range<usize>);
+impl_range_bit_slices!(RangeInclusive<usize>);
+impl_range_bit_slices!(RangeToInclusive<usize>);
+
+impl<'a, T: BitStore, O: BitOrder> IterateByValueGat<'a> for BitSlice<T, O> {
+    type Item = bool;
+    type Iter = bitvec::slice::BitValIter<'a, T, O>;
+}
+
+impl<T: BitStore, O: BitOrder> IterateByValue for BitSlice<T, O> {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.iter().by_vals()
+    }
+}
+
+impl<'a, T: BitStore, O: BitOrder> IterateByValueFromGat<'a> for BitSlice<T, O> {
+    type Item = bool;
+    type IterFrom = Skip<bitvec::slice::BitValIter<'a, T, O>>;
+}
+
+impl<T: BitStore, O: BitOrder> IterateByValueFrom for BitSlice<T, O> {
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.iter().by_vals().skip(from)
+    }
+}
+
+impl<T: BitStore, O: BitOrder> ExactSizeIterateByValue for BitSlice<T, O> {}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::*;
+    use bitvec::vec::BitVec;
+
+    impl<T: BitStore, O: BitOrder> SliceByValue for BitVec<T, O> {
+        type Value = bool;
+
+        #[inline]
+        fn len(&self) -> usize {
+            BitVec::len(self)
+        }
+
+        #[inline]
+        fn get_value(&self, index: usize) -> Option<Self::Value> {
+            self.as_bitslice().get_value(index)
+        }
+
+        #[inline]
+        fn index_value(&self, index: usize) -> Self::Value {
+            self[index]
+        }
+
+        #[inline]
+        unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+            // SAFETY: index is within bounds
+            unsafe { self.as_bitslice().get_value_unchecked(index) }
+        }
+    }
+
+    impl<T: BitStore, O: BitOrder> SliceByValueMut for BitVec<T, O> {
+        #[inline]
+        fn set_value(&mut self, index: usize, value: Self::Value) {
+            self.set(index, value);
+        }
+
+        #[inline]
+        unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+            // SAFETY: index is within bounds
+            unsafe { self.as_mut_bitslice().set_value_unchecked(index, value) }
+        }
+
+        #[inline]
+        fn replace_value(&mut self, index: usize, value: Self::Value) -> Self::Value {
+            self.replace(index, value)
+        }
+
+        #[inline]
+        unsafe fn replace_value_unchecked(
+            &mut self,
+            index: usize,
+            value: Self::Value,
+        ) -> Self::Value {
+            // SAFETY: index is within bounds
+            unsafe { self.as_mut_bitslice().replace_value_unchecked(index, value) }
+        }
+
+        #[inline]
+        fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+            SliceByValueMut::copy_within(self.as_mut_bitslice(), src, dst)
+        }
+
+        type ChunksMut<'a>
+            = bitvec::slice::ChunksMut<'a, T, O>
+        where
+            Self: 'a;
+
+        type ChunksMutError = core::convert::Infallible;
+
+        #[inline]
+        fn try_chunks_mut(
+            &mut self,
+            chunk_size: usize,
+        ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+            Ok(self.chunks_mut(chunk_size))
+        }
+    }
+
+    impl<'a, T: BitStore, O: BitOrder> SliceByValueSubsliceGat<'a> for BitVec<T, O> {
+        type Subslice = &'a BitSlice<T, O>;
+    }
+
+    impl<'a, T: BitStore, O: BitOrder> SliceByValueSubsliceGatMut<'a> for BitVec<T, O> {
+        type SubsliceMut = &'a mut BitSlice<T, O>;
+    }
+
+    macro_rules! impl_range_bit_vecs {
+        ($range:ty) => {
+            impl<T: BitStore, O: BitOrder> SliceByValueSubsliceRange<$range> for BitVec<T, O> {
+                #[inline]
+                fn get_subslice(&self, index: $range) -> Option<Subslice<'_, Self>> {
+                    self.as_bitslice().get_subslice(index)
+                }
+
+                #[inline]
+                fn index_subslice(&self, index: $range) -> Subslice<'_, Self> {
+                    self.as_bitslice().index_subslice(index)
+                }
+
+                #[inline]
+                unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
+                    unsafe { self.as_bitslice().get_subslice_unchecked(index) }
+                }
+            }
+
+            impl<T: BitStore, O: BitOrder> SliceByValueSubsliceRangeMut<$range> for BitVec<T, O> {
+                #[inline]
+                fn get_subslice_mut(&mut self, index: $range) -> Option<SubsliceMut<'_, Self>> {
+                    self.as_mut_bitslice().get_subslice_mut(index)
+                }
+
+                #[inline]
+                fn index_subslice_mut(&mut self, index: $range) -> SubsliceMut<'_, Self> {
+                    self.as_mut_bitslice().index_subslice_mut(index)
+                }
+
+                #[inline]
+                unsafe fn get_subslice_unchecked_mut(
+                    &mut self,
+                    index: $range,
+                ) -> SubsliceMut<'_, Self> {
+                    unsafe { self.as_mut_bitslice().get_subslice_unchecked_mut(index) }
+                }
+            }
+        };
+    }
+
+    impl_range_bit_vecs!(RangeFull);
+    impl_range_bit_vecs!(RangeFrom<usize>);
+    impl_range_bit_vecs!(RangeTo<usize>);
+    impl_range_bit_vecs!(Range<usize>);
+    impl_range_bit_vecs!(RangeInclusive<usize>);
+    impl_range_bit_vecs!(RangeToInclusive<usize>);
+
+    impl<'a, T: BitStore, O: BitOrder> IterateByValueGat<'a> for BitVec<T, O> {
+        type Item = bool;
+        type Iter = bitvec::slice::BitValIter<'a, T, O>;
+    }
+
+    impl<T: BitStore, O: BitOrder> IterateByValue for BitVec<T, O> {
+        fn iter_value(&self) -> Iter<'_, Self> {
+            self.as_bitslice().iter_value()
+        }
+    }
+
+    impl<'a, T: BitStore, O: BitOrder> IterateByValueFromGat<'a> for BitVec<T, O> {
+        type Item = bool;
+        type IterFrom = Skip<bitvec::slice::BitValIter<'a, T, O>>;
+    }
+
+    impl<T: BitStore, O: BitOrder> IterateByValueFrom for BitVec<T, O> {
+        fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+            self.as_bitslice().iter_value_from(from)
+        }
+    }
+
+    impl<T: BitStore, O: BitOrder> ExactSizeIterateByValue for BitVec<T, O> {}
+}
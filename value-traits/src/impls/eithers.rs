@@ -0,0 +1,324 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Implementations of by-value traits for the [`Either`] type of the
+//! [`either`] crate.
+//!
+//! If both sides of an [`Either`] implement a by-value trait with the same
+//! [`Value`](SliceByValue::Value), so does the [`Either`], dispatching each
+//! call to the side it contains. Subslices, mutable subslices, iterators and
+//! mutable chunks are again [`Either`]s (or, in the case of chunks, an
+//! [`IterEither`]). This is the simplest way to return one of two backends
+//! from a function without boxing:
+//!
+//! ```rust
+//! use either::Either;
+//! use value_traits::slices::*;
+//!
+//! fn backend(constant: bool) -> impl SliceByValue<Value = u32> + SliceByValueSubslice {
+//!     if constant {
+//!         Either::Left([1; 4])
+//!     } else {
+//!         Either::Right([1, 2, 3, 4])
+//!     }
+//! }
+//!
+//! assert_eq!(backend(true).index_value(3), 1);
+//! assert_eq!(backend(false).index_subslice(2..).index_value(0), 3);
+//! ```
+//!
+//! These implementations are available only if the `either` feature is
+//! enabled.
+
+#![cfg(feature = "either")]
+
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use either::{Either, IterEither, for_both};
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueGetInto, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut, SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut,
+        Subslice, SubsliceMut,
+    },
+};
+
+impl<L: SliceByValue, R: SliceByValue<Value = L::Value>> SliceByValue for Either<L, R> {
+    type Value = L::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        for_both!(self, s => s.len())
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        for_both!(self, s => s.get_value(index))
+    }
+
+    #[inline]
+    fn index_value(&self, index: usize) -> Self::Value {
+        for_both!(self, s => s.index_value(index))
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        for_both!(self, s => unsafe { s.get_value_unchecked(index) })
+    }
+}
+
+impl<L: SliceByValueGetInto, R: SliceByValueGetInto<Value = L::Value>> SliceByValueGetInto
+    for Either<L, R>
+{
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut L::Value) {
+        // SAFETY: index is within bounds
+        for_both!(self, s => unsafe { s.get_value_into_unchecked(index, out) })
+    }
+
+    #[inline]
+    fn get_value_into(&self, index: usize, out: &mut L::Value) {
+        for_both!(self, s => s.get_value_into(index, out))
+    }
+}
+
+impl<L: SliceByValueMut, R: SliceByValueMut<Value = L::Value>> SliceByValueMut for Either<L, R> {
+    #[inline]
+    fn set_value(&mut self, index: usize, value: Self::Value) {
+        for_both!(self, s => s.set_value(index, value))
+    }
+
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds
+        for_both!(self, s => unsafe { s.set_value_unchecked(index, value) })
+    }
+
+    #[inline]
+    fn replace_value(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        for_both!(self, s => s.replace_value(index, value))
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds
+        for_both!(self, s => unsafe { s.replace_value_unchecked(index, value) })
+    }
+
+    #[inline]
+    fn copy_within<Rg: ComposeRange>(&mut self, src: Rg, dst: usize) {
+        for_both!(self, s => s.copy_within(src, dst))
+    }
+
+    #[inline]
+    unsafe fn apply_in_place_unchecked<F>(&mut self, f: F)
+    where
+        F: FnMut(Self::Value) -> Self::Value,
+    {
+        // SAFETY: the function satisfies the requirements of both sides
+        for_both!(self, s => unsafe { s.apply_in_place_unchecked(f) })
+    }
+
+    #[inline]
+    fn apply_in_place<F>(&mut self, f: F)
+    where
+        F: FnMut(Self::Value) -> Self::Value,
+    {
+        for_both!(self, s => s.apply_in_place(f))
+    }
+
+    #[inline]
+    fn for_each_value_mut<Rg: ComposeRange, F>(&mut self, range: Rg, f: F)
+    where
+        F: FnMut(usize, Self::Value) -> Option<Self::Value>,
+    {
+        for_both!(self, s => s.for_each_value_mut(range, f))
+    }
+
+    type ChunksMut<'a>
+        = IterEither<L::ChunksMut<'a>, R::ChunksMut<'a>>
+    where
+        Self: 'a;
+
+    type ChunksMutError = Either<L::ChunksMutError, R::ChunksMutError>;
+
+    /// Returns the chunks of the side contained in the [`Either`], wrapped
+    /// in an [`IterEither`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the side contained in the [`Either`].
+    fn try_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        let chunks = match self {
+            Either::Left(s) => Either::Left(s.try_chunks_mut(chunk_size).map_err(Either::Left)?),
+            Either::Right(s) => Either::Right(s.try_chunks_mut(chunk_size).map_err(Either::Right)?),
+        };
+        Ok(chunks.factor_into_iter())
+    }
+}
+
+impl<'a, L, R> SliceByValueSubsliceGat<'a> for Either<L, R>
+where
+    L: SliceByValueSubsliceGat<'a>,
+    R: SliceByValueSubsliceGat<'a, Value = L::Value>,
+{
+    type Subslice = Either<Subslice<'a, L>, Subslice<'a, R>>;
+}
+
+impl<'a, L, R> SliceByValueSubsliceGatMut<'a> for Either<L, R>
+where
+    L: SliceByValueSubsliceGatMut<'a>,
+    R: SliceByValueSubsliceGatMut<'a, Value = L::Value>,
+{
+    type SubsliceMut = Either<SubsliceMut<'a, L>, SubsliceMut<'a, R>>;
+}
+
+macro_rules! impl_range_eithers {
+    ($range:ty) => {
+        impl<L, R> SliceByValueSubsliceRange<$range> for Either<L, R>
+        where
+            L: SliceByValueSubsliceRange<$range>,
+            R: SliceByValueSubsliceRange<$range, Value = L::Value>,
+        {
+            #[inline]
+            fn get_subslice(&self, range: $range) -> Option<Subslice<'_, Self>> {
+                match self {
+                    Either::Left(s) => s.get_subslice(range).map(Either::Left),
+                    Either::Right(s) => s.get_subslice(range).map(Either::Right),
+                }
+            }
+
+            #[inline]
+            fn index_subslice(&self, range: $range) -> Subslice<'_, Self> {
+                match self {
+                    Either::Left(s) => Either::Left(s.index_subslice(range)),
+                    Either::Right(s) => Either::Right(s.index_subslice(range)),
+                }
+            }
+
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, range: $range) -> Subslice<'_, Self> {
+                // SAFETY: range is within bounds
+                unsafe {
+                    match self {
+                        Either::Left(s) => Either::Left(s.get_subslice_unchecked(range)),
+                        Either::Right(s) => Either::Right(s.get_subslice_unchecked(range)),
+                    }
+                }
+            }
+        }
+
+        impl<L, R> SliceByValueSubsliceRangeMut<$range> for Either<L, R>
+        where
+            L: SliceByValueSubsliceRangeMut<$range>,
+            R: SliceByValueSubsliceRangeMut<$range, Value = L::Value>,
+        {
+            #[inline]
+            fn get_subslice_mut(&mut self, range: $range) -> Option<SubsliceMut<'_, Self>> {
+                match self {
+                    Either::Left(s) => s.get_subslice_mut(range).map(Either::Left),
+                    Either::Right(s) => s.get_subslice_mut(range).map(Either::Right),
+                }
+            }
+
+            #[inline]
+            fn index_subslice_mut(&mut self, range: $range) -> SubsliceMut<'_, Self> {
+                match self {
+                    Either::Left(s) => Either::Left(s.index_subslice_mut(range)),
+                    Either::Right(s) => Either::Right(s.index_subslice_mut(range)),
+                }
+            }
+
+            #[inline]
+            unsafe fn get_subslice_unchecked_mut(
+                &mut self,
+                range: $range,
+            ) -> SubsliceMut<'_, Self> {
+                // SAFETY: range is within bounds
+                unsafe {
+                    match self {
+                        Either::Left(s) => Either::Left(s.get_subslice_unchecked_mut(range)),
+                        Either::Right(s) => Either::Right(s.get_subslice_unchecked_mut(range)),
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_range_eithers!(RangeFull);
+impl_range_eithers!(RangeFrom<usize>);
+impl_range_eithers!(RangeTo<usize>);
+impl_range_eithers!(Range<usize>);
+impl_range_eithers!(RangeInclusive<usize>);
+impl_range_eithers!(RangeToInclusive<usize>);
+
+impl<'a, L, R> IterateByValueGat<'a> for Either<L, R>
+where
+    L: IterateByValueGat<'a>,
+    R: IterateByValueGat<'a, Item = L::Item>,
+{
+    type Item = L::Item;
+    type Iter = Either<Iter<'a, L>, Iter<'a, R>>;
+}
+
+impl<L, R> IterateByValue for Either<L, R>
+where
+    L: IterateByValue,
+    R: IterateByValue + for<'a> IterateByValueGat<'a, Item = <L as IterateByValueGat<'a>>::Item>,
+{
+    #[inline]
+    fn iter_value(&self) -> Iter<'_, Self> {
+        match self {
+            Either::Left(s) => Either::Left(s.iter_value()),
+            Either::Right(s) => Either::Right(s.iter_value()),
+        }
+    }
+}
+
+impl<'a, L, R> IterateByValueFromGat<'a> for Either<L, R>
+where
+    L: IterateByValueFromGat<'a>,
+    R: IterateByValueFromGat<'a, Item = L::Item>,
+{
+    type Item = L::Item;
+    type IterFrom = Either<IterFrom<'a, L>, IterFrom<'a, R>>;
+}
+
+impl<L, R> IterateByValueFrom for Either<L, R>
+where
+    L: IterateByValueFrom,
+    R: IterateByValueFrom
+        + for<'a> IterateByValueFromGat<'a, Item = <L as IterateByValueFromGat<'a>>::Item>,
+{
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        match self {
+            Either::Left(s) => Either::Left(s.iter_value_from(from)),
+            Either::Right(s) => Either::Right(s.iter_value_from(from)),
+        }
+    }
+}
+
+impl<L, R> ExactSizeIterateByValue for Either<L, R>
+where
+    L: ExactSizeIterateByValue,
+    R: ExactSizeIterateByValue<Value = L::Value>
+        + for<'a> IterateByValueGat<'a, Item = <L as IterateByValueGat<'a>>::Item>
+        + for<'a> IterateByValueFromGat<'a, Item = <L as IterateByValueFromGat<'a>>::Item>,
+{
+}
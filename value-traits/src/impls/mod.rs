@@ -6,8 +6,16 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-//! Implementations of by-value traits for arrays, slices, and vectors.
+//! Implementations of by-value traits for arrays, slices, tuples, options,
+//! and vectors, and for the types of optional third-party crates.
 
 pub mod arrays;
+pub mod arrow_arrays;
+pub mod bit_slices;
+pub mod eithers;
+pub mod nd_arrays;
+pub mod options;
+pub mod pod_slices;
 pub mod slices;
+pub mod tuples;
 pub mod vectors;
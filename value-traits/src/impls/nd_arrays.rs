@@ -0,0 +1,185 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Implementations of by-value traits for one-dimensional arrays of
+//! [cloneable](Clone) types of the [`ndarray`] crate.
+//!
+//! The implementations cover all one-dimensional [`ArrayBase`] types, such as
+//! [`Array1`](ndarray::Array1), [`ArrayView1`] and [`ArrayViewMut1`],
+//! including strided views, which cannot be handled by the implementations
+//! for slices. Subslices are views.
+//!
+//! ```rust
+//! use ndarray::{Array1, s};
+//! use value_traits::slices::*;
+//!
+//! fn sum(s: impl SliceByValue<Value = f64>) -> f64 {
+//!     (0..s.len()).map(|i| s.index_value(i)).sum()
+//! }
+//!
+//! let mut a = Array1::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+//! // A strided view
+//! let odd = a.slice(s![..;2]);
+//! assert_eq!(sum(&odd), 9.0);
+//! assert_eq!(odd.index_subslice(1..).index_value(0), 3.0);
+//! a.set_value(0, 0.0);
+//! assert_eq!(sum(&a), 14.0);
+//! ```
+//!
+//! These implementations are available only if the `ndarray` feature is
+//! enabled.
+
+#![cfg(feature = "ndarray")]
+
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use ndarray::{
+    ArrayBase, ArrayView1, ArrayViewMut1, Axis, Data, DataMut, Ix1, iter::AxisChunksIterMut, s,
+};
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut, SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut,
+        Subslice, SubsliceMut,
+    },
+};
+
+impl<A: Clone, S: Data<Elem = A>> SliceByValue for ArrayBase<S, Ix1> {
+    type Value = A;
+
+    #[inline]
+    fn len(&self) -> usize {
+        ArrayBase::len(self)
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.get(index).cloned()
+    }
+
+    #[inline]
+    fn index_value(&self, index: usize) -> Self::Value {
+        self[index].clone()
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        // SAFETY: index is within bounds
+        unsafe { self.uget(index) }.clone()
+    }
+}
+
+impl<A: Clone, S: DataMut<Elem = A>> SliceByValueMut for ArrayBase<S, Ix1> {
+    #[inline]
+    fn set_value(&mut self, index: usize, value: Self::Value) {
+        self[index] = value;
+    }
+
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        // SAFETY: index is within bounds
+        *unsafe { self.uget_mut(index) } = value;
+    }
+
+    #[inline]
+    fn replace_value(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        core::mem::replace(&mut self[index], value)
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        // SAFETY: index is within bounds
+        core::mem::replace(unsafe { self.uget_mut(index) }, value)
+    }
+
+    type ChunksMut<'a>
+        = AxisChunksIterMut<'a, A, Ix1>
+    where
+        Self: 'a;
+
+    type ChunksMutError = core::convert::Infallible;
+
+    #[inline]
+    fn try_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Ok(self.axis_chunks_iter_mut(Axis(0), chunk_size))
+    }
+}
+
+impl<'a, A: Clone, S: Data<Elem = A>> SliceByValueSubsliceGat<'a> for ArrayBase<S, Ix1> {
+    type Subslice = ArrayView1<'a, A>;
+}
+
+impl<'a, A: Clone, S: DataMut<Elem = A>> SliceByValueSubsliceGatMut<'a> for ArrayBase<S, Ix1> {
+    type SubsliceMut = ArrayViewMut1<'a, A>;
+}
+
+macro_rules! impl_range_nd_arrays {
+    ($range:ty) => {
+        impl<A: Clone, S: Data<Elem = A>> SliceByValueSubsliceRange<$range> for ArrayBase<S, Ix1> {
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
+                let range = index.compose(0..ArrayBase::len(self));
+                self.slice(s![range])
+            }
+        }
+
+        impl<A: Clone, S: DataMut<Elem = A>> SliceByValueSubsliceRangeMut<$range>
+            for ArrayBase<S, Ix1>
+        {
+            #[inline]
+            unsafe fn get_subslice_unchecked_mut(
+                &mut self,
+                index: $range,
+            ) -> SubsliceMut<'_, Self> {
+                let range = index.compose(0..ArrayBase::len(self));
+                self.slice_mut(s![range])
+            }
+        }
+    };
+}
+
+impl_range_nd_arrays!(RangeFull);
+impl_range_nd_arrays!(RangeFrom<usize>);
+impl_range_nd_arrays!(RangeTo<usize>);
+impl_range_nd_arrays!(Range<usize>);
+impl_range_nd_arrays!(RangeInclusive<usize>);
+impl_range_nd_arrays!(RangeToInclusive<usize>);
+
+impl<'a, A: Clone, S: Data<Elem = A>> IterateByValueGat<'a> for ArrayBase<S, Ix1> {
+    type Item = A;
+    type Iter = core::iter::Cloned<ndarray::iter::Iter<'a, A, Ix1>>;
+}
+
+impl<A: Clone, S: Data<Elem = A>> IterateByValue for ArrayBase<S, Ix1> {
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.iter().cloned()
+    }
+}
+
+impl<'a, A: Clone, S: Data<Elem = A>> IterateByValueFromGat<'a> for ArrayBase<S, Ix1> {
+    type Item = A;
+    type IterFrom = core::iter::Cloned<ndarray::iter::Iter<'a, A, Ix1>>;
+}
+
+impl<A: Clone, S: Data<Elem = A>> IterateByValueFrom for ArrayBase<S, Ix1> {
+    /// Iteration starts from a view, so skipping takes constant time.
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        let from = from.min(ArrayBase::len(self));
+        self.slice(s![from..]).into_iter().cloned()
+    }
+}
+
+impl<A: Clone, S: Data<Elem = A>> ExactSizeIterateByValue for ArrayBase<S, Ix1> {}
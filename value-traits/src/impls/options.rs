@@ -0,0 +1,214 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Implementations of by-value traits for [`Option`].
+//!
+//! An `Option<S>` is a by-value slice if `S` is: [`None`] behaves as an
+//! empty slice, and [`Some`] delegates to the contained slice. In this way,
+//! optional backends (e.g., a column that is present only in some
+//! configurations) can be passed to generic code without special-casing
+//! every call site:
+//!
+//! ```rust
+//! use value_traits::slices::*;
+//!
+//! fn sum(s: impl SliceByValue<Value = u32>) -> u32 {
+//!     (0..s.len()).map(|i| s.index_value(i)).sum()
+//! }
+//!
+//! assert_eq!(sum(Some([1, 2, 3])), 6);
+//! assert_eq!(sum(None::<[u32; 3]>), 0);
+//! assert_eq!(Some([1, 2, 3]).index_subslice(1..).len(), 2);
+//! assert!(None::<[u32; 3]>.index_subslice(..).is_empty());
+//! ```
+//!
+//! Subslices of an `Option<S>` are options of subslices of `S`, and its
+//! iterators are flattened options of iterators of `S`.
+
+use core::{
+    iter::Flatten,
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use crate::{
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    panics::{assert_dest, assert_range},
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueGetInto, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut, SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut,
+        Subslice, SubsliceMut,
+    },
+};
+
+impl<S: SliceByValue> SliceByValue for Option<S> {
+    type Value = S::Value;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.as_ref().map_or(0, S::len)
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        self.as_ref().and_then(|s| s.get_value(index))
+    }
+
+    #[inline]
+    unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+        match self {
+            // SAFETY: index is within bounds
+            Some(s) => unsafe { s.get_value_unchecked(index) },
+            // SAFETY: no index is within the bounds of an empty slice
+            None => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+}
+
+impl<S: SliceByValueGetInto> SliceByValueGetInto for Option<S> {
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut S::Value) {
+        match self {
+            // SAFETY: index is within bounds
+            Some(s) => unsafe { s.get_value_into_unchecked(index, out) },
+            // SAFETY: no index is within the bounds of an empty slice
+            None => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+}
+
+impl<S: SliceByValueMut> SliceByValueMut for Option<S> {
+    #[inline]
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: Self::Value) {
+        match self {
+            // SAFETY: index is within bounds
+            Some(s) => unsafe { s.set_value_unchecked(index, value) },
+            // SAFETY: no index is within the bounds of an empty slice
+            None => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    #[inline]
+    unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
+        match self {
+            // SAFETY: index is within bounds
+            Some(s) => unsafe { s.replace_value_unchecked(index, value) },
+            // SAFETY: no index is within the bounds of an empty slice
+            None => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    #[inline]
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        match self {
+            Some(s) => s.copy_within(src, dst),
+            None => {
+                assert_range(&src, 0);
+                assert_dest(dst, 0, 0);
+            }
+        }
+    }
+
+    type ChunksMut<'a>
+        = Flatten<core::option::IntoIter<S::ChunksMut<'a>>>
+    where
+        Self: 'a;
+
+    type ChunksMutError = S::ChunksMutError;
+
+    /// Returns the chunks of the contained slice, or no chunks if the
+    /// option is [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the contained slice.
+    fn try_chunks_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, Self::ChunksMutError> {
+        Ok(self
+            .as_mut()
+            .map(|s| s.try_chunks_mut(chunk_size))
+            .transpose()?
+            .into_iter()
+            .flatten())
+    }
+}
+
+impl<'a, S: SliceByValueSubsliceGat<'a>> SliceByValueSubsliceGat<'a> for Option<S> {
+    type Subslice = Option<Subslice<'a, S>>;
+}
+
+impl<'a, S: SliceByValueSubsliceGatMut<'a>> SliceByValueSubsliceGatMut<'a> for Option<S> {
+    type SubsliceMut = Option<SubsliceMut<'a, S>>;
+}
+
+macro_rules! impl_range_options {
+    ($range:ty) => {
+        impl<S: SliceByValueSubsliceRange<$range>> SliceByValueSubsliceRange<$range> for Option<S> {
+            #[inline]
+            unsafe fn get_subslice_unchecked(&self, range: $range) -> Subslice<'_, Self> {
+                // SAFETY: range is within bounds
+                self.as_ref()
+                    .map(|s| unsafe { s.get_subslice_unchecked(range) })
+            }
+        }
+
+        impl<S: SliceByValueSubsliceRangeMut<$range>> SliceByValueSubsliceRangeMut<$range>
+            for Option<S>
+        {
+            #[inline]
+            unsafe fn get_subslice_unchecked_mut(
+                &mut self,
+                range: $range,
+            ) -> SubsliceMut<'_, Self> {
+                // SAFETY: range is within bounds
+                self.as_mut()
+                    .map(|s| unsafe { s.get_subslice_unchecked_mut(range) })
+            }
+        }
+    };
+}
+
+impl_range_options!(RangeFull);
+impl_range_options!(RangeFrom<usize>);
+impl_range_options!(RangeTo<usize>);
+impl_range_options!(Range<usize>);
+impl_range_options!(RangeInclusive<usize>);
+impl_range_options!(RangeToInclusive<usize>);
+
+impl<'a, S: IterateByValueGat<'a>> IterateByValueGat<'a> for Option<S> {
+    type Item = S::Item;
+    type Iter = Flatten<core::option::IntoIter<Iter<'a, S>>>;
+}
+
+impl<S: IterateByValue> IterateByValue for Option<S> {
+    #[inline]
+    fn iter_value(&self) -> Iter<'_, Self> {
+        self.as_ref().map(S::iter_value).into_iter().flatten()
+    }
+}
+
+impl<'a, S: IterateByValueFromGat<'a>> IterateByValueFromGat<'a> for Option<S> {
+    type Item = S::Item;
+    type IterFrom = Flatten<core::option::IntoIter<IterFrom<'a, S>>>;
+}
+
+impl<S: IterateByValueFrom> IterateByValueFrom for Option<S> {
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+        self.as_ref()
+            .map(|s| s.iter_value_from(from))
+            .into_iter()
+            .flatten()
+    }
+}
+
+impl<S: ExactSizeIterateByValue> ExactSizeIterateByValue for Option<S> {}
@@ -0,0 +1,74 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Zero-copy reinterpretation of slices of plain-old-data values using the
+//! [`bytemuck`] crate.
+//!
+//! The [`CastValues`] trait reinterprets a contiguous slice of
+//! [`Pod`] values, such as a byte region read from a file or memory-mapped,
+//! as a slice of another [`Pod`] type, wider or narrower, without copying.
+//! The resulting slice implements all by-value traits, so it can be passed
+//! directly to generic code. Alignment and size are checked, and
+//! reinterpretations that would be unsound return a [`PodCastError`]:
+//!
+//! ```rust
+//! use value_traits::impls::pod_slices::CastValues;
+//! use value_traits::slices::*;
+//!
+//! let words = [1_u64, 2, 3];
+//! // Narrowing always succeeds
+//! let bytes: &[u8] = words.cast_values().unwrap();
+//! assert_eq!(bytes.len(), 24);
+//! // Widening succeeds if the alignment and the size are right
+//! let again: &[u64] = bytes.cast_values().unwrap();
+//! assert_eq!(again.index_value(2), 3);
+//! assert!(bytes[..20].cast_values::<u64>().is_err());
+//! ```
+//!
+//! Since the trait is implemented for slices, its methods can be called
+//! also on arrays and vectors.
+//!
+//! This trait is available only if the `bytemuck` feature is enabled.
+
+#![cfg(feature = "bytemuck")]
+
+pub use bytemuck::{Pod, PodCastError};
+
+/// Zero-copy reinterpretation of a slice of [`Pod`] values as a slice of
+/// another [`Pod`] type.
+///
+/// See the [module documentation](self).
+pub trait CastValues {
+    /// Reinterprets this slice as a slice of values of type `U`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PodCastError`] if the slice is not suitably aligned for
+    /// `U`, or if its size in bytes is not a multiple of the size of `U`.
+    fn cast_values<U: Pod>(&self) -> Result<&[U], PodCastError>;
+
+    /// Reinterprets this slice as a mutable slice of values of type `U`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PodCastError`] if the slice is not suitably aligned for
+    /// `U`, or if its size in bytes is not a multiple of the size of `U`.
+    fn cast_values_mut<U: Pod>(&mut self) -> Result<&mut [U], PodCastError>;
+}
+
+impl<T: Pod> CastValues for [T] {
+    #[inline]
+    fn cast_values<U: Pod>(&self) -> Result<&[U], PodCastError> {
+        bytemuck::try_cast_slice(self)
+    }
+
+    #[inline]
+    fn cast_values_mut<U: Pod>(&mut self) -> Result<&mut [U], PodCastError> {
+        bytemuck::try_cast_slice_mut(self)
+    }
+}
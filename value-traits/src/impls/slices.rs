@@ -19,12 +19,14 @@ use core::{
 
 use crate::{
     iter::{
-        Iter, IterFrom, IterateByValue, IterateByValueFrom, IterateByValueFromGat,
-        IterateByValueGat,
+        ConsistentIteration, ExactSizeIterateByValue, Iter, IterFrom, IterateByValue,
+        IterateByValueFrom, IterateByValueFromGat, IterateByValueGat,
     },
+    panics::{assert_dest, assert_range},
     slices::{
-        SliceByValue, SliceByValueMut, SliceByValueSubsliceGat, SliceByValueSubsliceGatMut,
-        SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut, Subslice, SubsliceMut,
+        ComposeRange, SliceByValue, SliceByValueGetInto, SliceByValueMut, SliceByValueSubsliceGat,
+        SliceByValueSubsliceGatMut, SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut,
+        Subslice, SubsliceMut,
     },
 };
 
@@ -54,6 +56,19 @@ impl<T: Clone> SliceByValue for [T] {
     }
 }
 
+impl<T: Clone> SliceByValueGetInto for [T] {
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut T) {
+        // SAFETY: index is within bounds
+        out.clone_from(unsafe { (*self).get_unchecked(index) });
+    }
+
+    #[inline]
+    fn get_value_into(&self, index: usize, out: &mut T) {
+        out.clone_from(&self[index]);
+    }
+}
+
 impl<T: Clone> SliceByValueMut for [T] {
     #[inline]
     fn set_value(&mut self, index: usize, value: Self::Value) {
@@ -79,6 +94,40 @@ impl<T: Clone> SliceByValueMut for [T] {
         core::mem::replace(val_mut, value)
     }
 
+    /// Since this implementation requires only [`Clone`], it cannot use
+    /// [`slice::copy_within`]: values are copied using
+    /// [`slice::clone_from_slice`], which is compiled to a `memcpy` for
+    /// [`Copy`] types, in blocks no longer than the distance between the
+    /// source and the destination, so that each block is disjoint from its
+    /// destination.
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        let len = self.len();
+        assert_range(&src, len);
+        let src = src.compose(0..len);
+        let count = src.len();
+        assert_dest(dst, count, len);
+        if dst < src.start {
+            // Copy forward, so the source is read before being overwritten
+            let step = src.start - dst;
+            for k in (0..count).step_by(step) {
+                let n = step.min(count - k);
+                let (head, tail) = self.split_at_mut(src.start + k);
+                head[dst + k..dst + k + n].clone_from_slice(&tail[..n]);
+            }
+        } else if dst > src.start {
+            // Copy backward, so the source is read before being overwritten
+            let step = dst - src.start;
+            let mut end = count;
+            while end > 0 {
+                let n = step.min(end);
+                let k = end - n;
+                let (head, tail) = self.split_at_mut(dst + k);
+                tail[..n].clone_from_slice(&head[src.start + k..src.start + end]);
+                end = k;
+            }
+        }
+    }
+
     type ChunksMut<'a>
         = core::slice::ChunksMut<'a, T>
     where
@@ -172,3 +221,23 @@ impl<T: Clone> IterateByValueFrom for [T] {
         self.iter().skip(from).cloned()
     }
 }
+
+impl<T: Clone> ExactSizeIterateByValue for [T] {}
+impl<T: Clone> ConsistentIteration for [T] {}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use crate::slices::{ComposeRange, IntoSubslice};
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    use alloc::boxed::Box;
+
+    impl<T: Clone> IntoSubslice for Box<[T]> {
+        type IntoSubslice = Box<[T]>;
+
+        /// Converts the boxed slice into a vector, which is then truncated and
+        /// drained in place.
+        fn into_subslice<R: ComposeRange>(self, range: R) -> Self::IntoSubslice {
+            self.into_vec().into_subslice(range).into_boxed_slice()
+        }
+    }
+}
@@ -0,0 +1,101 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Implementations of by-value traits for homogeneous tuples of
+//! [cloneable](Clone) types.
+//!
+//! Tuples with up to twelve elements of the same type implement
+//! [`SliceByValue`] and the iteration traits, so small fixed groups of values
+//! can be passed to by-value generic code without building an array:
+//!
+//! ```rust
+//! use value_traits::slices::SliceByValue;
+//!
+//! fn sum(s: &impl SliceByValue<Value = u32>) -> u32 {
+//!     (0..s.len()).map(|i| s.index_value(i)).sum()
+//! }
+//!
+//! assert_eq!(sum(&(1, 2, 3)), 6);
+//! ```
+//!
+//! Iterators clone the elements of the tuple in an array when they are
+//! created.
+
+use core::iter::Skip;
+
+use crate::{
+    algorithms::FilterIndices,
+    iter::{
+        ExactSizeIterateByValue, Iter, IterFrom, IterateByValue, IterateByValueFrom,
+        IterateByValueFromGat, IterateByValueGat,
+    },
+    slices::{FixedLenSliceByValue, SliceByValue},
+};
+
+macro_rules! impl_tuple {
+    ($n:literal; $($idx:tt $t:ident),+) => {
+        impl<T: Clone> SliceByValue for ($($t,)+) {
+            type Value = T;
+
+            #[inline(always)]
+            fn len(&self) -> usize {
+                $n
+            }
+
+            #[inline]
+            unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+                match index {
+                    $($idx => self.$idx.clone(),)+
+                    // SAFETY: index is within bounds
+                    _ => unsafe { core::hint::unreachable_unchecked() },
+                }
+            }
+        }
+
+        impl<T: Clone> FixedLenSliceByValue<$n> for ($($t,)+) {}
+
+        impl<T: Clone> FilterIndices for ($($t,)+) {}
+
+        impl<'a, T: Clone> IterateByValueGat<'a> for ($($t,)+) {
+            type Item = T;
+            type Iter = core::array::IntoIter<T, $n>;
+        }
+
+        impl<T: Clone> IterateByValue for ($($t,)+) {
+            fn iter_value(&self) -> Iter<'_, Self> {
+                [$(self.$idx.clone()),+].into_iter()
+            }
+        }
+
+        impl<'a, T: Clone> IterateByValueFromGat<'a> for ($($t,)+) {
+            type Item = T;
+            type IterFrom = Skip<core::array::IntoIter<T, $n>>;
+        }
+
+        impl<T: Clone> IterateByValueFrom for ($($t,)+) {
+            fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+                self.iter_value().skip(from)
+            }
+        }
+
+        impl<T: Clone> ExactSizeIterateByValue for ($($t,)+) {}
+    };
+}
+
+impl_tuple!(1; 0 T);
+impl_tuple!(2; 0 T, 1 T);
+impl_tuple!(3; 0 T, 1 T, 2 T);
+impl_tuple!(4; 0 T, 1 T, 2 T, 3 T);
+impl_tuple!(5; 0 T, 1 T, 2 T, 3 T, 4 T);
+impl_tuple!(6; 0 T, 1 T, 2 T, 3 T, 4 T, 5 T);
+impl_tuple!(7; 0 T, 1 T, 2 T, 3 T, 4 T, 5 T, 6 T);
+impl_tuple!(8; 0 T, 1 T, 2 T, 3 T, 4 T, 5 T, 6 T, 7 T);
+impl_tuple!(9; 0 T, 1 T, 2 T, 3 T, 4 T, 5 T, 6 T, 7 T, 8 T);
+impl_tuple!(10; 0 T, 1 T, 2 T, 3 T, 4 T, 5 T, 6 T, 7 T, 8 T, 9 T);
+impl_tuple!(11; 0 T, 1 T, 2 T, 3 T, 4 T, 5 T, 6 T, 7 T, 8 T, 9 T, 10 T);
+impl_tuple!(12; 0 T, 1 T, 2 T, 3 T, 4 T, 5 T, 6 T, 7 T, 8 T, 9 T, 10 T, 11 T);
@@ -25,13 +25,16 @@ use core::{
 
 use crate::{
     iter::{
-        Iter, IterFrom, IterateByValue, IterateByValueFrom, IterateByValueFromGat,
-        IterateByValueGat,
+        ConsistentIteration, ExactSizeIterateByValue, Iter, IterFrom, IterateByValue,
+        IterateByValueFrom, IterateByValueFromGat, IterateByValueGat,
     },
+    panics::{assert_from, assert_index, assert_range},
     slices::{
-        SliceByValue, SliceByValueMut, SliceByValueSubsliceGat, SliceByValueSubsliceGatMut,
-        SliceByValueSubsliceRange, SliceByValueSubsliceRangeMut, Subslice, SubsliceMut,
+        ComposeRange, IntoSubslice, SliceByValue, SliceByValueGetInto, SliceByValueMut,
+        SliceByValueSubsliceGat, SliceByValueSubsliceGatMut, SliceByValueSubsliceRange,
+        SliceByValueSubsliceRangeMut, Subslice, SubsliceMut,
     },
+    vecs::VecByValue,
 };
 
 impl<T: Clone> SliceByValue for Vec<T> {
@@ -59,6 +62,19 @@ impl<T: Clone> SliceByValue for Vec<T> {
     }
 }
 
+impl<T: Clone> SliceByValueGetInto for Vec<T> {
+    #[inline]
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut T) {
+        // SAFETY: index is within bounds
+        unsafe { self.as_slice().get_value_into_unchecked(index, out) }
+    }
+
+    #[inline]
+    fn get_value_into(&self, index: usize, out: &mut T) {
+        out.clone_from(&self[index]);
+    }
+}
+
 impl<T: Clone> SliceByValueMut for Vec<T> {
     #[inline]
     fn set_value(&mut self, index: usize, value: Self::Value) {
@@ -86,6 +102,11 @@ impl<T: Clone> SliceByValueMut for Vec<T> {
         core::mem::replace(val_mut, value)
     }
 
+    #[inline]
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        SliceByValueMut::copy_within(self.as_mut_slice(), src, dst);
+    }
+
     type ChunksMut<'a>
         = core::slice::ChunksMut<'a, T>
     where
@@ -178,6 +199,86 @@ impl<T: Clone> IterateByValueFrom for Vec<T> {
     }
 }
 
+impl<T: Clone> ExactSizeIterateByValue for Vec<T> {}
+impl<T: Clone> ConsistentIteration for Vec<T> {}
+
+impl<T: Clone> IntoSubslice for Vec<T> {
+    type IntoSubslice = Vec<T>;
+
+    /// Truncates and drains the vector in place.
+    fn into_subslice<R: ComposeRange>(mut self, range: R) -> Self::IntoSubslice {
+        assert_range(&range, self.len());
+        let range = range.compose(0..self.len());
+        self.truncate(range.end);
+        self.drain(..range.start);
+        self
+    }
+}
+
+impl<T: Clone> VecByValue for Vec<T> {
+    #[inline]
+    fn push_value(&mut self, value: Self::Value) {
+        self.push(value);
+    }
+
+    #[inline]
+    fn append_run(&mut self, value: Self::Value, count: usize) {
+        self.resize(self.len() + count, value);
+    }
+
+    #[inline]
+    fn pop_value(&mut self) -> Option<Self::Value> {
+        self.pop()
+    }
+
+    #[inline]
+    fn truncate_values(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    #[inline]
+    fn clear_values(&mut self) {
+        self.clear();
+    }
+
+    #[inline]
+    fn swap_remove_value(&mut self, index: usize) -> Self::Value {
+        assert_index(index, self.len());
+        self.swap_remove(index)
+    }
+
+    #[inline]
+    fn drain_values<R: ComposeRange>(
+        &mut self,
+        range: R,
+    ) -> impl Iterator<Item = Self::Value> + '_ {
+        assert_range(&range, self.len());
+        self.drain(range.compose(0..self.len()))
+    }
+
+    #[inline]
+    fn split_off_values(&mut self, at: usize) -> Self {
+        assert_from(at, self.len());
+        self.split_off(at)
+    }
+
+    #[inline]
+    fn retain_values<F>(&mut self, f: F)
+    where
+        F: FnMut(&Self::Value) -> bool,
+    {
+        self.retain(f);
+    }
+
+    #[inline]
+    fn dedup_values(&mut self)
+    where
+        Self::Value: PartialEq,
+    {
+        self.dedup();
+    }
+}
+
 #[cfg(feature = "std")]
 mod vec_deque {
     use super::*;
@@ -208,6 +309,19 @@ mod vec_deque {
         }
     }
 
+    impl<T: Clone> SliceByValueGetInto for VecDeque<T> {
+        #[inline]
+        unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut T) {
+            // SAFETY: index is within bounds
+            out.clone_from(unsafe { (*self).get(index).unwrap_unchecked() });
+        }
+
+        #[inline]
+        fn get_value_into(&self, index: usize, out: &mut T) {
+            out.clone_from(&self[index]);
+        }
+    }
+
     impl<T: Clone> SliceByValueMut for VecDeque<T> {
         #[inline]
         fn set_value(&mut self, index: usize, value: Self::Value) {
@@ -277,4 +391,64 @@ mod vec_deque {
             self.iter().skip(from).cloned()
         }
     }
+
+    impl<T: Clone> ExactSizeIterateByValue for VecDeque<T> {}
+    impl<T: Clone> ConsistentIteration for VecDeque<T> {}
+
+    impl<T: Clone> VecByValue for VecDeque<T> {
+        #[inline]
+        fn push_value(&mut self, value: Self::Value) {
+            self.push_back(value);
+        }
+
+        #[inline]
+        fn append_run(&mut self, value: Self::Value, count: usize) {
+            self.resize(self.len() + count, value);
+        }
+
+        #[inline]
+        fn pop_value(&mut self) -> Option<Self::Value> {
+            self.pop_back()
+        }
+
+        #[inline]
+        fn truncate_values(&mut self, len: usize) {
+            self.truncate(len);
+        }
+
+        #[inline]
+        fn clear_values(&mut self) {
+            self.clear();
+        }
+
+        #[inline]
+        fn swap_remove_value(&mut self, index: usize) -> Self::Value {
+            assert_index(index, self.len());
+            // SAFETY: index is within bounds
+            unsafe { self.swap_remove_back(index).unwrap_unchecked() }
+        }
+
+        #[inline]
+        fn drain_values<R: ComposeRange>(
+            &mut self,
+            range: R,
+        ) -> impl Iterator<Item = Self::Value> + '_ {
+            assert_range(&range, self.len());
+            self.drain(range.compose(0..self.len()))
+        }
+
+        #[inline]
+        fn split_off_values(&mut self, at: usize) -> Self {
+            assert_from(at, self.len());
+            self.split_off(at)
+        }
+
+        #[inline]
+        fn retain_values<F>(&mut self, f: F)
+        where
+            F: FnMut(&Self::Value) -> bool,
+        {
+            self.retain(f);
+        }
+    }
 }
@@ -22,10 +22,58 @@ pub use value_traits_derive::{Iterators, IteratorsMut, Subslices, SubslicesMut};
 // Impls are not re-exported
 pub mod impls;
 
+// Adapters are not re-exported
+pub mod adapters;
+
+// Algorithms are not re-exported
+pub mod algorithms;
+
+// Operations are not re-exported
+pub mod ops;
+
+// Test-data generators are not re-exported
+pub mod testing;
+
+// Typed-array export is not re-exported
+pub mod wasm;
+
+// Python bindings are not re-exported
+pub mod python;
+
 // Traits are re-exported
 mod traits;
 pub use traits::*;
 
+mod panics;
+
+// Support for the code generated by the derive macros
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::panics::*;
+
+    /// A marker trait implemented by the `Subslices` derive macro.
+    #[diagnostic::on_unimplemented(
+        message = "derive `Subslices` before `Iterators`",
+        label = "`Subslices` is not derived for `{Self}`"
+    )]
+    pub trait DerivedSubslices {}
+
+    /// A marker trait implemented by the `SubslicesMut` derive macro.
+    #[diagnostic::on_unimplemented(
+        message = "derive `SubslicesMut` before `IteratorsMut`",
+        label = "`SubslicesMut` is not derived for `{Self}`"
+    )]
+    pub trait DerivedSubslicesMut {}
+
+    /// Checks that subslices are implemented by the `Subslices` derive
+    /// macro.
+    pub fn assert_subslices<S: DerivedSubslices + ?Sized>(_slice: &S) {}
+
+    /// Checks that mutable subslices are implemented by the `SubslicesMut`
+    /// derive macro.
+    pub fn assert_subslices_mut<S: DerivedSubslicesMut + ?Sized>(_slice: &S) {}
+}
+
 #[doc(hidden)]
 #[allow(private_bounds)]
 pub trait ImplBound: ImplBoundPriv {}
@@ -0,0 +1,317 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Elementwise arithmetic and cumulative transforms on by-value slices.
+//!
+//! The functions in this module modify in place a [`SliceByValueMut`] using
+//! the values of another [`SliceByValue`] of the same length, or a scalar.
+//! Writes are performed using
+//! [`for_each_value_mut`](SliceByValueMut::for_each_value_mut) and
+//! [`apply_in_place`](SliceByValueMut::apply_in_place), so implementations
+//! overriding such methods with bulk operations will be faster.
+//!
+//! Moreover, the module provides cumulative transforms commonly used to
+//! pre- and post-process data for compressed storage, such as prefix sums
+//! and delta encoding.
+//!
+//! ```rust
+//! use value_traits::ops::*;
+//!
+//! let mut a = [1_u8, 2, 3];
+//! add_assign_values(&mut a, &[10, 20, 30]);
+//! assert_eq!(a, [11, 22, 33]);
+//! saturating_add_assign_values(&mut a, &[250, 0, 0]);
+//! assert_eq!(a, [255, 22, 33]);
+//! mul_scalar_in_place(&mut a[1..], 2);
+//! assert_eq!(a, [255, 44, 66]);
+//! ```
+
+use core::ops::{Add, Mul, Sub};
+
+use crate::slices::{SliceByValue, SliceByValueMut};
+
+/// Saturating arithmetic operations, implemented for all primitive integer
+/// types by delegation to the inherent methods of the same name.
+pub trait SaturatingArith: Sized {
+    /// See, for example, [`u64::saturating_add`].
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// See, for example, [`u64::saturating_sub`].
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// See, for example, [`u64::saturating_mul`].
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_saturating_arith {
+    ($($ty:ty),*) => {
+        $(
+            impl SaturatingArith for $ty {
+                #[inline(always)]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$ty>::saturating_add(self, rhs)
+                }
+                #[inline(always)]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$ty>::saturating_sub(self, rhs)
+                }
+                #[inline(always)]
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$ty>::saturating_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_arith!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Replaces each value of `dst` with `f(dst[i], src[i])`.
+fn zip_assign<D, S, F>(dst: &mut D, src: &S, mut f: F)
+where
+    D: SliceByValueMut + ?Sized,
+    S: SliceByValue<Value = D::Value> + ?Sized,
+    F: FnMut(D::Value, D::Value) -> D::Value,
+{
+    assert!(
+        dst.len() == src.len(),
+        "destination and source slices have different lengths"
+    );
+    dst.for_each_value_mut(.., |i, x| {
+        // SAFETY: src has the same length of dst
+        Some(f(x, unsafe { src.get_value_unchecked(i) }))
+    });
+}
+
+/// Adds elementwise the values of `src` to the values of `dst`.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths, or if
+/// the addition panics (e.g., on overflow in debug mode).
+pub fn add_assign_values<D, S>(dst: &mut D, src: &S)
+where
+    D: SliceByValueMut + ?Sized,
+    S: SliceByValue<Value = D::Value> + ?Sized,
+    D::Value: Add<Output = D::Value>,
+{
+    zip_assign(dst, src, |x, y| x + y);
+}
+
+/// Subtracts elementwise the values of `src` from the values of `dst`.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths, or if
+/// the subtraction panics (e.g., on overflow in debug mode).
+pub fn sub_assign_values<D, S>(dst: &mut D, src: &S)
+where
+    D: SliceByValueMut + ?Sized,
+    S: SliceByValue<Value = D::Value> + ?Sized,
+    D::Value: Sub<Output = D::Value>,
+{
+    zip_assign(dst, src, |x, y| x - y);
+}
+
+/// Multiplies elementwise the values of `dst` by the values of `src`.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths, or if
+/// the multiplication panics (e.g., on overflow in debug mode).
+pub fn mul_assign_values<D, S>(dst: &mut D, src: &S)
+where
+    D: SliceByValueMut + ?Sized,
+    S: SliceByValue<Value = D::Value> + ?Sized,
+    D::Value: Mul<Output = D::Value>,
+{
+    zip_assign(dst, src, |x, y| x * y);
+}
+
+/// Adds elementwise, with saturation, the values of `src` to the values of
+/// `dst`.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+pub fn saturating_add_assign_values<D, S>(dst: &mut D, src: &S)
+where
+    D: SliceByValueMut + ?Sized,
+    S: SliceByValue<Value = D::Value> + ?Sized,
+    D::Value: SaturatingArith,
+{
+    zip_assign(dst, src, SaturatingArith::saturating_add);
+}
+
+/// Subtracts elementwise, with saturation, the values of `src` from the
+/// values of `dst`.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+pub fn saturating_sub_assign_values<D, S>(dst: &mut D, src: &S)
+where
+    D: SliceByValueMut + ?Sized,
+    S: SliceByValue<Value = D::Value> + ?Sized,
+    D::Value: SaturatingArith,
+{
+    zip_assign(dst, src, SaturatingArith::saturating_sub);
+}
+
+/// Adds a scalar to all values of `dst`.
+///
+/// # Panics
+///
+/// This function will panic if the addition panics (e.g., on overflow in
+/// debug mode).
+pub fn add_scalar_in_place<D>(dst: &mut D, scalar: D::Value)
+where
+    D: SliceByValueMut + ?Sized,
+    D::Value: Add<Output = D::Value> + Clone,
+{
+    dst.apply_in_place(|x| x + scalar.clone());
+}
+
+/// Multiplies all values of `dst` by a scalar.
+///
+/// # Panics
+///
+/// This function will panic if the multiplication panics (e.g., on overflow
+/// in debug mode).
+pub fn mul_scalar_in_place<D>(dst: &mut D, scalar: D::Value)
+where
+    D: SliceByValueMut + ?Sized,
+    D::Value: Mul<Output = D::Value> + Clone,
+{
+    dst.apply_in_place(|x| x * scalar.clone());
+}
+
+/// Multiplies, with saturation, all values of `dst` by a scalar.
+pub fn saturating_mul_scalar_in_place<D>(dst: &mut D, scalar: D::Value)
+where
+    D: SliceByValueMut + ?Sized,
+    D::Value: SaturatingArith + Clone,
+{
+    dst.apply_in_place(|x| x.saturating_mul(scalar.clone()));
+}
+
+/// Replaces each value of `dst` with the sum of the values up to its
+/// position (included).
+///
+/// This function is the inverse of [`delta_encode_in_place`].
+///
+/// ```rust
+/// use value_traits::ops::prefix_sum_in_place;
+///
+/// let mut a = [1, 2, 3, 4];
+/// prefix_sum_in_place(&mut a);
+/// assert_eq!(a, [1, 3, 6, 10]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if the addition panics (e.g., on overflow in
+/// debug mode).
+pub fn prefix_sum_in_place<D>(dst: &mut D)
+where
+    D: SliceByValueMut + ?Sized,
+    D::Value: Add<Output = D::Value> + Clone,
+{
+    let mut acc: Option<D::Value> = None;
+    dst.apply_in_place(|x| {
+        let sum = match acc.take() {
+            Some(acc) => acc + x,
+            None => x,
+        };
+        acc = Some(sum.clone());
+        sum
+    });
+}
+
+/// Replaces each value of `dst` with the sum of the values from its
+/// position (included) to the end.
+///
+/// ```rust
+/// use value_traits::ops::suffix_sum_in_place;
+///
+/// let mut a = [1, 2, 3, 4];
+/// suffix_sum_in_place(&mut a);
+/// assert_eq!(a, [10, 9, 7, 4]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if the addition panics (e.g., on overflow in
+/// debug mode).
+pub fn suffix_sum_in_place<D>(dst: &mut D)
+where
+    D: SliceByValueMut + ?Sized,
+    D::Value: Add<Output = D::Value> + Clone,
+{
+    let mut acc: Option<D::Value> = None;
+    for i in (0..dst.len()).rev() {
+        // SAFETY: i is within bounds
+        let x = unsafe { dst.get_value_unchecked(i) };
+        let sum = match acc.take() {
+            Some(acc) => acc + x,
+            None => x,
+        };
+        acc = Some(sum.clone());
+        unsafe { dst.set_value_unchecked(i, sum) };
+    }
+}
+
+/// Replaces each value of `dst`, except for the first one, with its
+/// difference with the previous value.
+///
+/// Note that with unsigned types the values must be nondecreasing, unless
+/// subtraction wraps around (e.g., using [`Wrapping`](core::num::Wrapping)).
+/// This function is the inverse of [`delta_decode_in_place`].
+///
+/// ```rust
+/// use value_traits::ops::*;
+///
+/// let mut a = [1, 3, 6, 10];
+/// delta_encode_in_place(&mut a);
+/// assert_eq!(a, [1, 2, 3, 4]);
+/// delta_decode_in_place(&mut a);
+/// assert_eq!(a, [1, 3, 6, 10]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if the subtraction panics (e.g., on overflow in
+/// debug mode).
+pub fn delta_encode_in_place<D>(dst: &mut D)
+where
+    D: SliceByValueMut + ?Sized,
+    D::Value: Sub<Output = D::Value> + Clone,
+{
+    let mut prev: Option<D::Value> = None;
+    dst.apply_in_place(|x| match prev.replace(x.clone()) {
+        Some(prev) => x - prev,
+        None => x,
+    });
+}
+
+/// Inverts [`delta_encode_in_place`], replacing each value of `dst` with the
+/// sum of the values up to its position (included).
+///
+/// This function is an alias for [`prefix_sum_in_place`].
+///
+/// # Panics
+///
+/// This function will panic if the addition panics (e.g., on overflow in
+/// debug mode).
+pub fn delta_decode_in_place<D>(dst: &mut D)
+where
+    D: SliceByValueMut + ?Sized,
+    D::Value: Add<Output = D::Value> + Clone,
+{
+    prefix_sum_in_place(dst);
+}
@@ -0,0 +1,123 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Canonical bounds checks and panics.
+//!
+//! All bounds checks of the crate, and of the code generated by the derive
+//! macros, go through these functions, so that messages are consistent. By
+//! default, messages are formatted as the ones of slices; if the `panic-lite`
+//! feature is enabled, they are replaced by static messages, so that the
+//! formatting machinery is not pulled in.
+//!
+//! Other panics of the crate with formatted messages go through the
+//! crate-private macros [`fail!`], [`check!`], and [`check_eq!`], which
+//! behave in the same way.
+
+use crate::slices::ComposeRange;
+
+/// Checks that an index is within bounds.
+///
+/// # Panics
+///
+/// Panics if `index` is not smaller than `len`.
+#[inline(always)]
+pub fn assert_index(index: usize, len: usize) {
+    #[cfg(not(feature = "panic-lite"))]
+    assert!(
+        index < len,
+        "index out of bounds: the len is {len} but the index is {index}",
+    );
+    #[cfg(feature = "panic-lite")]
+    assert!(index < len, "index out of bounds");
+}
+
+/// Checks that a range is within bounds.
+///
+/// # Panics
+///
+/// Panics if `range` is not valid for a slice of length `len`.
+#[inline(always)]
+pub fn assert_range(range: &impl ComposeRange, len: usize) {
+    #[cfg(not(feature = "panic-lite"))]
+    assert!(
+        range.is_valid(len),
+        "range {range:?} out of range for slice of length {len}",
+    );
+    #[cfg(feature = "panic-lite")]
+    assert!(range.is_valid(len), "range out of range");
+}
+
+/// Checks that a starting index is within bounds.
+///
+/// # Panics
+///
+/// Panics if `from` is greater than `len`.
+#[inline(always)]
+pub fn assert_from(from: usize, len: usize) {
+    #[cfg(not(feature = "panic-lite"))]
+    assert!(
+        from <= len,
+        "index out of bounds: the len is {len} but the starting index is {from}",
+    );
+    #[cfg(feature = "panic-lite")]
+    assert!(from <= len, "index out of bounds");
+}
+
+/// Checks that a destination range is within bounds.
+///
+/// `count` must not be greater than `len`.
+///
+/// # Panics
+///
+/// Panics if a destination starting at `dst` cannot contain `count` elements
+/// in a slice of length `len`.
+#[inline(always)]
+pub fn assert_dest(dst: usize, count: usize, len: usize) {
+    assert!(dst <= len - count, "dest is out of bounds");
+}
+
+/// Panics with a formatted message, or with the given static message if the
+/// `panic-lite` feature is enabled.
+///
+/// Arguments are evaluated in both cases.
+macro_rules! fail {
+    ($lite:literal, $fmt:literal $(, $arg:expr)* $(,)?) => {{
+        #[cfg(not(feature = "panic-lite"))]
+        panic!($fmt $(, $arg)*);
+        #[cfg(feature = "panic-lite")]
+        {
+            $(let _ = &$arg;)*
+            panic!($lite);
+        }
+    }};
+}
+
+/// Checks a condition like [`assert!`], panicking with a formatted message,
+/// or with the given static message if the `panic-lite` feature is enabled.
+macro_rules! check {
+    ($cond:expr, $lite:literal, $($arg:tt)+) => {{
+        #[cfg(not(feature = "panic-lite"))]
+        assert!($cond, $($arg)+);
+        #[cfg(feature = "panic-lite")]
+        assert!($cond, $lite);
+    }};
+}
+
+/// Checks the equality of two values like [`assert_eq!`], panicking with a
+/// formatted message, or with the given static message (and without
+/// printing the values) if the `panic-lite` feature is enabled.
+macro_rules! check_eq {
+    ($left:expr, $right:expr, $lite:literal, $($arg:tt)+) => {{
+        #[cfg(not(feature = "panic-lite"))]
+        assert_eq!($left, $right, $($arg)+);
+        #[cfg(feature = "panic-lite")]
+        assert!($left == $right, $lite);
+    }};
+}
+
+pub(crate) use {check, check_eq, fail};
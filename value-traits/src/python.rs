@@ -0,0 +1,212 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Python classes wrapping by-value slices using the [`pyo3`] crate.
+//!
+//! Since Python classes defined with [`pyo3`] cannot be generic, the
+//! [`py_value_slice!`](crate::py_value_slice) macro generates a concrete
+//! class wrapping a given by-value slice type. The class implements the
+//! Python sequence protocol:
+//!
+//! - `__len__` returns the length of the slice;
+//!
+//! - `__getitem__` accepts an integer, possibly negative, and returns the
+//!   corresponding value, or raises an `IndexError`; it accepts also a
+//!   Python slice, and returns a list containing the values of the
+//!   corresponding subslice (if the step is not one, values are accessed
+//!   directly);
+//!
+//! - `__iter__` returns a [`PyValueIter`].
+//!
+//! ```rust
+//! # #[cfg(feature = "pyo3")] {
+//! use value_traits::py_value_slice;
+//! use value_traits::python::pyo3::prelude::*;
+//!
+//! py_value_slice! {
+//!     /// A Python sequence of unsigned integers.
+//!     pub struct PyU32Slice(Vec<u32>);
+//! }
+//!
+//! #[pymodule(crate = "value_traits::python::pyo3")]
+//! fn succinct(m: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     m.add_class::<PyU32Slice>()
+//! }
+//! # }
+//! ```
+//!
+//! The wrapped type must be `Send`, `Sync`, and `'static`, must implement
+//! [`SliceByValueSubsliceRange<Range<usize>>`](SliceByValueSubsliceRange),
+//! and its values must implement [`IntoPyObject`]. Classes are
+//! [frozen](https://pyo3.rs/latest/class.html#frozen-classes-opting-out-of-interior-mutability),
+//! so the wrapped slice cannot be modified from Python.
+//!
+//! The code generated by the macro refers to [`pyo3`] through the re-export
+//! [`value_traits::python::pyo3`](pyo3), so the calling crate does not need
+//! to depend on it directly (the re-export is found by name, so this crate
+//! must not be renamed).
+//!
+//! This module is available only if the `pyo3` feature is enabled.
+
+#![cfg(feature = "pyo3")]
+
+use core::ops::Range;
+
+pub use pyo3;
+use pyo3::{
+    IntoPyObjectExt,
+    exceptions::PyIndexError,
+    prelude::*,
+    types::{PyList, PySlice, PySliceMethods},
+};
+
+use crate::slices::{SliceByValue, SliceByValueSubsliceRange};
+
+/// Returns the item of a by-value slice with the given Python index, which
+/// can be an integer or a slice, with the semantics of the Python method
+/// `__getitem__`.
+///
+/// Slices with step one are mapped to
+/// [subslices](SliceByValueSubsliceRange::index_subslice). The items
+/// corresponding to slices are returned as Python lists.
+///
+/// This is the implementation of the `__getitem__` method of the classes
+/// generated by [`py_value_slice!`](crate::py_value_slice).
+///
+/// # Errors
+///
+/// Raises an `IndexError` if the index is an integer out of bounds, and a
+/// `TypeError` if the index is neither an integer nor a slice.
+pub fn get_item<'py, S>(slice: &S, index: &Bound<'py, PyAny>) -> PyResult<Py<PyAny>>
+where
+    S: SliceByValueSubsliceRange<Range<usize>> + ?Sized,
+    S::Value: IntoPyObject<'py>,
+{
+    let py = index.py();
+    let len = slice.len();
+    if let Ok(py_slice) = index.cast::<PySlice>() {
+        // Lengths of slices in memory are at most isize::MAX
+        let indices = py_slice.indices(len as isize)?;
+        let list = if indices.step == 1 {
+            let start = indices.start as usize;
+            let subslice = slice.index_subslice(start..start + indices.slicelength);
+            PyList::new(
+                py,
+                // SAFETY: i is within bounds
+                (0..subslice.len()).map(|i| unsafe { subslice.get_value_unchecked(i) }),
+            )?
+        } else {
+            PyList::new(
+                py,
+                (0..indices.slicelength as isize).map(|k| {
+                    // SAFETY: PySlice::indices returns positions within bounds
+                    unsafe {
+                        slice.get_value_unchecked((indices.start + k * indices.step) as usize)
+                    }
+                }),
+            )?
+        };
+        return Ok(list.into_any().unbind());
+    }
+
+    let index: isize = index.extract()?;
+    let pos = if index < 0 {
+        index + len as isize
+    } else {
+        index
+    };
+    if (0..len as isize).contains(&pos) {
+        // SAFETY: pos is within bounds
+        unsafe { slice.get_value_unchecked(pos as usize) }.into_py_any(py)
+    } else {
+        Err(PyIndexError::new_err("index out of range"))
+    }
+}
+
+/// A Python iterator over the values of a Python sequence.
+///
+/// This is the iterator returned by the `__iter__` method of the classes
+/// generated by [`py_value_slice!`](crate::py_value_slice). It retrieves
+/// values using the `__getitem__` method of the sequence.
+#[pyclass(module = "value_traits")]
+pub struct PyValueIter {
+    seq: Py<PyAny>,
+    index: usize,
+    len: usize,
+}
+
+impl PyValueIter {
+    /// Creates a new iterator over the first `len` values of the given
+    /// sequence.
+    pub fn new(seq: Py<PyAny>, len: usize) -> Self {
+        Self { seq, index: 0, len }
+    }
+}
+
+#[pymethods]
+impl PyValueIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        let value = self.seq.bind(py).get_item(self.index)?;
+        self.index += 1;
+        Ok(Some(value.unbind()))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        self.len - self.index
+    }
+}
+
+/// Defines a Python class wrapping a by-value slice type.
+///
+/// The class is a tuple struct with a public field containing the slice,
+/// and it can be created from the slice using [`From`]. See the
+/// [module documentation](crate::python).
+///
+/// This macro is available only if the `pyo3` feature is enabled.
+#[macro_export]
+macro_rules! py_value_slice {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident($ty:ty);) => {
+        $(#[$attr])*
+        #[$crate::python::pyo3::pyclass(frozen, crate = "::value_traits::python::pyo3")]
+        $vis struct $name(pub $ty);
+
+        impl ::core::convert::From<$ty> for $name {
+            fn from(slice: $ty) -> Self {
+                Self(slice)
+            }
+        }
+
+        #[$crate::python::pyo3::pymethods(crate = "::value_traits::python::pyo3")]
+        impl $name {
+            fn __len__(&self) -> usize {
+                $crate::slices::SliceByValue::len(&self.0)
+            }
+
+            fn __getitem__(
+                &self,
+                index: &$crate::python::pyo3::Bound<'_, $crate::python::pyo3::PyAny>,
+            ) -> $crate::python::pyo3::PyResult<
+                $crate::python::pyo3::Py<$crate::python::pyo3::PyAny>,
+            > {
+                $crate::python::get_item(&self.0, index)
+            }
+
+            fn __iter__(slf: $crate::python::pyo3::Bound<'_, Self>) -> $crate::python::PyValueIter {
+                let len = $crate::slices::SliceByValue::len(&slf.get().0);
+                $crate::python::PyValueIter::new(slf.into_any().unbind(), len)
+            }
+        }
+    };
+}
@@ -0,0 +1,167 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Deterministic generators of test data.
+//!
+//! The functions in this module generate by-value slices of `u64` shaped
+//! like the data that is usually stored in compressed form (skewed
+//! distributions, sorted sequences, runs of equal values), so that tests and
+//! benchmarks, both in this crate and downstream, can share realistic
+//! inputs. All generators take a seed, and the same seed always yields the
+//! same data on every platform.
+//!
+//! [`uniform_slice`] returns a [functional slice](crate::adapters::functional)
+//! computing its values on the fly, whereas the other generators return
+//! vectors, and are available only if the `alloc` feature is enabled
+//! ([`zipfian_slice`] requires the `std` feature).
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::slices::*;
+//! use value_traits::testing::*;
+//!
+//! let s = random_sorted_slice(1000, 100, 0);
+//! assert!(s.windows(2).all(|w| w[0] <= w[1]));
+//! assert_eq!(s, random_sorted_slice(1000, 100, 0));
+//!
+//! let u = uniform_slice(10, 5, 42);
+//! assert!((0..u.len()).all(|i| u.index_value(i) <= 5));
+//! # }
+//! ```
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::adapters::functional::FnSlice;
+
+/// The SplitMix64 increment.
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The SplitMix64 mixing function.
+#[inline]
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Maps a random 64-bit value to `[0..max]` (inclusive) using a
+/// multiply-shift.
+#[inline]
+fn below_or_eq(x: u64, max: u64) -> u64 {
+    ((x as u128 * (max as u128 + 1)) >> 64) as u64
+}
+
+/// A SplitMix64 pseudorandom number generator.
+#[cfg(feature = "alloc")]
+struct SplitMix64(u64);
+
+#[cfg(feature = "alloc")]
+impl SplitMix64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(GOLDEN_GAMMA);
+        mix(self.0)
+    }
+
+    /// Returns a value in `[0..max]` (inclusive).
+    #[inline]
+    fn next_below_or_eq(&mut self, max: u64) -> u64 {
+        below_or_eq(self.next_u64(), max)
+    }
+
+    /// Returns a value in `[0..1)`.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+    }
+}
+
+/// Returns a functional slice of length `n` whose values are uniformly
+/// distributed in `[0..max]` (inclusive).
+///
+/// Values are computed on the fly by hashing the index and the seed, so the
+/// slice uses no memory and supports random access in constant time.
+pub fn uniform_slice(n: usize, max: u64, seed: u64) -> FnSlice<impl Fn(usize) -> u64 + Copy> {
+    let seed = mix(seed);
+    FnSlice::new(n, move |i| {
+        below_or_eq(
+            mix(seed.wrapping_add((i as u64).wrapping_mul(GOLDEN_GAMMA))),
+            max,
+        )
+    })
+}
+
+/// Returns a vector of length `n` containing values in `[1..n]` (inclusive)
+/// with a Zipfian distribution of exponent `s`, that is, the probability of
+/// value `k` is proportional to 1/_k_<sup>_s_</sup>.
+///
+/// Larger exponents yield more skewed distributions; an exponent of zero
+/// yields a uniform distribution.
+///
+/// This function is available only if the `std` feature is enabled.
+///
+/// # Panics
+///
+/// This function will panic if `s` is negative or not a number.
+#[cfg(feature = "std")]
+pub fn zipfian_slice(n: usize, s: f64, seed: u64) -> Vec<u64> {
+    assert!(s >= 0.0, "the exponent must be nonnegative");
+    let mut cumulative = Vec::with_capacity(n);
+    let mut total = 0.0;
+    for k in 1..=n {
+        total += (k as f64).powf(-s);
+        cumulative.push(total);
+    }
+    let mut rng = SplitMix64(seed);
+    (0..n)
+        .map(|_| {
+            let x = rng.next_f64() * total;
+            (cumulative.partition_point(|&c| c <= x).min(n - 1) + 1) as u64
+        })
+        .collect()
+}
+
+/// Returns a nondecreasing vector of length `n` containing values uniformly
+/// distributed in `[0..max]` (inclusive).
+///
+/// This function is available only if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub fn random_sorted_slice(n: usize, max: u64, seed: u64) -> Vec<u64> {
+    let mut rng = SplitMix64(seed);
+    let mut values: Vec<u64> = (0..n).map(|_| rng.next_below_or_eq(max)).collect();
+    values.sort_unstable();
+    values
+}
+
+/// Returns a vector of length `n` made of runs of equal values.
+///
+/// The length of each run is uniformly distributed in `[1..max_run]`
+/// (inclusive), and its value is uniformly distributed in `[0..max]`
+/// (inclusive), so consecutive runs might have the same value. The last run
+/// is truncated so that the vector has length `n`.
+///
+/// This function is available only if the `alloc` feature is enabled.
+///
+/// # Panics
+///
+/// This function will panic if `max_run` is zero.
+#[cfg(feature = "alloc")]
+pub fn runs_slice(n: usize, max_run: usize, max: u64, seed: u64) -> Vec<u64> {
+    assert!(max_run != 0, "the maximum run length must be positive");
+    let mut rng = SplitMix64(seed);
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n {
+        let run = 1 + rng.next_below_or_eq(max_run as u64 - 1) as usize;
+        let value = rng.next_below_or_eq(max);
+        let run = run.min(n - values.len());
+        values.extend(core::iter::repeat_n(value, run));
+    }
+    values
+}
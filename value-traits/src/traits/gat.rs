@@ -0,0 +1,266 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Subslice and iterator traits with plain generic associated types.
+//!
+//! The subslice and iterator traits of this crate emulate generic associated
+//! types with lifetime-parameterized traits, such as
+//! [`SliceByValueSubsliceGat`](crate::slices::SliceByValueSubsliceGat), and
+//! an implicit [`ImplBound`](crate::ImplBound) parameter: in this way,
+//! higher-rank trait bounds on the associated types do not force the
+//! implementing type to be `'static` (see the [documentation of
+//! `SliceByValueSubsliceGat`](crate::slices::SliceByValueSubsliceGat) for
+//! the details). The price is that bounds are verbose, and that the type of a
+//! subslice or iterator must be accessed through the aliases
+//! [`Subslice`](crate::slices::Subslice) and [`Iter`](crate::iter::Iter).
+//!
+//! The traits in this module have the same names as the traits they mirror,
+//! but use plain generic associated types, so bounds can be written as
+//! `S::Subslice<'a>` or `S: IterateByValue<Item = usize>`. Blanket
+//! implementations bridge them to the traits of the crate, so every type
+//! implementing, say,
+//! [`slices::SliceByValueSubslice`](crate::slices::SliceByValueSubslice)
+//! implements [`SliceByValueSubslice`], and no code needs to be written for
+//! them. Since methods have the same names, import either these traits or
+//! the ones they mirror, but not both.
+//!
+//! ```rust
+//! use value_traits::gat::{IterateByValue, SliceByValueSubslice};
+//! use value_traits::slices::SliceByValue;
+//!
+//! fn sum_tail<S>(s: &S, from: usize) -> usize
+//! where
+//!     S: SliceByValueSubslice<Value = usize>,
+//!     for<'a> S::Subslice<'a>: IterateByValue<Item = usize>,
+//! {
+//!     s.index_subslice(from..).iter_value().sum()
+//! }
+//!
+//! assert_eq!(sum_tail(&[1, 2, 3, 4], 2), 7);
+//! ```
+//!
+//! Note, however, that a higher-rank trait bound such as the one above
+//! presently requires the slice type to be `'static`, which is exactly the
+//! problem the traits of the crate work around: bounds that do not quantify
+//! over all lifetimes have no such limitation. These traits are the expected
+//! shape of the API once the limitation is lifted upstream; presently, they
+//! are an experimental alternative available only if the `nightly` feature
+//! is enabled. They do not require a nightly compiler.
+
+#![cfg(feature = "nightly")]
+
+use core::ops::Range;
+
+use crate::{
+    iter::{self, IterateByValueFromGat, IterateByValueGat},
+    panics::assert_range,
+    slices::{self, ComposeRange, SliceByValue, SliceByValueMut},
+};
+
+/// Subslicing with a generic associated type.
+///
+/// This trait mirrors
+/// [`slices::SliceByValueSubslice`](crate::slices::SliceByValueSubslice),
+/// which it is implemented for. Ranges can be of any type implementing
+/// [`ComposeRange`].
+///
+/// See the [module documentation](self).
+pub trait SliceByValueSubslice: SliceByValue {
+    /// The type of the subslice.
+    type Subslice<'a>: SliceByValue<Value = Self::Value> + SliceByValueSubslice
+    where
+        Self: 'a;
+
+    /// See [`slices::SliceByValueSubsliceRange::get_subslice_unchecked`].
+    ///
+    /// # Safety
+    ///
+    /// The range must be within bounds.
+    unsafe fn get_subslice_unchecked(&self, range: impl ComposeRange) -> Self::Subslice<'_>;
+
+    /// See [`slices::SliceByValueSubsliceRange::index_subslice`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn index_subslice(&self, range: impl ComposeRange) -> Self::Subslice<'_> {
+        assert_range(&range, self.len());
+        // SAFETY: range is within bounds
+        unsafe { self.get_subslice_unchecked(range) }
+    }
+
+    /// See [`slices::SliceByValueSubsliceRange::get_subslice`].
+    fn get_subslice(&self, range: impl ComposeRange) -> Option<Self::Subslice<'_>> {
+        if range.is_valid(self.len()) {
+            // SAFETY: range is within bounds
+            Some(unsafe { self.get_subslice_unchecked(range) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: slices::SliceByValueSubslice + ?Sized> SliceByValueSubslice for S {
+    type Subslice<'a>
+        = slices::Subslice<'a, S>
+    where
+        Self: 'a;
+
+    #[inline]
+    unsafe fn get_subslice_unchecked(&self, range: impl ComposeRange) -> Self::Subslice<'_> {
+        let range = range.compose(0..self.len());
+        // SAFETY: range is within bounds
+        unsafe {
+            slices::SliceByValueSubsliceRange::<Range<usize>>::get_subslice_unchecked(self, range)
+        }
+    }
+}
+
+/// Mutable subslicing with a generic associated type.
+///
+/// This trait mirrors
+/// [`slices::SliceByValueSubsliceMut`](crate::slices::SliceByValueSubsliceMut),
+/// which it is implemented for. Ranges can be of any type implementing
+/// [`ComposeRange`].
+///
+/// See the [module documentation](self).
+pub trait SliceByValueSubsliceMut: SliceByValueMut {
+    /// The type of the mutable subslice.
+    type SubsliceMut<'a>: SliceByValueMut<Value = Self::Value> + SliceByValueSubsliceMut
+    where
+        Self: 'a;
+
+    /// See [`slices::SliceByValueSubsliceRangeMut::get_subslice_unchecked_mut`].
+    ///
+    /// # Safety
+    ///
+    /// The range must be within bounds.
+    unsafe fn get_subslice_unchecked_mut(
+        &mut self,
+        range: impl ComposeRange,
+    ) -> Self::SubsliceMut<'_>;
+
+    /// See [`slices::SliceByValueSubsliceRangeMut::index_subslice_mut`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn index_subslice_mut(&mut self, range: impl ComposeRange) -> Self::SubsliceMut<'_> {
+        assert_range(&range, self.len());
+        // SAFETY: range is within bounds
+        unsafe { self.get_subslice_unchecked_mut(range) }
+    }
+
+    /// See [`slices::SliceByValueSubsliceRangeMut::get_subslice_mut`].
+    fn get_subslice_mut(&mut self, range: impl ComposeRange) -> Option<Self::SubsliceMut<'_>> {
+        if range.is_valid(self.len()) {
+            // SAFETY: range is within bounds
+            Some(unsafe { self.get_subslice_unchecked_mut(range) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: slices::SliceByValueSubsliceMut + ?Sized> SliceByValueSubsliceMut for S {
+    type SubsliceMut<'a>
+        = slices::SubsliceMut<'a, S>
+    where
+        Self: 'a;
+
+    #[inline]
+    unsafe fn get_subslice_unchecked_mut(
+        &mut self,
+        range: impl ComposeRange,
+    ) -> Self::SubsliceMut<'_> {
+        let range = range.compose(0..self.len());
+        // SAFETY: range is within bounds
+        unsafe {
+            slices::SliceByValueSubsliceRangeMut::<Range<usize>>::get_subslice_unchecked_mut(
+                self, range,
+            )
+        }
+    }
+}
+
+/// By-value iteration with a generic associated type.
+///
+/// This trait mirrors [`iter::IterateByValue`](crate::iter::IterateByValue),
+/// which it is implemented for if the type of the items does not depend on
+/// the lifetime of the iterator.
+///
+/// See the [module documentation](self).
+pub trait IterateByValue {
+    /// The type of the items returned by the iterator.
+    type Item;
+
+    /// The type of the iterator returned by
+    /// [`iter_value`](IterateByValue::iter_value).
+    type Iter<'a>: Iterator<Item = Self::Item>
+    where
+        Self: 'a;
+
+    /// See [`iter::IterateByValue::iter_value`].
+    fn iter_value(&self) -> Self::Iter<'_>;
+}
+
+impl<T, S> IterateByValue for S
+where
+    S: iter::IterateByValue + for<'a> IterateByValueGat<'a, Item = T> + ?Sized,
+{
+    type Item = T;
+
+    type Iter<'a>
+        = iter::Iter<'a, S>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn iter_value(&self) -> Self::Iter<'_> {
+        iter::IterateByValue::iter_value(self)
+    }
+}
+
+/// By-value iteration from a given position with a generic associated type.
+///
+/// This trait mirrors
+/// [`iter::IterateByValueFrom`](crate::iter::IterateByValueFrom), which it
+/// is implemented for if the type of the items does not depend on the
+/// lifetime of the iterator.
+///
+/// See the [module documentation](self).
+pub trait IterateByValueFrom {
+    /// The type of the items returned by the iterator.
+    type Item;
+
+    /// The type of the iterator returned by
+    /// [`iter_value_from`](IterateByValueFrom::iter_value_from).
+    type IterFrom<'a>: Iterator<Item = Self::Item>
+    where
+        Self: 'a;
+
+    /// See [`iter::IterateByValueFrom::iter_value_from`].
+    fn iter_value_from(&self, from: usize) -> Self::IterFrom<'_>;
+}
+
+impl<T, S> IterateByValueFrom for S
+where
+    S: iter::IterateByValueFrom + for<'a> IterateByValueFromGat<'a, Item = T> + ?Sized,
+{
+    type Item = T;
+
+    type IterFrom<'a>
+        = iter::IterFrom<'a, S>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn iter_value_from(&self, from: usize) -> Self::IterFrom<'_> {
+        iter::IterateByValueFrom::iter_value_from(self, from)
+    }
+}
@@ -8,7 +8,9 @@
 
 //! Traits for by-value iterators.
 
-use crate::{ImplBound, Ref};
+use crate::{ImplBound, Ref, slices::SliceByValue};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
 
 /// A GAT-like trait specifying the type of a by-value iterator.
 ///
@@ -120,6 +122,21 @@ impl<'a, T: IterateByValueGat<'a> + ?Sized> IterateByValueGat<'a> for &mut T {
 pub trait IterateByValue: for<'a> IterateByValueGat<'a> {
     /// Returns an iterator on values.
     fn iter_value(&self) -> Iter<'_, Self>;
+
+    /// Returns a boxed iterator on values.
+    ///
+    /// This method is convenient when the type of the iterator cannot be
+    /// named, for example when returning it from a function or across crate
+    /// boundaries, at the cost of dynamic dispatch. See also
+    /// [`DynIterateByValue`].
+    ///
+    /// This method is available only if the `alloc` feature is enabled.
+    #[cfg(feature = "alloc")]
+    fn boxed_iter_value(
+        &self,
+    ) -> Box<dyn Iterator<Item = <Self as IterateByValueGat<'_>>::Item> + '_> {
+        Box::new(self.iter_value())
+    }
 }
 
 impl<T: IterateByValue + ?Sized> IterateByValue for &T {
@@ -243,6 +260,19 @@ pub type IterFrom<'a, T> = <T as IterateByValueFromGat<'a>>::IterFrom;
 pub trait IterateByValueFrom: for<'a> IterateByValueFromGat<'a> {
     /// Returns an iterator on values starting at the given position.
     fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self>;
+
+    /// Returns a boxed iterator on values starting at the given position.
+    ///
+    /// See [`IterateByValue::boxed_iter_value`].
+    ///
+    /// This method is available only if the `alloc` feature is enabled.
+    #[cfg(feature = "alloc")]
+    fn boxed_iter_value_from(
+        &self,
+        from: usize,
+    ) -> Box<dyn Iterator<Item = <Self as IterateByValueFromGat<'_>>::Item> + '_> {
+        Box::new(self.iter_value_from(from))
+    }
 }
 
 impl<T: IterateByValueFrom + ?Sized> IterateByValueFrom for &T {
@@ -257,11 +287,415 @@ impl<T: IterateByValueFrom + ?Sized> IterateByValueFrom for &mut T {
     }
 }
 
+/// A marker trait promising that by-value iterators return exactly as many
+/// values as the length of the slice.
+///
+/// More precisely, [`iter_value`](IterateByValue::iter_value) must return
+/// exactly [`len`](SliceByValue::len) values, and
+/// [`iter_value_from(from)`](IterateByValueFrom::iter_value_from) must
+/// return exactly `len - from` values when `from` is at most
+/// [`len`](SliceByValue::len).
+///
+/// Generic code can rely on this guarantee, for example, to preallocate
+/// output buffers, but, as it happens for [`ExactSizeIterator`], unsafe code
+/// must not rely on it for memory safety.
+///
+/// ```rust
+/// use value_traits::iter::*;
+/// use value_traits::slices::*;
+///
+/// fn to_array<S: ExactSizeIterateByValue, const N: usize>(s: &S) -> [S::Value; N]
+/// where
+///     for<'a> S: IterateByValueGat<'a, Item = S::Value>,
+///     S::Value: Default + Copy,
+/// {
+///     assert_eq!(s.len(), N);
+///     let mut a = [S::Value::default(); N];
+///     for (x, v) in a.iter_mut().zip(s.iter_value()) {
+///         *x = v;
+///     }
+///     a
+/// }
+///
+/// assert_eq!(to_array::<_, 3>(&[1, 2, 3]), [1, 2, 3]);
+/// ```
+pub trait ExactSizeIterateByValue: IterateByValue + IterateByValueFrom + SliceByValue {}
+
+impl<T: ExactSizeIterateByValue + ?Sized> ExactSizeIterateByValue for &T {}
+impl<T: ExactSizeIterateByValue + ?Sized> ExactSizeIterateByValue for &mut T {}
+
+/// A marker trait promising that the by-value iterator of a slice returns
+/// exactly the values of the slice, in order.
+///
+/// Nothing in the traits of this crate forces
+/// [`iter_value`](IterateByValue::iter_value) to agree with
+/// [`get_value`](SliceByValue::get_value), but generic code mixing the two
+/// access styles usually assumes they do. Implementing this trait makes the
+/// assumption explicit, so it can be required by a bound; as it happens for
+/// [`ExactSizeIterateByValue`], unsafe code must not rely on it for memory
+/// safety.
+///
+/// The provided method
+/// [`debug_check_consistency`](ConsistentIteration::debug_check_consistency)
+/// checks the promise in debug builds, and can be used as an enforcement
+/// hook in tests or at the boundaries of generic code.
+///
+/// ```rust
+/// use value_traits::iter::ConsistentIteration;
+///
+/// let v = vec![1, 2, 3];
+/// v.debug_check_consistency();
+/// ```
+pub trait ConsistentIteration: IterateByValue + SliceByValue {
+    /// Checks that [`iter_value`](IterateByValue::iter_value) returns
+    /// exactly the values returned by [`get_value`](SliceByValue::get_value)
+    /// for all indices, in debug builds; in release builds, this method does
+    /// nothing.
+    ///
+    /// The check takes linear time.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this method will panic if the iterator disagrees
+    /// with the slice.
+    fn debug_check_consistency(&self)
+    where
+        for<'a> Self: IterateByValueGat<'a, Item = Self::Value>,
+        Self::Value: PartialEq + core::fmt::Debug,
+    {
+        #[cfg(debug_assertions)]
+        {
+            let len = self.len();
+            let mut count = 0;
+            for (i, value) in self.iter_value().enumerate() {
+                match self.get_value(i) {
+                    Some(expected) => crate::panics::check_eq!(
+                        value,
+                        expected,
+                        "iter_value disagrees with get_value",
+                        "iter_value disagrees with get_value at index {i}"
+                    ),
+                    None => crate::panics::fail!(
+                        "iter_value returns too many values",
+                        "iter_value returns more than {len} values"
+                    ),
+                }
+                count += 1;
+            }
+            crate::panics::check_eq!(
+                count,
+                len,
+                "iter_value returns too few values",
+                "iter_value returns {count} values, expected {len}"
+            );
+        }
+    }
+}
+
+impl<T: ConsistentIteration + ?Sized> ConsistentIteration for &T {}
+impl<T: ConsistentIteration + ?Sized> ConsistentIteration for &mut T {}
+
+/// A GAT-like trait specifying the type of a fallible by-value iterator.
+///
+/// See [`SliceByValueSubsliceGat`](crate::slices::SliceByValueSubsliceGat) for
+/// more information.
+pub trait TryIterateByValueGat<'a, __Implicit: ImplBound = Ref<'a, Self>> {
+    /// The type of the values returned by the iterator.
+    type Item;
+    /// The type of the errors returned by the iterator.
+    type Error;
+    /// The type of the iterator returned by
+    /// [`try_iter_value`](TryIterateByValue::try_iter_value).
+    type TryIter: 'a + Iterator<Item = Result<Self::Item, Self::Error>>;
+}
+
+/// A convenience type representing the type of iterator returned by a type
+/// implementing [`TryIterateByValueGat`].
+pub type TryIter<'a, T> = <T as TryIterateByValueGat<'a>>::TryIter;
+
+impl<'a, T: TryIterateByValueGat<'a> + ?Sized> TryIterateByValueGat<'a> for &T {
+    type Item = T::Item;
+    type Error = T::Error;
+    type TryIter = T::TryIter;
+}
+
+impl<'a, T: TryIterateByValueGat<'a> + ?Sized> TryIterateByValueGat<'a> for &mut T {
+    type Item = T::Item;
+    type Error = T::Error;
+    type TryIter = T::TryIter;
+}
+
+/// A trait for obtaining a fallible by-value iterator.
+///
+/// This trait is the fallible analogue of [`IterateByValue`]: the iterator
+/// returns a [`Result`] for each value, so backends in which access can fail
+/// (e.g., because they are backed by I/O or validated by checksums) can
+/// report errors instead of panicking or returning default values. After
+/// returning an error, the behavior of the iterator is
+/// implementation-defined.
+///
+/// Infallible iterables can be passed to code expecting this trait using the
+/// [`Fallible`](crate::adapters::fallible::Fallible) adapter.
+///
+/// ```rust
+/// use value_traits::adapters::fallible::Fallible;
+/// use value_traits::iter::*;
+///
+/// fn try_sum<S, E>(s: &S) -> Result<u32, E>
+/// where
+///     S: TryIterateByValue + for<'a> TryIterateByValueGat<'a, Item = u32, Error = E>,
+/// {
+///     s.try_iter_value().sum()
+/// }
+///
+/// assert_eq!(try_sum(&Fallible::new([1, 2, 3])), Ok(6));
+/// ```
+pub trait TryIterateByValue: for<'a> TryIterateByValueGat<'a> {
+    /// Returns a fallible iterator on values.
+    fn try_iter_value(&self) -> TryIter<'_, Self>;
+}
+
+impl<T: TryIterateByValue + ?Sized> TryIterateByValue for &T {
+    fn try_iter_value(&self) -> TryIter<'_, Self> {
+        (**self).try_iter_value()
+    }
+}
+
+impl<T: TryIterateByValue + ?Sized> TryIterateByValue for &mut T {
+    fn try_iter_value(&self) -> TryIter<'_, Self> {
+        (**self).try_iter_value()
+    }
+}
+
+/// A by-value iterator that can be moved forward efficiently.
+///
+/// Iterators implementing this trait know the index of the next value they
+/// will return (their [current index](SeekableValueIter::current_index)) and can skip
+/// to a later index or, if the values are sorted, to the first value greater
+/// than or equal to a given one. In this way, algorithms such as the galloping
+/// intersection of posting lists can be written against the iterators of
+/// this crate instead of bespoke cursor types:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")] {
+/// use value_traits::iter::{IterateByValue, SeekableValueIter};
+/// use value_traits::slices::{SliceByValue, SliceByValueSubsliceRange};
+/// use value_traits::{Iterators, Subslices};
+///
+/// #[derive(Subslices, Iterators)]
+/// struct Squares(usize);
+///
+/// impl SliceByValue for Squares {
+///     type Value = usize;
+///     fn len(&self) -> usize {
+///         self.0
+///     }
+///     unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+///         index * index
+///     }
+/// }
+///
+/// #[derive(Subslices, Iterators)]
+/// struct Cubes(usize);
+///
+/// impl SliceByValue for Cubes {
+///     type Value = usize;
+///     fn len(&self) -> usize {
+///         self.0
+///     }
+///     unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+///         index * index * index
+///     }
+/// }
+///
+/// fn intersect(
+///     mut a: impl SeekableValueIter<Item = usize>,
+///     mut b: impl SeekableValueIter<Item = usize>,
+/// ) -> Vec<usize> {
+///     let mut result = vec![];
+///     let (mut x, mut y) = (a.next(), b.next());
+///     while let (Some(u), Some(v)) = (x, y) {
+///         if u == v {
+///             result.push(u);
+///             (x, y) = (a.next(), b.next());
+///         } else if u < v {
+///             x = a.seek_value(&v);
+///         } else {
+///             y = b.seek_value(&u);
+///         }
+///     }
+///     result
+/// }
+///
+/// let (squares, cubes) = (Squares(100), Cubes(100));
+/// let (squares, cubes) = (squares.index_subslice(..), cubes.index_subslice(..));
+/// assert_eq!(
+///     intersect(squares.iter_value(), cubes.iter_value()),
+///     [0, 1, 64, 729, 4096]
+/// );
+///
+/// let mut iter = squares.iter_value();
+/// iter.seek(10);
+/// assert_eq!(iter.current_index(), 10);
+/// assert_eq!(iter.next(), Some(100));
+/// # }
+/// ```
+///
+/// Indices are relative to the sequence being iterated: for iterators on
+/// subslices, index zero is the first value of the subslice, consistently
+/// with [`iter_value_from`](IterateByValueFrom::iter_value_from).
+pub trait SeekableValueIter: Iterator {
+    /// Returns the index of the next value that will be returned.
+    fn current_index(&self) -> usize;
+
+    /// Moves the iterator forward so that the next value returned is the one
+    /// at the given index.
+    ///
+    /// If `to_index` is smaller than or equal to the current
+    /// [index](SeekableValueIter::current_index), this method has no effect;
+    /// if it is beyond the end of the iterator, the iterator is exhausted.
+    fn seek(&mut self, to_index: usize);
+
+    /// Moves the iterator forward to the first value greater than or equal to
+    /// the given one, and returns it, or returns `None` if there is no such
+    /// value.
+    ///
+    /// The remaining values must be sorted in nondecreasing order, or the
+    /// result is unspecified. The default implementation performs a linear
+    /// scan; implementations with random access can use exponential search.
+    fn seek_value(&mut self, value: &Self::Item) -> Option<Self::Item>
+    where
+        Self::Item: PartialOrd,
+    {
+        Iterator::find(&mut &mut *self, |x| x >= value)
+    }
+}
+
+impl<I: SeekableValueIter + ?Sized> SeekableValueIter for &mut I {
+    #[inline]
+    fn current_index(&self) -> usize {
+        (**self).current_index()
+    }
+
+    #[inline]
+    fn seek(&mut self, to_index: usize) {
+        (**self).seek(to_index)
+    }
+
+    #[inline]
+    fn seek_value(&mut self, value: &Self::Item) -> Option<Self::Item>
+    where
+        Self::Item: PartialOrd,
+    {
+        (**self).seek_value(value)
+    }
+}
+
+/// A dyn-compatible mirror of [`IterateByValue`] and [`IterateByValueFrom`].
+///
+/// [`IterateByValue`] and [`IterateByValueFrom`] are not dyn compatible, as
+/// the type of the returned iterator is specified by a GAT-like trait. This
+/// trait is automatically implemented for all sized types implementing both
+/// traits, returning boxed iterators, and in turn `dyn DynIterateByValue<Item
+/// = V>` (possibly with `Send` and `Sync` bounds) implements [`IterateByValue`]
+/// and [`IterateByValueFrom`]. As a result, [`Box`], [`Arc`](std::sync::Arc)
+/// and [`Rc`](std::rc::Rc) pointers to such trait objects satisfy the
+/// standard bounds, and heterogeneous sources of values can be stored
+/// together:
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use value_traits::iter::*;
+///
+/// fn sum<S: IterateByValue + for<'a> IterateByValueGat<'a, Item = u32>>(s: &S) -> u32 {
+///     s.iter_value().sum()
+/// }
+///
+/// let sources: Vec<Box<dyn DynIterateByValue<Item = u32>>> =
+///     vec![Box::new(vec![1, 2, 3]), Box::new([4, 5])];
+/// assert_eq!(sources.iter().map(sum).collect::<Vec<_>>(), vec![6, 9]);
+/// # }
+/// ```
+///
+/// This trait is available only if the `alloc` feature is enabled.
+#[cfg(feature = "alloc")]
+pub trait DynIterateByValue {
+    /// The type of the items returned by the iterators.
+    type Item;
+
+    /// Returns a boxed iterator on values.
+    ///
+    /// See [`IterateByValue::iter_value`].
+    fn dyn_iter_value(&self) -> Box<dyn Iterator<Item = Self::Item> + '_>;
+
+    /// Returns a boxed iterator on values starting at the given position.
+    ///
+    /// See [`IterateByValueFrom::iter_value_from`].
+    fn dyn_iter_value_from(&self, from: usize) -> Box<dyn Iterator<Item = Self::Item> + '_>;
+}
+
+#[cfg(feature = "alloc")]
+impl<V, T> DynIterateByValue for T
+where
+    T: IterateByValue
+        + IterateByValueFrom
+        + for<'a> IterateByValueGat<'a, Item = V>
+        + for<'a> IterateByValueFromGat<'a, Item = V>,
+{
+    type Item = V;
+
+    fn dyn_iter_value(&self) -> Box<dyn Iterator<Item = V> + '_> {
+        Box::new(self.iter_value())
+    }
+
+    fn dyn_iter_value_from(&self, from: usize) -> Box<dyn Iterator<Item = V> + '_> {
+        Box::new(self.iter_value_from(from))
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_dyn {
+    ($($bounds:tt)*) => {
+        impl<'a, 'b, V> IterateByValueGat<'a> for dyn DynIterateByValue<Item = V> $($bounds)* + 'b {
+            type Item = V;
+            type Iter = Box<dyn Iterator<Item = V> + 'a>;
+        }
+
+        impl<V> IterateByValue for dyn DynIterateByValue<Item = V> $($bounds)* + '_ {
+            fn iter_value(&self) -> Iter<'_, Self> {
+                self.dyn_iter_value()
+            }
+        }
+
+        impl<'a, 'b, V> IterateByValueFromGat<'a> for dyn DynIterateByValue<Item = V> $($bounds)* + 'b {
+            type Item = V;
+            type IterFrom = Box<dyn Iterator<Item = V> + 'a>;
+        }
+
+        impl<V> IterateByValueFrom for dyn DynIterateByValue<Item = V> $($bounds)* + '_ {
+            fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+                self.dyn_iter_value_from(from)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_dyn!();
+#[cfg(feature = "alloc")]
+impl_dyn!(+ Send);
+#[cfg(feature = "alloc")]
+impl_dyn!(+ Send + Sync);
+
 #[cfg(feature = "alloc")]
 mod alloc_impls {
     use super::*;
     #[cfg(all(feature = "alloc", not(feature = "std")))]
-    use alloc::boxed::Box;
+    use alloc::borrow::Cow;
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    use alloc::{borrow::ToOwned, boxed::Box};
+    #[cfg(feature = "std")]
+    use std::borrow::Cow;
 
     impl<'a, S: IterateByValueGat<'a> + ?Sized> IterateByValueGat<'a> for Box<S> {
         type Item = S::Item;
@@ -274,6 +708,10 @@ mod alloc_impls {
         }
     }
 
+    impl<S: ExactSizeIterateByValue + ?Sized> ExactSizeIterateByValue for Box<S> {}
+
+    impl<S: ConsistentIteration + ?Sized> ConsistentIteration for Box<S> {}
+
     impl<'a, S: IterateByValueFromGat<'a> + ?Sized> IterateByValueFromGat<'a> for Box<S> {
         type Item = S::Item;
         type IterFrom = S::IterFrom;
@@ -284,6 +722,32 @@ mod alloc_impls {
             (**self).iter_value_from(from)
         }
     }
+
+    impl<'a, S: IterateByValueGat<'a> + ToOwned + ?Sized> IterateByValueGat<'a> for Cow<'_, S> {
+        type Item = S::Item;
+        type Iter = S::Iter;
+    }
+
+    impl<S: IterateByValue + ToOwned + ?Sized> IterateByValue for Cow<'_, S> {
+        fn iter_value(&self) -> Iter<'_, Self> {
+            (**self).iter_value()
+        }
+    }
+
+    impl<S: ExactSizeIterateByValue + ToOwned + ?Sized> ExactSizeIterateByValue for Cow<'_, S> {}
+
+    impl<S: ConsistentIteration + ToOwned + ?Sized> ConsistentIteration for Cow<'_, S> {}
+
+    impl<'a, S: IterateByValueFromGat<'a> + ToOwned + ?Sized> IterateByValueFromGat<'a> for Cow<'_, S> {
+        type Item = S::Item;
+        type IterFrom = S::IterFrom;
+    }
+
+    impl<S: IterateByValueFrom + ToOwned + ?Sized> IterateByValueFrom for Cow<'_, S> {
+        fn iter_value_from(&self, from: usize) -> IterFrom<'_, Self> {
+            (**self).iter_value_from(from)
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -302,6 +766,10 @@ mod std_impls {
         }
     }
 
+    impl<S: ExactSizeIterateByValue + ?Sized> ExactSizeIterateByValue for Arc<S> {}
+
+    impl<S: ConsistentIteration + ?Sized> ConsistentIteration for Arc<S> {}
+
     impl<'a, S: IterateByValueFromGat<'a> + ?Sized> IterateByValueFromGat<'a> for Arc<S> {
         type Item = S::Item;
         type IterFrom = S::IterFrom;
@@ -324,6 +792,10 @@ mod std_impls {
         }
     }
 
+    impl<S: ExactSizeIterateByValue + ?Sized> ExactSizeIterateByValue for Rc<S> {}
+
+    impl<S: ConsistentIteration + ?Sized> ConsistentIteration for Rc<S> {}
+
     impl<'a, S: IterateByValueFromGat<'a> + ?Sized> IterateByValueFromGat<'a> for Rc<S> {
         type Item = S::Item;
         type IterFrom = S::IterFrom;
@@ -0,0 +1,186 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Traits for jagged arrays, that is, sequences of by-value rows of variable
+//! length.
+//!
+//! [`JaggedByValue`] models structures such as adjacency lists or posting
+//! lists, in which each row is a by-value slice. The
+//! [`Csr`](crate::adapters::jagged::Csr) adapter implements it using a
+//! by-value slice of values and a by-value slice of offsets, as in the
+//! _compressed sparse row_ (CSR) format:
+//!
+//! ```rust
+//! use value_traits::adapters::jagged::Csr;
+//! use value_traits::jagged::JaggedByValue;
+//! use value_traits::slices::SliceByValue;
+//!
+//! // Rows [1, 2], [], [3, 4, 5]
+//! let j = Csr::new([0, 2, 2, 5], [1, 2, 3, 4, 5]);
+//! assert_eq!(j.num_rows(), 3);
+//! assert_eq!(j.total_len(), 5);
+//! assert_eq!(j.row(2).index_value(1), 4);
+//! assert_eq!(j.rows().map(|r| r.len()).collect::<Vec<_>>(), [2, 0, 3]);
+//! ```
+
+use core::{iter::FusedIterator, ops::Range};
+
+use crate::{panics::assert_index, slices::SliceByValue};
+
+/// A read-only sequence of by-value rows of variable length.
+///
+/// The only methods that must be implemented are
+/// [`num_rows`](JaggedByValue::num_rows),
+/// [`total_len`](JaggedByValue::total_len), and
+/// [`get_row_unchecked`](JaggedByValue::get_row_unchecked).
+pub trait JaggedByValue {
+    /// The type of the values in the rows.
+    type Value;
+
+    /// The type of the rows.
+    type Row<'a>: SliceByValue<Value = Self::Value>
+    where
+        Self: 'a;
+
+    /// Returns the number of rows.
+    fn num_rows(&self) -> usize;
+
+    /// Returns the sum of the lengths of the rows.
+    fn total_len(&self) -> usize;
+
+    /// Returns the given row without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be smaller than [`num_rows`](JaggedByValue::num_rows).
+    unsafe fn get_row_unchecked(&self, row: usize) -> Self::Row<'_>;
+
+    /// Returns the given row, or `None` if it is out of bounds.
+    fn get_row(&self, row: usize) -> Option<Self::Row<'_>> {
+        if row < self.num_rows() {
+            // SAFETY: row is within bounds
+            Some(unsafe { self.get_row_unchecked(row) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the given row.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the row is out of bounds.
+    fn row(&self, row: usize) -> Self::Row<'_> {
+        assert_index(row, self.num_rows());
+        // SAFETY: row is within bounds
+        unsafe { self.get_row_unchecked(row) }
+    }
+
+    /// Returns the length of the given row.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the row is out of bounds.
+    fn row_len(&self, row: usize) -> usize {
+        self.row(row).len()
+    }
+
+    /// Returns an iterator on the rows.
+    fn rows(&self) -> Rows<'_, Self> {
+        Rows {
+            jagged: self,
+            range: 0..self.num_rows(),
+        }
+    }
+}
+
+impl<J: JaggedByValue + ?Sized> JaggedByValue for &J {
+    type Value = J::Value;
+
+    type Row<'a>
+        = J::Row<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn num_rows(&self) -> usize {
+        (**self).num_rows()
+    }
+
+    #[inline]
+    fn total_len(&self) -> usize {
+        (**self).total_len()
+    }
+
+    #[inline]
+    unsafe fn get_row_unchecked(&self, row: usize) -> Self::Row<'_> {
+        unsafe { (**self).get_row_unchecked(row) }
+    }
+
+    #[inline]
+    fn row_len(&self, row: usize) -> usize {
+        (**self).row_len(row)
+    }
+}
+
+/// An iterator on the rows of a [`JaggedByValue`].
+///
+/// See [`JaggedByValue::rows`].
+#[derive(Debug)]
+pub struct Rows<'a, J: ?Sized> {
+    jagged: &'a J,
+    range: Range<usize>,
+}
+
+impl<J: ?Sized> Clone for Rows<'_, J> {
+    fn clone(&self) -> Self {
+        Self {
+            jagged: self.jagged,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<'a, J: JaggedByValue + ?Sized> Iterator for Rows<'a, J> {
+    type Item = J::Row<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range
+            .next()
+            // SAFETY: row is within bounds
+            .map(|row| unsafe { self.jagged.get_row_unchecked(row) })
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range
+            .nth(n)
+            // SAFETY: row is within bounds
+            .map(|row| unsafe { self.jagged.get_row_unchecked(row) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<J: JaggedByValue + ?Sized> DoubleEndedIterator for Rows<'_, J> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range
+            .next_back()
+            // SAFETY: row is within bounds
+            .map(|row| unsafe { self.jagged.get_row_unchecked(row) })
+    }
+}
+
+impl<J: JaggedByValue + ?Sized> ExactSizeIterator for Rows<'_, J> {}
+
+impl<J: JaggedByValue + ?Sized> FusedIterator for Rows<'_, J> {}
@@ -0,0 +1,300 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Traits for by-value matrices.
+//!
+//! [`MatrixByValue`] is the two-dimensional analogue of
+//! [`SliceByValue`]: it provides by-value access to the entries of a matrix
+//! that might be stored in compressed form or computed on the fly. Rows and
+//! columns can be accessed as by-value slices using
+//! [`row`](MatrixByValue::row) and [`col`](MatrixByValue::col), and
+//! traversed using [`rows`](MatrixByValue::rows) and
+//! [`cols`](MatrixByValue::cols), without copies.
+//!
+//! The adapters [`RowMajor`](crate::adapters::matrix::RowMajor) and
+//! [`Transposed`](crate::adapters::matrix::Transposed) make it possible to
+//! view a by-value slice as a matrix, and to swap the orientation of a
+//! matrix, respectively.
+//!
+//! ```rust
+//! use value_traits::adapters::matrix::{RowMajor, Transposed};
+//! use value_traits::matrices::MatrixByValue;
+//! use value_traits::slices::SliceByValue;
+//!
+//! let m = RowMajor::new([1, 2, 3, 4, 5, 6], 3);
+//! assert_eq!(m.index_entry(1, 0), 4);
+//! let col_sums = m
+//!     .cols()
+//!     .map(|c| (0..c.len()).map(|i| c.index_value(i)).sum::<i32>())
+//!     .collect::<Vec<_>>();
+//! assert_eq!(col_sums, [5, 7, 9]);
+//!
+//! let t = Transposed::new(&m);
+//! assert_eq!(t.num_rows(), 3);
+//! assert_eq!(t.row(2).index_value(1), 6);
+//! ```
+//!
+//! [`CoordByValue`] is a minimal trait for structures, such as sparse
+//! matrices, that provide by-value access keyed by coordinates, but that
+//! might have no value at some coordinates. All matrices implement it, and
+//! the adapter [`CooMatrix`](crate::adapters::matrix::CooMatrix) provides a
+//! sparse implementation based on sorted coordinate lists.
+
+use core::{iter::FusedIterator, ops::Range};
+
+use crate::{panics::assert_index, slices::SliceByValue};
+
+/// A read-only by-value matrix.
+///
+/// Implementors must provide the dimensions of the matrix and unchecked
+/// access to its entries; all other methods have default implementations.
+pub trait MatrixByValue {
+    /// The type of the entries of the matrix.
+    type Value;
+
+    /// Returns the number of rows of the matrix.
+    fn num_rows(&self) -> usize;
+
+    /// Returns the number of columns of the matrix.
+    fn num_cols(&self) -> usize;
+
+    /// Returns the entry at the given position without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be smaller than [`num_rows`](MatrixByValue::num_rows) and
+    /// `col` must be smaller than [`num_cols`](MatrixByValue::num_cols).
+    unsafe fn get_entry_unchecked(&self, row: usize, col: usize) -> Self::Value;
+
+    /// Returns the entry at the given position, or `None` if the position is
+    /// out of bounds.
+    fn get_entry(&self, row: usize, col: usize) -> Option<Self::Value> {
+        if row < self.num_rows() && col < self.num_cols() {
+            // SAFETY: row and col are within bounds
+            Some(unsafe { self.get_entry_unchecked(row, col) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the entry at the given position.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the position is out of bounds.
+    fn index_entry(&self, row: usize, col: usize) -> Self::Value {
+        assert_index(row, self.num_rows());
+        assert_index(col, self.num_cols());
+        // SAFETY: row and col are within bounds
+        unsafe { self.get_entry_unchecked(row, col) }
+    }
+
+    /// Returns a by-value slice view of the given row.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the row is out of bounds.
+    fn row(&self, row: usize) -> RowView<'_, Self> {
+        assert_index(row, self.num_rows());
+        RowView { matrix: self, row }
+    }
+
+    /// Returns a by-value slice view of the given column.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the column is out of bounds.
+    fn col(&self, col: usize) -> ColView<'_, Self> {
+        assert_index(col, self.num_cols());
+        ColView { matrix: self, col }
+    }
+
+    /// Returns an iterator on the rows of the matrix, returned as by-value
+    /// slice views.
+    fn rows(&self) -> Rows<'_, Self> {
+        Rows {
+            matrix: self,
+            range: 0..self.num_rows(),
+        }
+    }
+
+    /// Returns an iterator on the columns of the matrix, returned as by-value
+    /// slice views.
+    fn cols(&self) -> Cols<'_, Self> {
+        Cols {
+            matrix: self,
+            range: 0..self.num_cols(),
+        }
+    }
+}
+
+impl<M: MatrixByValue + ?Sized> MatrixByValue for &M {
+    type Value = M::Value;
+
+    #[inline]
+    fn num_rows(&self) -> usize {
+        (**self).num_rows()
+    }
+
+    #[inline]
+    fn num_cols(&self) -> usize {
+        (**self).num_cols()
+    }
+
+    #[inline]
+    unsafe fn get_entry_unchecked(&self, row: usize, col: usize) -> Self::Value {
+        unsafe { (**self).get_entry_unchecked(row, col) }
+    }
+
+    #[inline]
+    fn get_entry(&self, row: usize, col: usize) -> Option<Self::Value> {
+        (**self).get_entry(row, col)
+    }
+
+    #[inline]
+    fn index_entry(&self, row: usize, col: usize) -> Self::Value {
+        (**self).index_entry(row, col)
+    }
+}
+
+/// By-value access keyed by coordinates.
+///
+/// This trait provides a common vocabulary for structures, such as sparse
+/// matrices, in which a coordinate might have no associated value. It is
+/// implemented for all [matrices](MatrixByValue), for which it returns
+/// [`get_entry`](MatrixByValue::get_entry).
+///
+/// ```rust
+/// use value_traits::adapters::matrix::RowMajor;
+/// use value_traits::matrices::CoordByValue;
+///
+/// fn trace<C: CoordByValue<Value = i32>>(c: &C, n: usize) -> i32 {
+///     (0..n).filter_map(|i| c.get_value((i, i))).sum()
+/// }
+///
+/// let m = RowMajor::new([1, 2, 3, 4], 2);
+/// assert_eq!(trace(&m, 2), 5);
+/// ```
+pub trait CoordByValue {
+    /// The type of the values.
+    type Value;
+
+    /// Returns the value at the given coordinates, given as a pair formed by
+    /// a row and a column, or `None` if there is no value at the
+    /// coordinates.
+    fn get_value(&self, coord: (usize, usize)) -> Option<Self::Value>;
+}
+
+impl<M: MatrixByValue + ?Sized> CoordByValue for M {
+    type Value = M::Value;
+
+    #[inline]
+    fn get_value(&self, (row, col): (usize, usize)) -> Option<Self::Value> {
+        self.get_entry(row, col)
+    }
+}
+
+macro_rules! impl_line_view {
+    ($view:ident, $iter:ident, $field:ident, $len:ident, $what:literal, ($m:ident, $i:ident) => $entry:expr) => {
+        #[doc = concat!("A by-value slice view of a ", $what, " of a [`MatrixByValue`].")]
+        ///
+        #[doc = concat!("See [`MatrixByValue::", stringify!($field), "`].")]
+        #[derive(Debug)]
+        pub struct $view<'a, M: ?Sized> {
+            matrix: &'a M,
+            $field: usize,
+        }
+
+        impl<M: ?Sized> Clone for $view<'_, M> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<M: ?Sized> Copy for $view<'_, M> {}
+
+        impl<M: MatrixByValue + ?Sized> SliceByValue for $view<'_, M> {
+            type Value = M::Value;
+
+            #[inline]
+            fn len(&self) -> usize {
+                self.matrix.$len()
+            }
+
+            #[inline]
+            unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+                let $m = self.matrix;
+                let $i = index;
+                let $field = self.$field;
+                // SAFETY: the line is within bounds by construction, and
+                // index is within bounds
+                unsafe { $entry }
+            }
+        }
+
+        #[doc = concat!("An iterator on the ", $what, "s of a [`MatrixByValue`].")]
+        ///
+        #[doc = concat!("See [`MatrixByValue::", stringify!($field), "s`].")]
+        #[derive(Debug)]
+        pub struct $iter<'a, M: ?Sized> {
+            matrix: &'a M,
+            range: Range<usize>,
+        }
+
+        impl<M: ?Sized> Clone for $iter<'_, M> {
+            fn clone(&self) -> Self {
+                Self {
+                    matrix: self.matrix,
+                    range: self.range.clone(),
+                }
+            }
+        }
+
+        impl<'a, M: MatrixByValue + ?Sized> Iterator for $iter<'a, M> {
+            type Item = $view<'a, M>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.range.next().map(|$field| $view {
+                    matrix: self.matrix,
+                    $field,
+                })
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.range.nth(n).map(|$field| $view {
+                    matrix: self.matrix,
+                    $field,
+                })
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.range.size_hint()
+            }
+        }
+
+        impl<M: MatrixByValue + ?Sized> DoubleEndedIterator for $iter<'_, M> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.range.next_back().map(|$field| $view {
+                    matrix: self.matrix,
+                    $field,
+                })
+            }
+        }
+
+        impl<M: MatrixByValue + ?Sized> ExactSizeIterator for $iter<'_, M> {}
+
+        impl<M: MatrixByValue + ?Sized> FusedIterator for $iter<'_, M> {}
+    };
+}
+
+impl_line_view!(RowView, Rows, row, num_cols, "row", (m, i) => m.get_entry_unchecked(row, i));
+impl_line_view!(ColView, Cols, col, num_rows, "column", (m, i) => m.get_entry_unchecked(i, col));
@@ -6,5 +6,11 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
+pub mod gat;
 pub mod iter;
+pub mod jagged;
+pub mod matrices;
 pub mod slices;
+pub mod strings;
+pub mod total;
+pub mod vecs;
@@ -99,11 +99,16 @@
 //! }
 //! ```
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 use core::ops::{
-    Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
 };
 
-use crate::{ImplBound, Ref};
+use crate::{
+    ImplBound, Ref,
+    panics::{assert_dest, assert_index, assert_range},
+};
 
 /// Error type returned when [`try_chunks_mut`](SliceByValueMut::try_chunks_mut)
 /// is not supported by a type.
@@ -121,22 +126,57 @@ impl core::fmt::Display for ChunksMutNotSupported {
 
 impl core::error::Error for ChunksMutNotSupported {}
 
-#[inline(always)]
-fn assert_index(index: usize, len: usize) {
-    assert!(
-        index < len,
-        "index out of bounds: the len is {len} but the index is {index}",
-    );
+/// Error type returned by checked constructors when a range is not within
+/// the bounds of a slice.
+///
+/// The error records the bounds of the offending range and the length of the
+/// slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidRange {
+    start: Bound<usize>,
+    end: Bound<usize>,
+    len: usize,
+}
+
+impl InvalidRange {
+    /// Creates a new error for the given range and slice length.
+    pub fn new(range: &impl ComposeRange, len: usize) -> Self {
+        Self {
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+            len,
+        }
+    }
+
+    /// Returns the bounds of the offending range.
+    pub fn bounds(&self) -> (Bound<usize>, Bound<usize>) {
+        (self.start, self.end)
+    }
+
+    /// Returns the length of the slice.
+    pub fn slice_len(&self) -> usize {
+        self.len
+    }
 }
 
-#[inline(always)]
-fn assert_range(range: &impl ComposeRange, len: usize) {
-    assert!(
-        range.is_valid(len),
-        "range {range:?} out of range for slice of length {len}",
-    );
+impl core::fmt::Display for InvalidRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.start {
+            Bound::Included(start) => write!(f, "range {start}")?,
+            Bound::Excluded(start) => write!(f, "range ({start}")?,
+            Bound::Unbounded => write!(f, "range ")?,
+        }
+        match self.end {
+            Bound::Included(end) => write!(f, "..={end}")?,
+            Bound::Excluded(end) => write!(f, "..{end}")?,
+            Bound::Unbounded => write!(f, "..")?,
+        }
+        write!(f, " out of range for slice of length {}", self.len)
+    }
 }
 
+impl core::error::Error for InvalidRange {}
+
 /// Read-only by-value slice trait.
 ///
 /// The only methods that must be implemented are
@@ -220,6 +260,459 @@ impl<S: SliceByValue + ?Sized> SliceByValue for &mut S {
     }
 }
 
+/// Extension methods returning iterators over groups of values of a
+/// [`SliceByValue`].
+///
+/// These methods are not part of [`SliceByValue`] so that the latter is dyn
+/// compatible. The trait is implemented for all types implementing
+/// [`SliceByValue`], including unsized ones.
+pub trait SliceByValueExt: SliceByValue {
+    /// Returns an iterator over consecutive, non-overlapping arrays of `N`
+    /// values, starting at the beginning of the slice.
+    ///
+    /// This is the by-value analogue of [`slice::chunks_exact`] with a
+    /// length known at compile time: if the length of the slice is not a
+    /// multiple of `N`, the last values are not returned by the iterator, and
+    /// their range is available from
+    /// [`remainder`](ArrayChunksValue::remainder).
+    ///
+    /// ```rust
+    /// use value_traits::slices::SliceByValueExt;
+    ///
+    /// let v = [1, 2, 3, 4, 5, 6, 7];
+    /// let mut chunks = v.array_chunks_value::<3>();
+    /// assert_eq!(chunks.next(), Some([1, 2, 3]));
+    /// assert_eq!(chunks.next(), Some([4, 5, 6]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), 6..7);
+    /// ```
+    ///
+    /// Using this method with `N` equal to zero is a compile-time error.
+    fn array_chunks_value<const N: usize>(&self) -> ArrayChunksValue<'_, Self, N> {
+        const { assert!(N != 0, "chunk size must be nonzero") };
+        let len = self.len();
+        ArrayChunksValue {
+            slice: self,
+            range: 0..len / N,
+            len,
+        }
+    }
+
+    /// Returns an iterator over all overlapping windows of `N` consecutive
+    /// values.
+    ///
+    /// This is the by-value analogue of [`slice::windows`] with a length
+    /// known at compile time: the iterator returns arrays of values, and if
+    /// the slice is shorter than `N` it returns nothing. See also
+    /// [`pairs_value`](SliceByValueExt::pairs_value).
+    ///
+    /// ```rust
+    /// use value_traits::slices::SliceByValueExt;
+    ///
+    /// let v = [1, 2, 3, 4];
+    /// let mut windows = v.tuple_windows_value::<3>();
+    /// assert_eq!(windows.len(), 2);
+    /// assert_eq!(windows.next(), Some([1, 2, 3]));
+    /// assert_eq!(windows.next(), Some([2, 3, 4]));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    ///
+    /// Using this method with `N` equal to zero is a compile-time error.
+    fn tuple_windows_value<const N: usize>(&self) -> WindowsValue<'_, Self, N> {
+        const { assert!(N != 0, "window size must be nonzero") };
+        WindowsValue {
+            slice: self,
+            range: 0..(self.len() + 1).saturating_sub(N),
+        }
+    }
+
+    /// Returns an iterator over all pairs of consecutive values.
+    ///
+    /// ```rust
+    /// use value_traits::slices::SliceByValueExt;
+    ///
+    /// let v = [1, 4, 9, 16];
+    /// let deltas: Vec<_> = v.pairs_value().map(|(a, b)| b - a).collect();
+    /// assert_eq!(deltas, [3, 5, 7]);
+    /// ```
+    fn pairs_value(&self) -> PairsValue<'_, Self> {
+        PairsValue(self.tuple_windows_value::<2>())
+    }
+}
+
+impl<S: SliceByValue + ?Sized> SliceByValueExt for S {}
+
+/// An iterator over consecutive, non-overlapping arrays of values of a
+/// [`SliceByValue`].
+///
+/// See [`SliceByValueExt::array_chunks_value`].
+#[derive(Debug)]
+pub struct ArrayChunksValue<'a, S: ?Sized, const N: usize> {
+    slice: &'a S,
+    /// The range of chunk indices still to be returned.
+    range: Range<usize>,
+    len: usize,
+}
+
+impl<S: ?Sized, const N: usize> Clone for ArrayChunksValue<'_, S, N> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            range: self.range.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> ArrayChunksValue<'_, S, N> {
+    /// Returns the range of the values at the end of the slice that are not
+    /// returned by the iterator, because they do not fill an array.
+    ///
+    /// The range has length smaller than `N`.
+    #[inline]
+    pub fn remainder(&self) -> Range<usize> {
+        self.len - self.len % N..self.len
+    }
+
+    #[inline(always)]
+    fn chunk(&self, chunk: usize) -> [S::Value; N] {
+        let start = chunk * N;
+        // SAFETY: chunk is smaller than len / N, so all indices are within
+        // bounds
+        core::array::from_fn(|i| unsafe { self.slice.get_value_unchecked(start + i) })
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> Iterator for ArrayChunksValue<'_, S, N> {
+    type Item = [S::Value; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|chunk| self.chunk(chunk))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|chunk| self.chunk(chunk))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> DoubleEndedIterator for ArrayChunksValue<'_, S, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|chunk| self.chunk(chunk))
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> ExactSizeIterator for ArrayChunksValue<'_, S, N> {}
+
+impl<S: SliceByValue + ?Sized, const N: usize> core::iter::FusedIterator
+    for ArrayChunksValue<'_, S, N>
+{
+}
+
+/// An iterator over overlapping windows of consecutive values of a
+/// [`SliceByValue`].
+///
+/// See [`SliceByValueExt::tuple_windows_value`].
+#[derive(Debug)]
+pub struct WindowsValue<'a, S: ?Sized, const N: usize> {
+    slice: &'a S,
+    /// The range of starting indices of the windows still to be returned.
+    range: Range<usize>,
+}
+
+impl<S: ?Sized, const N: usize> Clone for WindowsValue<'_, S, N> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            range: self.range.clone(),
+        }
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> WindowsValue<'_, S, N> {
+    #[inline(always)]
+    fn window(&self, start: usize) -> [S::Value; N] {
+        // SAFETY: start is smaller than len + 1 - N, so all indices are
+        // within bounds
+        core::array::from_fn(|i| unsafe { self.slice.get_value_unchecked(start + i) })
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> Iterator for WindowsValue<'_, S, N> {
+    type Item = [S::Value; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|start| self.window(start))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|start| self.window(start))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> DoubleEndedIterator for WindowsValue<'_, S, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|start| self.window(start))
+    }
+}
+
+impl<S: SliceByValue + ?Sized, const N: usize> ExactSizeIterator for WindowsValue<'_, S, N> {}
+
+impl<S: SliceByValue + ?Sized, const N: usize> core::iter::FusedIterator
+    for WindowsValue<'_, S, N>
+{
+}
+
+/// An iterator over pairs of consecutive values of a [`SliceByValue`].
+///
+/// See [`SliceByValueExt::pairs_value`].
+#[derive(Debug)]
+pub struct PairsValue<'a, S: ?Sized>(WindowsValue<'a, S, 2>);
+
+impl<S: ?Sized> Clone for PairsValue<'_, S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<S: SliceByValue + ?Sized> Iterator for PairsValue<'_, S> {
+    type Item = (S::Value, S::Value);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|[a, b]| (a, b))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(|[a, b]| (a, b))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<S: SliceByValue + ?Sized> DoubleEndedIterator for PairsValue<'_, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|[a, b]| (a, b))
+    }
+}
+
+impl<S: SliceByValue + ?Sized> ExactSizeIterator for PairsValue<'_, S> {}
+
+impl<S: SliceByValue + ?Sized> core::iter::FusedIterator for PairsValue<'_, S> {}
+
+/// Fallible read-only by-value slice trait.
+///
+/// This trait is the fallible analogue of [`SliceByValue`], for backends in
+/// which access can fail, such as file-backed or remote slices:
+/// [`try_get_value`](TrySliceByValueGet::try_get_value) returns `Ok(None)`
+/// if the index is out of bounds, and an error if the value could not be
+/// retrieved.
+///
+/// All types implementing [`SliceByValue`] implement this trait with
+/// [`Infallible`](core::convert::Infallible) as error type, so algorithms
+/// written against this trait work on both kinds of backends:
+///
+/// ```rust
+/// use value_traits::slices::TrySliceByValueGet;
+///
+/// fn try_first_two<S: TrySliceByValueGet>(s: &S) -> Result<Option<(S::Value, S::Value)>, S::Error> {
+///     Ok(s.try_get_value(0)?.zip(s.try_get_value(1)?))
+/// }
+///
+/// assert_eq!(try_first_two(&[1, 2, 3]), Ok(Some((1, 2))));
+/// assert_eq!(try_first_two(&[1]), Ok(None));
+/// ```
+pub trait TrySliceByValueGet {
+    /// The type of the values in the slice.
+    type Value;
+    /// The type of the errors returned when a value cannot be retrieved.
+    type Error;
+
+    /// Returns the value at the given index, `Ok(None)` if the index is out
+    /// of bounds, or an error if the value could not be retrieved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Error`](TrySliceByValueGet::Error) if the
+    /// value could not be retrieved.
+    fn try_get_value(&self, index: usize) -> Result<Option<Self::Value>, Self::Error>;
+}
+
+impl<S: SliceByValue + ?Sized> TrySliceByValueGet for S {
+    type Value = S::Value;
+    type Error = core::convert::Infallible;
+
+    #[inline]
+    fn try_get_value(&self, index: usize) -> Result<Option<Self::Value>, Self::Error> {
+        Ok(self.get_value(index))
+    }
+}
+
+/// By-value slice trait writing values into a caller-provided destination.
+///
+/// When values have heap-allocated parts, such as [`String`] or `Vec<u8>`,
+/// returning a fresh value at each access is often the dominant cost of a
+/// scan. The methods of this trait overwrite instead an existing value,
+/// making it possible to reuse its capacity.
+///
+/// The default implementation of
+/// [`get_value_into_unchecked`](SliceByValueGetInto::get_value_into_unchecked)
+/// simply assigns the result of
+/// [`get_value_unchecked`](SliceByValue::get_value_unchecked); the
+/// implementations for slices and vectors of [cloneable](Clone) types use
+/// [`Clone::clone_from`].
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use value_traits::slices::SliceByValueGetInto;
+///
+/// let v = vec![vec![1, 2, 3], vec![4]];
+/// let mut out = Vec::with_capacity(10);
+/// v.get_value_into(1, &mut out);
+/// assert_eq!(out, [4]);
+/// assert!(out.capacity() >= 10);
+/// # }
+/// ```
+pub trait SliceByValueGetInto: SliceByValue {
+    /// Overwrites `out` with the value at the given index without bounds
+    /// checks.
+    ///
+    /// For a safe alternative see
+    /// [`get_value_into`](SliceByValueGetInto::get_value_into).
+    ///
+    /// # Safety
+    ///
+    /// The index must be within bounds.
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+        // SAFETY: index is within bounds
+        *out = unsafe { self.get_value_unchecked(index) };
+    }
+
+    /// Overwrites `out` with the value at the given index.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+        assert_index(index, self.len());
+        // SAFETY: index is within bounds
+        unsafe { self.get_value_into_unchecked(index, out) }
+    }
+}
+
+impl<S: SliceByValueGetInto + ?Sized> SliceByValueGetInto for &S {
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+        unsafe { (**self).get_value_into_unchecked(index, out) }
+    }
+    fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+        (**self).get_value_into(index, out)
+    }
+}
+
+impl<S: SliceByValueGetInto + ?Sized> SliceByValueGetInto for &mut S {
+    unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+        unsafe { (**self).get_value_into_unchecked(index, out) }
+    }
+    fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+        (**self).get_value_into(index, out)
+    }
+}
+
+/// A marker trait for by-value slices whose length is known at compile time.
+///
+/// Implementors promise that [`len`](SliceByValue::len) returns `N`. The
+/// provided methods take the index as a const parameter: indices out of
+/// bounds are compile-time errors, and since the methods delegate to
+/// [`index_value`](SliceByValue::index_value),
+/// [`set_value`](SliceByValueMut::set_value), and
+/// [`replace_value`](SliceByValueMut::replace_value), their bounds checks
+/// are elided by the compiler once [`len`](SliceByValue::len) is inlined.
+/// This makes it possible to write generic code on statically-sized
+/// records, such as tuples and arrays, without runtime checks:
+///
+/// ```rust
+/// use value_traits::slices::FixedLenSliceByValue;
+///
+/// fn area<S: FixedLenSliceByValue<4, Value = u32>>(rect: &S) -> u32 {
+///     // Fields are x0, y0, x1, y1
+///     (rect.get_value_const::<2>() - rect.get_value_const::<0>())
+///         * (rect.get_value_const::<3>() - rect.get_value_const::<1>())
+/// }
+///
+/// assert_eq!(area(&[1, 1, 4, 3]), 6);
+/// assert_eq!(area(&(0, 0, 2, 2)), 4);
+/// ```
+///
+/// ```compile_fail
+/// use value_traits::slices::FixedLenSliceByValue;
+///
+/// [1, 2, 3].get_value_const::<3>();
+/// ```
+///
+/// Note that [`len`](SliceByValue::len) might be called by the provided
+/// methods: an implementation breaking the promise causes panics, but not
+/// undefined behavior.
+pub trait FixedLenSliceByValue<const N: usize>: SliceByValue {
+    /// Returns the value at index `I`.
+    ///
+    /// Using this method with `I` not smaller than `N` is a compile-time
+    /// error.
+    #[inline(always)]
+    fn get_value_const<const I: usize>(&self) -> Self::Value {
+        const { assert!(I < N, "index out of bounds") };
+        self.index_value(I)
+    }
+
+    /// Sets the value at index `I`.
+    ///
+    /// Using this method with `I` not smaller than `N` is a compile-time
+    /// error.
+    #[inline(always)]
+    fn set_value_const<const I: usize>(&mut self, value: Self::Value)
+    where
+        Self: SliceByValueMut,
+    {
+        const { assert!(I < N, "index out of bounds") };
+        self.set_value(I, value);
+    }
+
+    /// Sets the value at index `I`, returning the previous value.
+    ///
+    /// Using this method with `I` not smaller than `N` is a compile-time
+    /// error.
+    #[inline(always)]
+    fn replace_value_const<const I: usize>(&mut self, value: Self::Value) -> Self::Value
+    where
+        Self: SliceByValueMut,
+    {
+        const { assert!(I < N, "index out of bounds") };
+        self.replace_value(I, value)
+    }
+}
+
+impl<S: FixedLenSliceByValue<N> + ?Sized, const N: usize> FixedLenSliceByValue<N> for &S {}
+impl<S: FixedLenSliceByValue<N> + ?Sized, const N: usize> FixedLenSliceByValue<N> for &mut S {}
+
 /// Mutable by-value slice trait providing setting and replacement methods.
 ///
 /// This trait provides both [`set_value`](SliceByValueMut::set_value) (for setting
@@ -283,6 +776,121 @@ pub trait SliceByValueMut: SliceByValue {
         unsafe { self.replace_value_unchecked(index, value) }
     }
 
+    /// Replaces the value at the given index with the default value and
+    /// returns the previous value.
+    ///
+    /// This is the by-value analogue of [`core::mem::take`].
+    ///
+    /// ```
+    /// use value_traits::slices::SliceByValueMut;
+    /// let mut vec = vec![1, 2, 3];
+    ///
+    /// assert_eq!(vec.take_value(1), 2);
+    /// assert_eq!(vec, [1, 0, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    fn take_value(&mut self, index: usize) -> Self::Value
+    where
+        Self::Value: Default,
+    {
+        self.replace_value(index, Self::Value::default())
+    }
+
+    /// Replaces the value at the given index with the result of applying a
+    /// function to it, and returns the new value, without doing bounds
+    /// checking.
+    ///
+    /// For a safe alternative see
+    /// [`update_value`](SliceByValueMut::update_value).
+    ///
+    /// The returned value is a clone of the result of the function, so the
+    /// slice is accessed just once for reading and once for writing.
+    ///
+    /// # Safety
+    ///
+    /// The index must be within bounds.
+    unsafe fn update_value_unchecked<F>(&mut self, index: usize, f: F) -> Self::Value
+    where
+        Self::Value: Clone,
+        F: FnOnce(Self::Value) -> Self::Value,
+    {
+        unsafe {
+            let value = f(self.get_value_unchecked(index));
+            self.set_value_unchecked(index, value.clone());
+            value
+        }
+    }
+
+    /// Replaces the value at the given index with the result of applying a
+    /// function to it, and returns the new value.
+    ///
+    /// Bounds are checked just once. See
+    /// [`update_value_unchecked`](SliceByValueMut::update_value_unchecked)
+    /// for details.
+    ///
+    /// ```
+    /// use value_traits::slices::SliceByValueMut;
+    /// let mut vec = vec![1, 2, 3];
+    ///
+    /// assert_eq!(vec.update_value(1, |x| x * 10), 20);
+    /// assert_eq!(vec, [1, 20, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    fn update_value<F>(&mut self, index: usize, f: F) -> Self::Value
+    where
+        Self::Value: Clone,
+        F: FnOnce(Self::Value) -> Self::Value,
+    {
+        assert_index(index, self.len());
+        // SAFETY: index is within bounds
+        unsafe { self.update_value_unchecked(index, f) }
+    }
+
+    /// Replaces the value at the given index with the result of applying a
+    /// fallible function to it, and returns the new value.
+    ///
+    /// The slice is modified only if the function succeeds. As in the case
+    /// of [`update_value`](SliceByValueMut::update_value), the returned value
+    /// is a clone of the result of the function.
+    ///
+    /// ```
+    /// use value_traits::slices::SliceByValueMut;
+    /// let mut counters = vec![0_u8, 254];
+    ///
+    /// let incr = |x: u8| x.checked_add(1).ok_or("overflow");
+    /// assert_eq!(counters.try_update_value(1, incr), Ok(255));
+    /// assert_eq!(counters.try_update_value(1, incr), Err("overflow"));
+    /// assert_eq!(counters, [0, 255]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error returned by the function, in which case the slice
+    /// is not modified.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    fn try_update_value<F, E>(&mut self, index: usize, f: F) -> Result<Self::Value, E>
+    where
+        Self::Value: Clone,
+        F: FnOnce(Self::Value) -> Result<Self::Value, E>,
+    {
+        assert_index(index, self.len());
+        // SAFETY: index is within bounds
+        unsafe {
+            let value = f(self.get_value_unchecked(index))?;
+            self.set_value_unchecked(index, value.clone());
+            Ok(value)
+        }
+    }
+
     /// Copy part of the content of the slice to another slice.
     ///
     /// At most `len` elements are copied, compatibly with the elements
@@ -313,6 +921,93 @@ pub trait SliceByValueMut: SliceByValue {
         }
     }
 
+    /// Copies a range of elements of the slice to another position in the
+    /// same slice.
+    ///
+    /// `src` is the range to copy, and `dst` is the index of the first
+    /// element of the destination. The source and the destination ranges may
+    /// overlap. This is the by-value analogue of [`slice::copy_within`].
+    ///
+    /// ```
+    /// use value_traits::slices::SliceByValueMut;
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// vec.copy_within(1..4, 2);
+    /// assert_eq!(vec, [1, 2, 2, 3, 4]);
+    /// vec.copy_within(2.., 0);
+    /// assert_eq!(vec, [2, 3, 4, 3, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `src` is not within bounds, or if `dst` is
+    /// greater than the length of the slice minus the length of `src`.
+    ///
+    /// # Implementation Notes
+    ///
+    /// The default implementation copies the elements one by one, forward or
+    /// backward depending on the relative position of the source and of the
+    /// destination, so that every element is read before being overwritten.
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        let len = self.len();
+        assert_range(&src, len);
+        let src = src.compose(0..len);
+        let count = src.len();
+        assert_dest(dst, count, len);
+        if dst <= src.start {
+            for i in 0..count {
+                // SAFETY: both ranges are within bounds
+                unsafe {
+                    let value = self.get_value_unchecked(src.start + i);
+                    self.set_value_unchecked(dst + i, value);
+                }
+            }
+        } else {
+            for i in (0..count).rev() {
+                // SAFETY: both ranges are within bounds
+                unsafe {
+                    let value = self.get_value_unchecked(src.start + i);
+                    self.set_value_unchecked(dst + i, value);
+                }
+            }
+        }
+    }
+
+    /// Swaps the value at index `i` of this slice with the value at index `j`
+    /// of another slice.
+    ///
+    /// The other slice can be of a different type, provided that it has the
+    /// same type of values: for example, values can be moved between a
+    /// compressed slice and an uncompressed one. Each value is read and
+    /// written exactly once, so no value is cloned.
+    ///
+    /// ```
+    /// use value_traits::slices::SliceByValueMut;
+    /// let mut a = vec![1, 2, 3];
+    /// let mut b = [4, 5];
+    ///
+    /// a.swap_with(0, &mut b, 1);
+    /// assert_eq!(a, [5, 2, 3]);
+    /// assert_eq!(b, [4, 1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `i` is not within the bounds of this slice,
+    /// or if `j` is not within the bounds of `other`.
+    fn swap_with<O>(&mut self, i: usize, other: &mut O, j: usize)
+    where
+        O: SliceByValueMut<Value = Self::Value> + ?Sized,
+    {
+        assert_index(i, self.len());
+        assert_index(j, other.len());
+        // SAFETY: both indices are within bounds
+        unsafe {
+            let value = other.replace_value_unchecked(j, self.get_value_unchecked(i));
+            self.set_value_unchecked(i, value);
+        }
+    }
+
     /// Applies a function to all elements of the slice in place without
     /// checks.
     ///
@@ -380,6 +1075,77 @@ pub trait SliceByValueMut: SliceByValue {
         }
     }
 
+    /// Applies a function to all elements of the slice in place, processing
+    /// the slice in chunks of given size.
+    ///
+    /// If [`try_chunks_mut`](SliceByValueMut::try_chunks_mut) succeeds, the
+    /// function is applied using [`apply_in_place`](SliceByValueMut::apply_in_place)
+    /// on each chunk in turn, which might improve locality, for example, for
+    /// packed representations; otherwise, this method falls back to
+    /// [`apply_in_place`](SliceByValueMut::apply_in_place) on the whole
+    /// slice. In both cases, the function is applied from the first element
+    /// to the last.
+    ///
+    /// ```
+    /// use value_traits::slices::SliceByValueMut;
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// vec.apply_in_place_chunked(2, |x| x * 10);
+    /// assert_eq!(vec, [10, 20, 30, 40, 50]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method might panic if `chunk_size` is zero, depending on the
+    /// implementation of [`try_chunks_mut`](SliceByValueMut::try_chunks_mut).
+    fn apply_in_place_chunked<F>(&mut self, chunk_size: usize, mut f: F)
+    where
+        F: FnMut(Self::Value) -> Self::Value,
+    {
+        if let Ok(chunks) = self.try_chunks_mut(chunk_size) {
+            for mut chunk in chunks {
+                chunk.apply_in_place(&mut f);
+            }
+            return;
+        }
+        self.apply_in_place(f);
+    }
+
+    /// Reads, transforms, and possibly writes back each element in a range.
+    ///
+    /// For each index `i` in `range`, the function is called with `i` and
+    /// the value at index `i`; if it returns `Some(new_value)`, `new_value`
+    /// is written at index `i`, otherwise the element is left unchanged.
+    /// Elements are processed from the first to the last.
+    ///
+    /// This method is the by-value analogous of iterating mutably on a slice.
+    ///
+    /// ```
+    /// use value_traits::slices::SliceByValueMut;
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// // Double the odd elements in the range 1..4
+    /// vec.for_each_value_mut(1..4, |_, x| (x % 2 == 1).then_some(x * 2));
+    /// assert_eq!(vec, [1, 2, 6, 4, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn for_each_value_mut<R: ComposeRange, F>(&mut self, range: R, mut f: F)
+    where
+        F: FnMut(usize, Self::Value) -> Option<Self::Value>,
+    {
+        assert_range(&range, self.len());
+        for idx in range.compose(0..self.len()) {
+            // SAFETY: range is within bounds
+            let value = unsafe { self.get_value_unchecked(idx) };
+            if let Some(new_value) = f(idx, value) {
+                unsafe { self.set_value_unchecked(idx, new_value) };
+            }
+        }
+    }
+
     /// The iterator type returned by [`try_chunks_mut`](SliceByValueMut::try_chunks_mut).
     type ChunksMut<'a>: Iterator<Item: SliceByValueMut<Value = Self::Value>>
     where
@@ -439,6 +1205,15 @@ impl<S: SliceByValueMut + ?Sized> SliceByValueMut for &mut S {
     unsafe fn replace_value_unchecked(&mut self, index: usize, value: Self::Value) -> Self::Value {
         unsafe { (**self).replace_value_unchecked(index, value) }
     }
+    fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+        (**self).copy_within(src, dst);
+    }
+    fn for_each_value_mut<R: ComposeRange, F>(&mut self, range: R, f: F)
+    where
+        F: FnMut(usize, Self::Value) -> Option<Self::Value>,
+    {
+        (**self).for_each_value_mut(range, f);
+    }
 
     type ChunksMut<'a>
         = S::ChunksMut<'a>
@@ -544,6 +1319,66 @@ impl ComposeRange for RangeToInclusive<usize> {
     }
 }
 
+/// Folds a chain of nested range selections into a single range.
+///
+/// The first range selects a view of a slice of length `len`, each following
+/// range selects a view of the previous view, and the result is the range of
+/// the slice covered by the last view. This is the range that would be
+/// covered by the subslice obtained by applying
+/// [`index_subslice`](SliceByValueSubsliceRange::index_subslice) repeatedly.
+/// If `ranges` is empty, the result is `0..len`.
+///
+/// ```rust
+/// use value_traits::slices::compose_all;
+///
+/// assert_eq!(compose_all([2..8, 1..4, 1..2], 10), 4..5);
+/// assert_eq!(compose_all([..], 10), 0..10);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if a range is not within the bounds of the view
+/// it is applied to. See [`try_compose_all`] for a non-panicking version.
+pub fn compose_all<R: ComposeRange>(
+    ranges: impl IntoIterator<Item = R>,
+    len: usize,
+) -> Range<usize> {
+    ranges.into_iter().fold(0..len, |base, range| {
+        assert_range(&range, base.len());
+        range.compose(base)
+    })
+}
+
+/// Folds a chain of nested range selections into a single range, checking
+/// bounds.
+///
+/// See [`compose_all`].
+///
+/// ```rust
+/// use value_traits::slices::try_compose_all;
+///
+/// assert_eq!(try_compose_all([2..8, 1..4], 10), Ok(3..6));
+/// let err = try_compose_all([2..8, 1..7], 10).unwrap_err();
+/// assert_eq!(err.to_string(), "range 1..7 out of range for slice of length 6");
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`InvalidRange`] error, whose slice length is the length of the
+/// view the offending range was applied to, if a range is not within bounds.
+pub fn try_compose_all<R: ComposeRange>(
+    ranges: impl IntoIterator<Item = R>,
+    len: usize,
+) -> Result<Range<usize>, InvalidRange> {
+    ranges.into_iter().try_fold(0..len, |base, range| {
+        if range.is_valid(base.len()) {
+            Ok(range.compose(base))
+        } else {
+            Err(InvalidRange::new(&range, base.len()))
+        }
+    })
+}
+
 /// A GAT-like trait specifying the subslice type.
 ///
 /// It implicitly restricts the lifetime `'a` used in `SliceByValueRange` to be
@@ -623,6 +1458,66 @@ pub trait SliceByValueSubsliceRange<R: ComposeRange>: for<'a> SliceByValueSubsli
             None
         }
     }
+
+    /// Returns the subslices delimited by the given ranges, or `None` if any
+    /// of the ranges is not within bounds.
+    ///
+    /// All ranges are validated before any subslice is created.
+    /// Implementations can override this method to amortize validation or
+    /// setup costs over the whole batch, as when extracting many windows
+    /// (e.g., posting lists) from a single base slice.
+    ///
+    /// This method is available only if the `alloc` feature is enabled.
+    ///
+    /// ```rust
+    /// use value_traits::slices::*;
+    ///
+    /// let v = vec![1, 2, 3, 4, 5, 6];
+    /// let windows = v.get_subslices(&[0..2, 3..6]).unwrap();
+    /// assert_eq!(windows, [&[1, 2][..], &[4, 5, 6][..]]);
+    /// assert!(v.get_subslices(&[0..2, 5..7]).is_none());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn get_subslices(&self, ranges: &[R]) -> Option<Vec<Subslice<'_, Self>>>
+    where
+        R: Clone,
+    {
+        let len = self.len();
+        if !ranges.iter().all(|range| range.is_valid(len)) {
+            return None;
+        }
+        Some(
+            ranges
+                .iter()
+                // SAFETY: all ranges are within bounds
+                .map(|range| unsafe { self.get_subslice_unchecked(range.clone()) })
+                .collect(),
+        )
+    }
+
+    /// Returns the subslices delimited by the given ranges.
+    ///
+    /// This method is available only if the `alloc` feature is enabled.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if any of the ranges is not within bounds; in
+    /// that case, no subslice is created.
+    #[cfg(feature = "alloc")]
+    fn index_subslices(&self, ranges: &[R]) -> Vec<Subslice<'_, Self>>
+    where
+        R: Clone,
+    {
+        let len = self.len();
+        for range in ranges {
+            assert_range(range, len);
+        }
+        ranges
+            .iter()
+            // SAFETY: all ranges are within bounds
+            .map(|range| unsafe { self.get_subslice_unchecked(range.clone()) })
+            .collect()
+    }
 }
 
 impl<R: ComposeRange, S: SliceByValueSubsliceRange<R> + ?Sized> SliceByValueSubsliceRange<R>
@@ -637,6 +1532,20 @@ impl<R: ComposeRange, S: SliceByValueSubsliceRange<R> + ?Sized> SliceByValueSubs
     unsafe fn get_subslice_unchecked(&self, range: R) -> Subslice<'_, Self> {
         unsafe { (**self).get_subslice_unchecked(range) }
     }
+    #[cfg(feature = "alloc")]
+    fn get_subslices(&self, ranges: &[R]) -> Option<Vec<Subslice<'_, Self>>>
+    where
+        R: Clone,
+    {
+        (**self).get_subslices(ranges)
+    }
+    #[cfg(feature = "alloc")]
+    fn index_subslices(&self, ranges: &[R]) -> Vec<Subslice<'_, Self>>
+    where
+        R: Clone,
+    {
+        (**self).index_subslices(ranges)
+    }
 }
 impl<R: ComposeRange, S: SliceByValueSubsliceRange<R> + ?Sized> SliceByValueSubsliceRange<R>
     for &mut S
@@ -650,6 +1559,20 @@ impl<R: ComposeRange, S: SliceByValueSubsliceRange<R> + ?Sized> SliceByValueSubs
     unsafe fn get_subslice_unchecked(&self, range: R) -> Subslice<'_, Self> {
         unsafe { (**self).get_subslice_unchecked(range) }
     }
+    #[cfg(feature = "alloc")]
+    fn get_subslices(&self, ranges: &[R]) -> Option<Vec<Subslice<'_, Self>>>
+    where
+        R: Clone,
+    {
+        (**self).get_subslices(ranges)
+    }
+    #[cfg(feature = "alloc")]
+    fn index_subslices(&self, ranges: &[R]) -> Vec<Subslice<'_, Self>>
+    where
+        R: Clone,
+    {
+        (**self).index_subslices(ranges)
+    }
 }
 
 /// A GAT-like trait specifying the mutable subslice type.
@@ -872,11 +1795,46 @@ where
 {
 }
 
+/// A trait for consuming an owned by-value slice and obtaining an owned
+/// subslice.
+///
+/// Differently from [`SliceByValueSubslice`], the returned subslice does not
+/// borrow from the original slice, so it can be handed out independently, for
+/// example to other threads, without lifetime constraints. Implementations
+/// reuse the storage of the original slice whenever possible: for example,
+/// vectors are truncated and drained in place, and reference-counted
+/// pointers are wrapped in an [`OwnedSubslice`](crate::adapters::owned::OwnedSubslice).
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use value_traits::slices::*;
+///
+/// let v = vec![0, 1, 2, 3, 4, 5];
+/// let w = v.into_subslice(2..5);
+/// assert_eq!(w, vec![2, 3, 4]);
+/// # }
+/// ```
+pub trait IntoSubslice: SliceByValue + Sized {
+    /// The type of the owned subslice.
+    type IntoSubslice: SliceByValue<Value = Self::Value>;
+
+    /// Consumes the slice and returns an owned subslice.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn into_subslice<R: ComposeRange>(self, range: R) -> Self::IntoSubslice;
+}
+
 #[cfg(feature = "alloc")]
 mod alloc_impls {
     use super::*;
     #[cfg(all(feature = "alloc", not(feature = "std")))]
-    use alloc::boxed::Box;
+    use alloc::borrow::Cow;
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    use alloc::{borrow::ToOwned, boxed::Box};
+    #[cfg(feature = "std")]
+    use std::borrow::Cow;
 
     impl<S: SliceByValue + ?Sized> SliceByValue for Box<S> {
         type Value = S::Value;
@@ -897,6 +1855,15 @@ mod alloc_impls {
         }
     }
 
+    impl<S: SliceByValueGetInto + ?Sized> SliceByValueGetInto for Box<S> {
+        unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+            unsafe { (**self).get_value_into_unchecked(index, out) }
+        }
+        fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+            (**self).get_value_into(index, out)
+        }
+    }
+
     impl<S: SliceByValueMut + ?Sized> SliceByValueMut for Box<S> {
         fn set_value(&mut self, index: usize, value: Self::Value) {
             (**self).set_value(index, value);
@@ -916,6 +1883,15 @@ mod alloc_impls {
         ) -> Self::Value {
             unsafe { (**self).replace_value_unchecked(index, value) }
         }
+        fn copy_within<R: ComposeRange>(&mut self, src: R, dst: usize) {
+            (**self).copy_within(src, dst);
+        }
+        fn for_each_value_mut<R: ComposeRange, F>(&mut self, range: R, f: F)
+        where
+            F: FnMut(usize, Self::Value) -> Option<Self::Value>,
+        {
+            (**self).for_each_value_mut(range, f);
+        }
 
         type ChunksMut<'a>
             = S::ChunksMut<'a>
@@ -932,9 +1908,45 @@ mod alloc_impls {
         }
     }
 
+    impl<S: FixedLenSliceByValue<N> + ?Sized, const N: usize> FixedLenSliceByValue<N> for Box<S> {}
+
     impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> SliceByValueSubsliceGat<'a> for Box<S> {
         type Subslice = S::Subslice;
     }
+
+    impl<S: SliceByValue + ToOwned + ?Sized> SliceByValue for Cow<'_, S> {
+        type Value = S::Value;
+
+        #[inline]
+        fn len(&self) -> usize {
+            (**self).len()
+        }
+
+        fn get_value(&self, index: usize) -> Option<Self::Value> {
+            (**self).get_value(index)
+        }
+        fn index_value(&self, index: usize) -> Self::Value {
+            (**self).index_value(index)
+        }
+        unsafe fn get_value_unchecked(&self, index: usize) -> Self::Value {
+            unsafe { (**self).get_value_unchecked(index) }
+        }
+    }
+
+    impl<S: SliceByValueGetInto + ToOwned + ?Sized> SliceByValueGetInto for Cow<'_, S> {
+        unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+            unsafe { (**self).get_value_into_unchecked(index, out) }
+        }
+        fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+            (**self).get_value_into(index, out)
+        }
+    }
+
+    impl<'a, S: SliceByValueSubsliceGat<'a> + ToOwned + ?Sized> SliceByValueSubsliceGat<'a>
+        for Cow<'_, S>
+    {
+        type Subslice = S::Subslice;
+    }
     impl<'a, S: SliceByValueSubsliceGatMut<'a> + ?Sized> SliceByValueSubsliceGatMut<'a> for Box<S> {
         type SubsliceMut = S::SubsliceMut;
     }
@@ -958,6 +1970,16 @@ mod alloc_impls {
                 unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
                     unsafe { (**self).get_subslice_unchecked(index) }
                 }
+
+                #[inline]
+                fn get_subslices(&self, ranges: &[$range]) -> Option<Vec<Subslice<'_, Self>>> {
+                    (**self).get_subslices(ranges)
+                }
+
+                #[inline]
+                fn index_subslices(&self, ranges: &[$range]) -> Vec<Subslice<'_, Self>> {
+                    (**self).index_subslices(ranges)
+                }
             }
             impl<S: SliceByValueSubsliceRangeMut<$range> + ?Sized>
                 SliceByValueSubsliceRangeMut<$range> for Box<S>
@@ -983,6 +2005,46 @@ mod alloc_impls {
         };
     }
 
+    macro_rules! impl_range_cow {
+        ($range:ty) => {
+            impl<S: SliceByValueSubsliceRange<$range> + ToOwned + ?Sized>
+                SliceByValueSubsliceRange<$range> for Cow<'_, S>
+            {
+                #[inline]
+                fn get_subslice(&self, index: $range) -> Option<Subslice<'_, Self>> {
+                    (**self).get_subslice(index)
+                }
+
+                #[inline]
+                fn index_subslice(&self, index: $range) -> Subslice<'_, Self> {
+                    (**self).index_subslice(index)
+                }
+
+                #[inline]
+                unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
+                    unsafe { (**self).get_subslice_unchecked(index) }
+                }
+
+                #[inline]
+                fn get_subslices(&self, ranges: &[$range]) -> Option<Vec<Subslice<'_, Self>>> {
+                    (**self).get_subslices(ranges)
+                }
+
+                #[inline]
+                fn index_subslices(&self, ranges: &[$range]) -> Vec<Subslice<'_, Self>> {
+                    (**self).index_subslices(ranges)
+                }
+            }
+        };
+    }
+
+    impl_range_cow!(RangeFull);
+    impl_range_cow!(RangeFrom<usize>);
+    impl_range_cow!(RangeTo<usize>);
+    impl_range_cow!(Range<usize>);
+    impl_range_cow!(RangeInclusive<usize>);
+    impl_range_cow!(RangeToInclusive<usize>);
+
     impl_range_alloc!(RangeFull);
     impl_range_alloc!(RangeFrom<usize>);
     impl_range_alloc!(RangeTo<usize>);
@@ -1014,6 +2076,17 @@ mod std_impls {
             unsafe { (**self).get_value_unchecked(index) }
         }
     }
+    impl<S: SliceByValueGetInto + ?Sized> SliceByValueGetInto for Arc<S> {
+        unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+            unsafe { (**self).get_value_into_unchecked(index, out) }
+        }
+        fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+            (**self).get_value_into(index, out)
+        }
+    }
+
+    impl<S: FixedLenSliceByValue<N> + ?Sized, const N: usize> FixedLenSliceByValue<N> for Arc<S> {}
+
     impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> SliceByValueSubsliceGat<'a> for Arc<S> {
         type Subslice = S::Subslice;
     }
@@ -1037,6 +2110,17 @@ mod std_impls {
         }
     }
 
+    impl<S: SliceByValueGetInto + ?Sized> SliceByValueGetInto for Rc<S> {
+        unsafe fn get_value_into_unchecked(&self, index: usize, out: &mut Self::Value) {
+            unsafe { (**self).get_value_into_unchecked(index, out) }
+        }
+        fn get_value_into(&self, index: usize, out: &mut Self::Value) {
+            (**self).get_value_into(index, out)
+        }
+    }
+
+    impl<S: FixedLenSliceByValue<N> + ?Sized, const N: usize> FixedLenSliceByValue<N> for Rc<S> {}
+
     impl<'a, S: SliceByValueSubsliceGat<'a> + ?Sized> SliceByValueSubsliceGat<'a> for Rc<S> {
         type Subslice = S::Subslice;
     }
@@ -1060,6 +2144,16 @@ mod std_impls {
                 unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
                     unsafe { (**self).get_subslice_unchecked(index) }
                 }
+
+                #[inline]
+                fn get_subslices(&self, ranges: &[$range]) -> Option<Vec<Subslice<'_, Self>>> {
+                    (**self).get_subslices(ranges)
+                }
+
+                #[inline]
+                fn index_subslices(&self, ranges: &[$range]) -> Vec<Subslice<'_, Self>> {
+                    (**self).index_subslices(ranges)
+                }
             }
             impl<S: SliceByValueSubsliceRange<$range> + ?Sized> SliceByValueSubsliceRange<$range>
                 for Arc<S>
@@ -1078,6 +2172,16 @@ mod std_impls {
                 unsafe fn get_subslice_unchecked(&self, index: $range) -> Subslice<'_, Self> {
                     unsafe { (**self).get_subslice_unchecked(index) }
                 }
+
+                #[inline]
+                fn get_subslices(&self, ranges: &[$range]) -> Option<Vec<Subslice<'_, Self>>> {
+                    (**self).get_subslices(ranges)
+                }
+
+                #[inline]
+                fn index_subslices(&self, ranges: &[$range]) -> Vec<Subslice<'_, Self>> {
+                    (**self).index_subslices(ranges)
+                }
             }
         };
     }
@@ -0,0 +1,143 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Traits for by-value sequences of strings.
+//!
+//! Dictionaries of terms are often stored in compressed form (e.g., using
+//! [front coding](crate::adapters::front_coded)), so their elements can be
+//! returned only by value. [`SliceOfStringsByValue`] extends
+//! [`SliceByValue`] with methods writing a string into a caller-provided
+//! buffer, so that scanning a dictionary does not require an allocation per
+//! access.
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::slices::SliceByValue;
+//! use value_traits::strings::SliceOfStringsByValue;
+//!
+//! let terms = vec!["alpha".to_string(), "beta".to_string()];
+//! let mut buffer = String::new();
+//! terms.get_str_into(1, &mut buffer);
+//! assert_eq!(buffer, "beta");
+//! assert_eq!(&*terms.get_boxed_str(0), "alpha");
+//! # }
+//! ```
+//!
+//! This module is available only if the `alloc` feature is enabled.
+
+#![cfg(feature = "alloc")]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{panics::assert_index, slices::SliceByValue};
+
+/// A by-value slice of strings that can write its elements into a
+/// caller-provided buffer.
+///
+/// The only method that must be implemented is
+/// [`get_str_into_unchecked`](SliceOfStringsByValue::get_str_into_unchecked),
+/// which should reuse the capacity of the buffer.
+pub trait SliceOfStringsByValue: SliceByValue<Value = String> {
+    /// Replaces the content of `out` with the string at the given index
+    /// without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The index must be within bounds.
+    unsafe fn get_str_into_unchecked(&self, index: usize, out: &mut String);
+
+    /// Replaces the content of `out` with the string at the given index.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    fn get_str_into(&self, index: usize, out: &mut String) {
+        assert_index(index, self.len());
+        // SAFETY: index is within bounds
+        unsafe { self.get_str_into_unchecked(index, out) }
+    }
+
+    /// Returns the string at the given index as a boxed string slice.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    fn get_boxed_str(&self, index: usize) -> Box<str> {
+        self.index_value(index).into_boxed_str()
+    }
+}
+
+impl SliceOfStringsByValue for [String] {
+    #[inline]
+    unsafe fn get_str_into_unchecked(&self, index: usize, out: &mut String) {
+        out.clear();
+        // SAFETY: index is within bounds
+        out.push_str(unsafe { self.get_unchecked(index) });
+    }
+
+    #[inline]
+    fn get_boxed_str(&self, index: usize) -> Box<str> {
+        self[index].as_str().into()
+    }
+}
+
+impl<const N: usize> SliceOfStringsByValue for [String; N] {
+    #[inline]
+    unsafe fn get_str_into_unchecked(&self, index: usize, out: &mut String) {
+        // SAFETY: index is within bounds
+        unsafe { self.as_slice().get_str_into_unchecked(index, out) }
+    }
+
+    #[inline]
+    fn get_boxed_str(&self, index: usize) -> Box<str> {
+        self.as_slice().get_boxed_str(index)
+    }
+}
+
+impl SliceOfStringsByValue for Vec<String> {
+    #[inline]
+    unsafe fn get_str_into_unchecked(&self, index: usize, out: &mut String) {
+        // SAFETY: index is within bounds
+        unsafe { self.as_slice().get_str_into_unchecked(index, out) }
+    }
+
+    #[inline]
+    fn get_boxed_str(&self, index: usize) -> Box<str> {
+        self.as_slice().get_boxed_str(index)
+    }
+}
+
+macro_rules! impl_strings_delegation {
+    ($ty:ty) => {
+        impl<S: SliceOfStringsByValue + ?Sized> SliceOfStringsByValue for $ty {
+            #[inline]
+            unsafe fn get_str_into_unchecked(&self, index: usize, out: &mut String) {
+                unsafe { (**self).get_str_into_unchecked(index, out) }
+            }
+
+            #[inline]
+            fn get_str_into(&self, index: usize, out: &mut String) {
+                (**self).get_str_into(index, out)
+            }
+
+            #[inline]
+            fn get_boxed_str(&self, index: usize) -> Box<str> {
+                (**self).get_boxed_str(index)
+            }
+        }
+    };
+}
+
+impl_strings_delegation!(&S);
+impl_strings_delegation!(&mut S);
+impl_strings_delegation!(Box<S>);
+#[cfg(feature = "std")]
+impl_strings_delegation!(std::sync::Arc<S>);
+#[cfg(feature = "std")]
+impl_strings_delegation!(std::rc::Rc<S>);
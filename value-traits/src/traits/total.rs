@@ -0,0 +1,198 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Total (i.e., non-panicking) views of by-value slices.
+//!
+//! The traits in [`slices`](crate::slices) and [`iter`](crate::iter) provide,
+//! like standard slices, both checked methods returning an [`Option`] and
+//! methods that panic on out-of-bounds accesses. In contexts in which
+//! reachable panics are forbidden, such as safety-critical builds, generic
+//! code can use instead the traits in this module, whose methods never panic
+//! because of bounds checks: failures are always reported by returning an
+//! [`Option`] or a [`Result`].
+//!
+//! [`SliceByValueTotal`] and [`SliceByValueTotalMut`] are implemented for all
+//! types implementing [`SliceByValue`] and [`SliceByValueMut`],
+//! respectively, and cannot be implemented otherwise. Since some of their
+//! methods have the same name of methods of [`SliceByValue`], this module
+//! should be imported in place of [`slices`](crate::slices):
+//!
+//! ```rust
+//! use value_traits::total::*;
+//!
+//! fn first_or_zero<S: SliceByValueTotal<Value = u32>>(s: &S) -> u32 {
+//!     s.get_value(0).unwrap_or(0)
+//! }
+//!
+//! fn set_last<S: SliceByValueTotalMut<Value = u32>>(s: &mut S, value: u32) -> bool {
+//!     s.len()
+//!         .checked_sub(1)
+//!         .is_some_and(|last| s.try_set_value(last, value).is_ok())
+//! }
+//!
+//! let mut a = [1, 2, 3];
+//! assert_eq!(first_or_zero(&a), 1);
+//! assert!(set_last(&mut a, 0));
+//! assert_eq!(a, [1, 2, 0]);
+//! assert!(!set_last(&mut [0_u32; 0], 0));
+//! ```
+//!
+//! Note that the absence of panics depends also on the implementations of
+//! the underlying traits: the implementations in this crate do not panic
+//! when accessed through these traits.
+
+use crate::{
+    iter::{IterFrom, IterateByValueFrom},
+    slices::{
+        ComposeRange, SliceByValue, SliceByValueMut, SliceByValueSubsliceRange,
+        SliceByValueSubsliceRangeMut, Subslice, SubsliceMut,
+    },
+};
+
+mod sealed {
+    pub trait Sealed {}
+    impl<S: crate::slices::SliceByValue + ?Sized> Sealed for S {}
+}
+
+/// A total view of a [`SliceByValue`].
+///
+/// See the [module documentation](self).
+pub trait SliceByValueTotal: sealed::Sealed {
+    /// The type of the values in the slice.
+    type Value;
+
+    /// See [`slice::len`].
+    fn len(&self) -> usize;
+
+    /// See [`slice::is_empty`].
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value at the given index, or `None` if the index is out
+    /// of bounds.
+    ///
+    /// See [`SliceByValue::get_value`].
+    fn get_value(&self, index: usize) -> Option<Self::Value>;
+
+    /// Returns a subslice, or `None` if the range is out of bounds.
+    ///
+    /// See [`SliceByValueSubsliceRange::get_subslice`].
+    fn get_subslice<R: ComposeRange>(&self, range: R) -> Option<Subslice<'_, Self>>
+    where
+        Self: SliceByValueSubsliceRange<R>;
+
+    /// Returns an iterator on values starting at the given position, or
+    /// `None` if the position is greater than the length of the slice.
+    ///
+    /// See [`IterateByValueFrom::iter_value_from`].
+    fn try_iter_value_from(&self, from: usize) -> Option<IterFrom<'_, Self>>
+    where
+        Self: IterateByValueFrom;
+}
+
+impl<S: SliceByValue + ?Sized> SliceByValueTotal for S {
+    type Value = S::Value;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        SliceByValue::len(self)
+    }
+
+    #[inline]
+    fn get_value(&self, index: usize) -> Option<Self::Value> {
+        SliceByValue::get_value(self, index)
+    }
+
+    #[inline]
+    fn get_subslice<R: ComposeRange>(&self, range: R) -> Option<Subslice<'_, Self>>
+    where
+        Self: SliceByValueSubsliceRange<R>,
+    {
+        SliceByValueSubsliceRange::get_subslice(self, range)
+    }
+
+    #[inline]
+    fn try_iter_value_from(&self, from: usize) -> Option<IterFrom<'_, Self>>
+    where
+        Self: IterateByValueFrom,
+    {
+        (from <= SliceByValue::len(self)).then(|| self.iter_value_from(from))
+    }
+}
+
+/// A total view of a [`SliceByValueMut`].
+///
+/// See the [module documentation](self).
+pub trait SliceByValueTotalMut: SliceByValueTotal {
+    /// Sets the value at the given index to the given value, or returns the
+    /// value if the index is out of bounds.
+    ///
+    /// See [`SliceByValueMut::set_value`].
+    ///
+    /// # Errors
+    ///
+    /// If the index is out of bounds, the value is returned as an error.
+    fn try_set_value(&mut self, index: usize, value: Self::Value) -> Result<(), Self::Value>;
+
+    /// Sets the value at the given index to the given value and returns the
+    /// previous value, or returns the value if the index is out of bounds.
+    ///
+    /// See [`SliceByValueMut::replace_value`].
+    ///
+    /// # Errors
+    ///
+    /// If the index is out of bounds, the value is returned as an error.
+    fn try_replace_value(
+        &mut self,
+        index: usize,
+        value: Self::Value,
+    ) -> Result<Self::Value, Self::Value>;
+
+    /// Returns a mutable subslice, or `None` if the range is out of bounds.
+    ///
+    /// See [`SliceByValueSubsliceRangeMut::get_subslice_mut`].
+    fn get_subslice_mut<R: ComposeRange>(&mut self, range: R) -> Option<SubsliceMut<'_, Self>>
+    where
+        Self: SliceByValueSubsliceRangeMut<R>;
+}
+
+impl<S: SliceByValueMut + ?Sized> SliceByValueTotalMut for S {
+    #[inline]
+    fn try_set_value(&mut self, index: usize, value: Self::Value) -> Result<(), Self::Value> {
+        if index < SliceByValue::len(self) {
+            // SAFETY: index is within bounds
+            unsafe { self.set_value_unchecked(index, value) };
+            Ok(())
+        } else {
+            Err(value)
+        }
+    }
+
+    #[inline]
+    fn try_replace_value(
+        &mut self,
+        index: usize,
+        value: Self::Value,
+    ) -> Result<Self::Value, Self::Value> {
+        if index < SliceByValue::len(self) {
+            // SAFETY: index is within bounds
+            Ok(unsafe { self.replace_value_unchecked(index, value) })
+        } else {
+            Err(value)
+        }
+    }
+
+    #[inline]
+    fn get_subslice_mut<R: ComposeRange>(&mut self, range: R) -> Option<SubsliceMut<'_, Self>>
+    where
+        Self: SliceByValueSubsliceRangeMut<R>,
+    {
+        SliceByValueSubsliceRangeMut::get_subslice_mut(self, range)
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Traits for growable by-value containers.
+//!
+//! [`VecByValue`] extends [`SliceByValueMut`] with methods changing the
+//! length of the container, analogous to those of [`Vec`]. It is implemented
+//! for [`Vec`] (if the `alloc` feature is enabled) and for
+//! [`VecDeque`](std::collections::VecDeque) (if the `std` feature is
+//! enabled).
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")] {
+//! use value_traits::vecs::VecByValue;
+//!
+//! let mut v = vec![1, 1, 2, 3, 3, 3, 4];
+//! v.dedup_values();
+//! assert_eq!(v, [1, 2, 3, 4]);
+//! v.retain_values(|x| x % 2 == 0);
+//! assert_eq!(v, [2, 4]);
+//! assert_eq!(v.swap_remove_value(0), 2);
+//! assert_eq!(v, [4]);
+//! # }
+//! ```
+
+use crate::{
+    panics::{assert_from, assert_index},
+    slices::{ComposeRange, SliceByValueMut},
+};
+
+/// A growable by-value container.
+///
+/// Besides the methods changing the length of the container that must be
+/// implemented, this trait provides in-place compaction methods, such as
+/// [`retain_values`](VecByValue::retain_values) and
+/// [`dedup_values`](VecByValue::dedup_values), whose default implementations
+/// use a read cursor and a write cursor on the content of the container,
+/// followed by a call to [`truncate_values`](VecByValue::truncate_values).
+///
+/// Methods returning types that depend on the implementation, such as
+/// [`drain_values`](VecByValue::drain_values), have no default
+/// implementation.
+pub trait VecByValue: SliceByValueMut {
+    /// Appends a value at the end of the container.
+    ///
+    /// See [`Vec::push`].
+    fn push_value(&mut self, value: Self::Value);
+
+    /// Appends `count` copies of a value at the end of the container.
+    ///
+    /// Run-aware containers, such as run-length-encoded or sparse ones, can
+    /// override this method to store the run directly, so that generic
+    /// builders can fill them efficiently. The default implementation calls
+    /// [`push_value`](VecByValue::push_value) `count` times.
+    fn append_run(&mut self, value: Self::Value, count: usize)
+    where
+        Self::Value: Clone,
+    {
+        if count == 0 {
+            return;
+        }
+        for _ in 1..count {
+            self.push_value(value.clone());
+        }
+        self.push_value(value);
+    }
+
+    /// Removes the last value of the container and returns it, or returns
+    /// `None` if the container is empty.
+    ///
+    /// See [`Vec::pop`].
+    fn pop_value(&mut self) -> Option<Self::Value>;
+
+    /// Shortens the container, keeping the first `len` values.
+    ///
+    /// If `len` is greater than or equal to the current length of the
+    /// container, this method has no effect.
+    ///
+    /// See [`Vec::truncate`].
+    fn truncate_values(&mut self, len: usize);
+
+    /// Removes all values from the container.
+    ///
+    /// See [`Vec::clear`].
+    fn clear_values(&mut self) {
+        self.truncate_values(0);
+    }
+
+    /// Removes the value at the given index and returns it, replacing it
+    /// with the last value of the container.
+    ///
+    /// See [`Vec::swap_remove`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is not within bounds.
+    fn swap_remove_value(&mut self, index: usize) -> Self::Value {
+        let len = self.len();
+        assert_index(index, len);
+        // SAFETY: the container is not empty
+        let last = unsafe { self.pop_value().unwrap_unchecked() };
+        if index == len - 1 {
+            last
+        } else {
+            // SAFETY: index is within bounds
+            unsafe { self.replace_value_unchecked(index, last) }
+        }
+    }
+
+    /// Removes the values in the given range, returning them as a by-value
+    /// iterator.
+    ///
+    /// As in the case of [`Vec::drain`], the values are removed even if the
+    /// iterator is not fully consumed.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the range is not within bounds.
+    fn drain_values<R: ComposeRange>(&mut self, range: R)
+    -> impl Iterator<Item = Self::Value> + '_;
+
+    /// Splits the container in two at the given index, returning a new
+    /// container with the values from `at` onwards.
+    ///
+    /// The default implementation pushes the values from `at` onwards into
+    /// a [default](Default) container, and then truncates this container.
+    ///
+    /// See [`Vec::split_off`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `at` is greater than the length of the
+    /// container.
+    fn split_off_values(&mut self, at: usize) -> Self
+    where
+        Self: Sized + Default,
+    {
+        let len = self.len();
+        assert_from(at, len);
+        let mut other = Self::default();
+        for i in at..len {
+            // SAFETY: i is within bounds
+            other.push_value(unsafe { self.get_value_unchecked(i) });
+        }
+        self.truncate_values(at);
+        other
+    }
+
+    /// Retains only the values specified by the predicate, preserving their
+    /// order.
+    ///
+    /// See [`Vec::retain`].
+    fn retain_values<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Self::Value) -> bool,
+    {
+        let len = self.len();
+        let mut write = 0;
+        for read in 0..len {
+            // SAFETY: read is within bounds
+            let value = unsafe { self.get_value_unchecked(read) };
+            if f(&value) {
+                if write != read {
+                    // SAFETY: write < read is within bounds
+                    unsafe { self.set_value_unchecked(write, value) };
+                }
+                write += 1;
+            }
+        }
+        self.truncate_values(write);
+    }
+
+    /// Removes consecutive repeated values.
+    ///
+    /// See [`Vec::dedup`].
+    fn dedup_values(&mut self)
+    where
+        Self::Value: PartialEq,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+        let mut write = 1;
+        for read in 1..len {
+            // SAFETY: read and write - 1 < read are within bounds
+            let value = unsafe { self.get_value_unchecked(read) };
+            if value != unsafe { self.get_value_unchecked(write - 1) } {
+                if write != read {
+                    // SAFETY: write < read is within bounds
+                    unsafe { self.set_value_unchecked(write, value) };
+                }
+                write += 1;
+            }
+        }
+        self.truncate_values(write);
+    }
+}
+
+impl<V: VecByValue + ?Sized> VecByValue for &mut V {
+    #[inline]
+    fn push_value(&mut self, value: Self::Value) {
+        (**self).push_value(value)
+    }
+    #[inline]
+    fn append_run(&mut self, value: Self::Value, count: usize)
+    where
+        Self::Value: Clone,
+    {
+        (**self).append_run(value, count)
+    }
+    #[inline]
+    fn pop_value(&mut self) -> Option<Self::Value> {
+        (**self).pop_value()
+    }
+    #[inline]
+    fn truncate_values(&mut self, len: usize) {
+        (**self).truncate_values(len)
+    }
+    #[inline]
+    fn clear_values(&mut self) {
+        (**self).clear_values()
+    }
+    #[inline]
+    fn swap_remove_value(&mut self, index: usize) -> Self::Value {
+        (**self).swap_remove_value(index)
+    }
+    #[inline]
+    fn drain_values<R: ComposeRange>(
+        &mut self,
+        range: R,
+    ) -> impl Iterator<Item = Self::Value> + '_ {
+        (**self).drain_values(range)
+    }
+    #[inline]
+    fn retain_values<F>(&mut self, f: F)
+    where
+        F: FnMut(&Self::Value) -> bool,
+    {
+        (**self).retain_values(f)
+    }
+    #[inline]
+    fn dedup_values(&mut self)
+    where
+        Self::Value: PartialEq,
+    {
+        (**self).dedup_values()
+    }
+}
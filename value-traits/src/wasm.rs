@@ -0,0 +1,180 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Export of numeric by-value slices to JavaScript typed arrays.
+//!
+//! Setting the elements of a [`js_sys`] typed array one by one crosses the
+//! boundary between WebAssembly and JavaScript once per value. The functions
+//! in this module read instead the values of a by-value slice into a buffer
+//! of [`BLOCK_SIZE`] values in WebAssembly memory, and copy each block to
+//! the typed array with a single call, so web front-ends can stream values
+//! out of compressed structures efficiently:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "wasm")] {
+//! use value_traits::adapters::functional::FnSlice;
+//! use value_traits::wasm::*;
+//!
+//! let squares = FnSlice::new(100_000, |i| (i * i) as u32);
+//! // A Uint32Array with all values
+//! let array = to_typed_array(&squares);
+//! assert_eq!(array.length(), 100_000);
+//! // Uint32Arrays of at most 4096 values each
+//! for chunk in typed_array_chunks(&squares, 4096) {
+//!     assert!(chunk.length() <= 4096);
+//! }
+//! # }
+//! ```
+//!
+//! Values must implement [`TypedArrayValue`], which is implemented for all
+//! primitive numeric types with a corresponding typed array.
+//!
+//! This module is available only if the `wasm` feature is enabled. The
+//! functions can be called only on WebAssembly targets.
+
+#![cfg(feature = "wasm")]
+
+use core::ops::Range;
+
+use crate::{
+    panics::assert_range,
+    slices::{ComposeRange, SliceByValue},
+};
+
+/// The number of values copied to a typed array with a single call.
+pub const BLOCK_SIZE: usize = 1024;
+
+/// A primitive numeric type with a corresponding JavaScript typed array.
+pub trait TypedArrayValue: Copy + Default {
+    /// The typed array type.
+    type Array;
+
+    /// Returns a new typed array of given length.
+    fn new_array(len: u32) -> Self::Array;
+
+    /// Copies `values` to the typed array starting at `offset`.
+    fn copy_to_array(array: &Self::Array, offset: u32, values: &[Self]);
+}
+
+macro_rules! impl_typed_array_value {
+    ($($ty:ty => $array:ident),* $(,)?) => {$(
+        impl TypedArrayValue for $ty {
+            type Array = js_sys::$array;
+
+            #[inline]
+            fn new_array(len: u32) -> Self::Array {
+                js_sys::$array::new_with_length(len)
+            }
+
+            #[inline]
+            fn copy_to_array(array: &Self::Array, offset: u32, values: &[Self]) {
+                array
+                    .subarray(offset, offset + values.len() as u32)
+                    .copy_from(values);
+            }
+        }
+    )*};
+}
+
+impl_typed_array_value! {
+    i8 => Int8Array,
+    i16 => Int16Array,
+    i32 => Int32Array,
+    i64 => BigInt64Array,
+    u8 => Uint8Array,
+    u16 => Uint16Array,
+    u32 => Uint32Array,
+    u64 => BigUint64Array,
+    f32 => Float32Array,
+    f64 => Float64Array,
+}
+
+/// Reads the values of `slice` in `range` into `array`, starting at
+/// `offset`, one block at a time.
+fn copy_blocks<S>(
+    slice: &S,
+    range: Range<usize>,
+    array: &<S::Value as TypedArrayValue>::Array,
+    offset: u32,
+) where
+    S: SliceByValue<Value: TypedArrayValue> + ?Sized,
+{
+    let mut buffer = [S::Value::default(); BLOCK_SIZE];
+    let mut offset = offset;
+    for start in range.clone().step_by(BLOCK_SIZE) {
+        let block = &mut buffer[..(range.end - start).min(BLOCK_SIZE)];
+        for (i, value) in block.iter_mut().enumerate() {
+            // SAFETY: start + i is within the range, which is within bounds
+            *value = unsafe { slice.get_value_unchecked(start + i) };
+        }
+        S::Value::copy_to_array(array, offset, block);
+        offset += block.len() as u32;
+    }
+}
+
+/// Returns a typed array containing the values of a by-value slice.
+///
+/// # Panics
+///
+/// This function will panic if the length of the slice does not fit a
+/// `u32`.
+pub fn to_typed_array<S>(slice: &S) -> <S::Value as TypedArrayValue>::Array
+where
+    S: SliceByValue<Value: TypedArrayValue> + ?Sized,
+{
+    to_typed_array_range(slice, ..)
+}
+
+/// Returns a typed array containing the values of a by-value slice in the
+/// given range.
+///
+/// # Panics
+///
+/// This function will panic if the range is not within bounds, or if its
+/// length does not fit a `u32`.
+pub fn to_typed_array_range<S>(
+    slice: &S,
+    range: impl ComposeRange,
+) -> <S::Value as TypedArrayValue>::Array
+where
+    S: SliceByValue<Value: TypedArrayValue> + ?Sized,
+{
+    assert_range(&range, slice.len());
+    let range = range.compose(0..slice.len());
+    let len = u32::try_from(range.len())
+        .unwrap_or_else(|_| panic!("typed arrays are limited to u32::MAX values"));
+    let array = S::Value::new_array(len);
+    copy_blocks(slice, range, &array, 0);
+    array
+}
+
+/// Returns an iterator over typed arrays containing consecutive chunks of
+/// `chunk_size` values of a by-value slice (the last chunk might be
+/// shorter).
+///
+/// # Panics
+///
+/// This function will panic if `chunk_size` is zero or does not fit a
+/// `u32`.
+pub fn typed_array_chunks<S>(
+    slice: &S,
+    chunk_size: usize,
+) -> impl Iterator<Item = <S::Value as TypedArrayValue>::Array> + '_
+where
+    S: SliceByValue<Value: TypedArrayValue> + ?Sized,
+{
+    assert!(chunk_size != 0, "chunk size must be positive");
+    assert!(
+        u32::try_from(chunk_size).is_ok(),
+        "typed arrays are limited to u32::MAX values"
+    );
+    let len = slice.len();
+    (0..len)
+        .step_by(chunk_size)
+        .map(move |start| to_typed_array_range(slice, start..(start + chunk_size).min(len)))
+}
@@ -0,0 +1,926 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+mod common;
+pub use common::*;
+
+#[cfg(feature = "std")]
+const EXPECTED: [i32; 5] = [1, 2, 3, 4, 5];
+
+#[test]
+#[cfg(feature = "std")]
+fn test_unique_arc() {
+    use std::sync::Arc;
+    use value_traits::adapters::unique::UniqueArc;
+
+    let x = UniqueArc::<[i32]>::try_from(<Arc<[i32]>>::from(EXPECTED)).unwrap();
+    generic_get(&x, &EXPECTED);
+    generic_slice(&x, &EXPECTED);
+    generic_iter(&x, &EXPECTED);
+    generic_mut(x);
+
+    let x = UniqueArc::<[i32]>::try_from(<Arc<[i32]>>::from(EXPECTED)).unwrap();
+    generic_slice_mut(x);
+
+    let shared = <Arc<[i32]>>::from(EXPECTED);
+    let _clone = shared.clone();
+    assert!(UniqueArc::try_from(shared).is_err());
+
+    let shared = Arc::new(EXPECTED.to_vec());
+    let clone = shared.clone();
+    let mut x = UniqueArc::make_unique(shared);
+    value_traits::slices::SliceByValueMut::set_value(&mut x, 0, 10);
+    value_traits::slices::SliceByValueMut::copy_within(&mut x, ..2, 3);
+    assert_eq!(*clone, EXPECTED.to_vec());
+    assert_eq!(*x.into_shared(), [10, 2, 3, 10, 2]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_unique_rc() {
+    use std::rc::Rc;
+    use value_traits::adapters::unique::UniqueRc;
+
+    let x = UniqueRc::new(EXPECTED.to_vec());
+    generic_get(&x, &EXPECTED);
+    generic_slice(&x, &EXPECTED);
+    generic_iter(&x, &EXPECTED);
+    generic_mut(x);
+
+    let x = UniqueRc::new(EXPECTED.to_vec());
+    generic_slice_mut(x);
+
+    let mut x = UniqueRc::new(EXPECTED.to_vec());
+    value_traits::slices::SliceByValueMut::copy_within(&mut x, 1..=3, 0);
+    value_traits::slices::SliceByValueMut::for_each_value_mut(&mut x, 3.., |_, v| Some(v * 10));
+    assert_eq!(*x.into_shared(), [2, 3, 4, 40, 50]);
+
+    let shared = Rc::new(EXPECTED.to_vec());
+    let _weak = Rc::downgrade(&shared);
+    assert!(UniqueRc::try_from(shared).is_err());
+}
+
+#[test]
+fn test_fn_slice() {
+    use value_traits::fn_slice;
+
+    let s = fn_slice!(5, |i| i as i32 + 1);
+    generic_get(s, &[1, 2, 3, 4, 5]);
+    generic_iter(&s, &[1, 2, 3, 4, 5]);
+    assert_eq!(format!("{s:?}"), "FnSlice { len: 5 }");
+
+    let empty = fn_slice!(0, |_| -> i32 { unreachable!() });
+    generic_get(empty, &[]);
+    generic_iter(&empty, &[]);
+}
+
+#[test]
+fn test_const_slice() {
+    use value_traits::const_slice;
+
+    let s = const_slice!(7; 4);
+    generic_get(s, &[7, 7, 7, 7]);
+    generic_iter(&s, &[7, 7, 7, 7]);
+    assert_eq!(
+        value_traits::iter::IterateByValueFrom::iter_value_from(&s, 10).count(),
+        0
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_subslice_cow() {
+    use value_traits::adapters::cow::SubsliceCow;
+    use value_traits::iter::*;
+    use value_traits::slices::*;
+
+    let v = vec![1, 2, 3, 4, 5];
+    let window = SubsliceCow::<Vec<i32>>::Borrowed(v.index_subslice(1..4));
+    generic_get(window.clone(), &[2, 3, 4]);
+    generic_iter(&window, &[2, 3, 4]);
+    assert_eq!(format!("{window:?}"), "Borrowed([2, 3, 4])");
+
+    let mut patched = window.clone();
+    patched.to_mut().push(6);
+    assert!(patched.is_owned());
+    generic_get(patched.clone(), &[2, 3, 4, 6]);
+    generic_iter(&patched, &[2, 3, 4, 6]);
+    let sub = patched.index_subslice(1..);
+    assert_eq!(sub.index_value(0), 3);
+    assert_eq!(sub.iter_value().collect::<Vec<_>>(), [3, 4, 6]);
+    assert_eq!(patched.iter_value_from(2).len(), 2);
+    assert_eq!(format!("{patched:?}"), "Owned([2, 3, 4, 6])");
+
+    assert!(window.is_borrowed());
+    assert_eq!(window.into_owned(), vec![2, 3, 4]);
+    assert_eq!(patched.into_owned(), vec![2, 3, 4, 6]);
+
+    generic_slice(SubsliceCow::<Vec<i32>>::Borrowed(v.index_subslice(..)), &v);
+    generic_slice(SubsliceCow::<Vec<i32>>::Owned(v.clone()), &v);
+}
+
+#[test]
+fn test_zip_with() {
+    use value_traits::adapters::zip::zip_with;
+    use value_traits::iter::*;
+    use value_traits::slices::*;
+
+    let a = [0, 1, 2, 3, 4];
+    let b = [1, 1, 1, 1, 1, 1, 1];
+    let z = zip_with(&a, &b, |x, y| x + y);
+    generic_get(z, &[1, 2, 3, 4, 5]);
+    generic_iter(&z, &[1, 2, 3, 4, 5]);
+    assert_eq!(z.iter_value().len(), 5);
+    assert_eq!(z.iter_value_from(3).len(), 2);
+
+    // Zipped slices can be zipped again
+    let w = zip_with(z, [2; 5], |x, y| x * y);
+    assert_eq!(w.iter_value().collect::<Vec<_>>(), [2, 4, 6, 8, 10]);
+    let (z, _) = w.into_inner();
+    assert_eq!(z.len(), 5);
+}
+
+#[test]
+fn test_subslice_of() {
+    use core::ops::Bound;
+    use value_traits::adapters::subslice::{SubsliceOf, SubsliceOfMut};
+    use value_traits::slices::*;
+
+    let mut data = [0, 1, 2, 3, 4, 5, 6];
+    let s = SubsliceOf::new(&data, 1..6).unwrap();
+    generic_get(s.clone(), &[1, 2, 3, 4, 5]);
+    generic_slice(s.clone(), &[1, 2, 3, 4, 5]);
+    assert_eq!(s.range(), 1..6);
+    assert_eq!(s.base(), &data);
+    let t = unsafe { SubsliceOf::new_unchecked(&data, 2..4) };
+    generic_get(t, &[2, 3]);
+    // Subslices are iterable, comparable and formatted as lists
+    generic_iter(&s, &[1, 2, 3, 4, 5]);
+    assert_eq!(s, [1, 2, 3, 4, 5]);
+    assert_eq!(s.iter_value_range(1..3).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(format!("{s:?}"), "[1, 2, 3, 4, 5]");
+
+    let err = SubsliceOf::new(&data, 3..=7).unwrap_err();
+    assert_eq!(err.slice_len(), 7);
+    assert_eq!(err.bounds(), (Bound::Included(3), Bound::Included(7)));
+    assert_eq!(
+        err.to_string(),
+        "range 3..=7 out of range for slice of length 7"
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 5..3;
+    assert!(SubsliceOf::new(&data, reversed).is_err());
+
+    let mut s = SubsliceOfMut::new(&mut data, 1..6).unwrap();
+    assert_eq!(s.range(), 1..6);
+    assert_eq!(s.base().len(), 7);
+    generic_mut(s.index_subslice_mut(..));
+    generic_slice_mut(SubsliceOfMut::new(&mut s, ..).unwrap());
+    s.copy_within(0..2, 3);
+    assert_eq!(s.as_subslice(), [1, 2, 3, 1, 2]);
+    assert_eq!(s.iter_value_to(2).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(data, [0, 1, 2, 3, 1, 2, 6]);
+
+    let err = SubsliceOfMut::new(&mut data, ..8).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "range ..8 out of range for slice of length 7"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_arc_values() {
+    use std::sync::Arc;
+    use value_traits::adapters::shared::ArcValues;
+
+    let values = ArcValues::from(Arc::new(EXPECTED.to_vec()));
+    generic_get(values.clone(), &EXPECTED);
+    assert_eq!(values.iter_value_shared().collect::<Vec<_>>(), EXPECTED);
+    assert_eq!(
+        values.iter_value_shared_from(2).rev().collect::<Vec<_>>(),
+        [5, 4, 3]
+    );
+    assert_eq!(values.iter_value_shared_from(5).len(), 0);
+
+    let mut iter = values.clone().into_iter_value_from(1);
+    let handle = std::thread::spawn(move || iter.nth(1));
+    assert_eq!(handle.join().unwrap(), Some(3));
+    assert_eq!(values.clone().into_iter().sum::<i32>(), 15);
+    assert_eq!(Arc::strong_count(&values.into_inner()), 1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn test_arc_values_from_out_of_bounds() {
+    use std::sync::Arc;
+    use value_traits::adapters::shared::ArcValues;
+
+    ArcValues::new(Arc::new(EXPECTED)).into_iter_value_from(6);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_snapshot() {
+    use std::sync::Arc;
+    use value_traits::adapters::shared::Snapshot;
+
+    let mut data = Arc::new(EXPECTED.to_vec());
+    let small = Snapshot::new(&data, 5);
+    let large = Snapshot::new(&data, 4);
+    assert!(!small.is_shared());
+    assert!(large.is_shared());
+    assert_eq!(Arc::strong_count(&data), 2);
+
+    Arc::make_mut(&mut data)[0] = 0;
+    generic_get(&small, &EXPECTED);
+    generic_get(&large, &EXPECTED);
+    assert_eq!(data[0], 0);
+    assert_eq!(small.into_iter_value().collect::<Vec<_>>(), EXPECTED);
+    assert_eq!(large.into_arc_values().iter_value_shared().len(), 5);
+}
+
+#[test]
+fn test_const_slice_const_access() {
+    use value_traits::adapters::functional::ConstSlice;
+    use value_traits::const_slice;
+    use value_traits::slices::SliceByValue;
+
+    const TABLE: ConstSlice<u16> = const_slice!(7; 3);
+    const FIRST: u16 = TABLE.index_value_const(0);
+    const MISSING: Option<u16> = TABLE.get_value_const(3);
+    static LEN: usize = TABLE.len();
+    assert_eq!(FIRST, 7);
+    assert_eq!(MISSING, None);
+    assert_eq!(LEN, 3);
+    assert!(!TABLE.is_empty());
+    assert_eq!(TABLE.get_value_const(2), SliceByValue::get_value(&TABLE, 2));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_lazy_slice() {
+    use core::cell::Cell;
+    use value_traits::adapters::lazy::LazySlice;
+    use value_traits::slices::SliceByValue;
+
+    let calls = Cell::new(0);
+    let mut lazy = LazySlice::new(5, |i| {
+        calls.set(calls.get() + 1);
+        i as i32 + 1
+    });
+    generic_get(&lazy, &[1, 2, 3, 4, 5]);
+    let after_first_pass = calls.get();
+    generic_get(&lazy, &[1, 2, 3, 4, 5]);
+    assert_eq!(calls.get(), after_first_pass);
+    assert_eq!(after_first_pass, 5);
+    assert!((0..5).all(|i| lazy.is_cached(i)));
+
+    lazy.clear();
+    assert!(!lazy.is_cached(3));
+    assert_eq!(lazy.get_value(3), Some(4));
+    assert_eq!(lazy.get_value(5), None);
+    assert_eq!(calls.get(), 6);
+
+    let empty = LazySlice::new(0, |_| 0_i32);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_buffered_slice() {
+    use core::cell::Cell;
+    use value_traits::adapters::buffered::BufferedSlice;
+    use value_traits::slices::SliceByValue;
+
+    let pulled = Cell::new(0);
+    let mut buffered = BufferedSlice::from_iter((1..=5).inspect(|_| pulled.set(pulled.get() + 1)));
+    assert!(buffered.is_empty());
+    assert_eq!(pulled.get(), 0);
+    assert_eq!(buffered.ensure(2), 2);
+    assert_eq!(pulled.get(), 2);
+    assert_eq!(buffered.get_value(2), None);
+    assert_eq!(buffered.ensure(1), 2);
+    assert_eq!(pulled.get(), 2);
+    assert!(!buffered.is_exhausted());
+    assert_eq!(buffered.ensure(5), 5);
+    generic_get(&buffered, &[1, 2, 3, 4, 5]);
+    generic_iter(&buffered, &[1, 2, 3, 4, 5]);
+    assert!(!buffered.is_exhausted());
+    assert_eq!(buffered.ensure(6), 5);
+    assert!(buffered.is_exhausted());
+    assert_eq!(buffered.as_slice(), [1, 2, 3, 4, 5]);
+
+    let mut rest = BufferedSlice::from_iter(0..10);
+    rest.ensure(3);
+    let (prefix, mut iter) = rest.into_parts();
+    assert_eq!(prefix, [0, 1, 2]);
+    assert_eq!(iter.next(), Some(3));
+
+    let mut all = BufferedSlice::from_iter(vec![7, 8]);
+    assert_eq!(all.ensure_all(), 2);
+    assert!(all.is_exhausted());
+    generic_get(&all, &[7, 8]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_paged_vec() {
+    use value_traits::adapters::paged::PagedVec;
+    use value_traits::iter::IterateByValueFrom;
+    use value_traits::slices::*;
+    use value_traits::vecs::VecByValue;
+
+    let mut v = PagedVec::with_page_size(2);
+    v.extend([1, 2, 3, 4, 5]);
+    assert_eq!(v.num_pages(), 3);
+    assert_eq!(v.page(2), Some(&[5][..]));
+    generic_get(&v, &[1, 2, 3, 4, 5]);
+    generic_slice(&v, &[1, 2, 3, 4, 5]);
+    generic_iter(&v, &[1, 2, 3, 4, 5]);
+    generic_mut(&mut v);
+    generic_slice_mut(&mut v);
+    generic_iter(&v, &[1, 2, 3, 4, 5]);
+    assert_eq!(v.iter_value_from(5).next(), None);
+
+    v.apply_in_place_chunked(2, |x| x * 10);
+    generic_get(&v, &[10, 20, 30, 40, 50]);
+    let err = v.try_chunks_mut(4).unwrap_err();
+    assert_eq!(err.to_string(), "chunk size 4 does not divide page size 2");
+    assert!(v.try_chunks_mut(0).is_err());
+    assert_eq!(v.try_chunks_mut(1).unwrap().count(), 5);
+
+    assert_eq!(v.pop_value(), Some(50));
+    assert_eq!(v.num_pages(), 2);
+    assert_eq!(v.drain_values(1..3).collect::<Vec<_>>(), [20, 30]);
+    generic_get(&v, &[10, 40]);
+    v.append_run(7, 3);
+    generic_get(&v, &[10, 40, 7, 7, 7]);
+    v.truncate_values(3);
+    assert_eq!(v.num_pages(), 2);
+    let w = v.split_off_values(1);
+    assert_eq!(w.page_size(), PagedVec::<i32>::new().page_size());
+    generic_get(&w, &[40, 7]);
+    v.clear_values();
+    assert_eq!(v.num_pages(), 0);
+    assert_eq!(v.pop_value(), None);
+
+    let big: PagedVec<i32> = (0..100_000).collect();
+    assert_eq!(big.num_pages(), 2);
+    assert_eq!(big.index_value(99_999), 99_999);
+
+    // Equality and hashing ignore the page size
+    let mut small = PagedVec::with_page_size(4);
+    small.extend(0..100_000);
+    assert_eq!(small, big);
+    let state = std::hash::RandomState::new();
+    assert_eq!(
+        std::hash::BuildHasher::hash_one(&state, &small),
+        std::hash::BuildHasher::hash_one(&state, &big)
+    );
+    small.set_value(0, 1);
+    assert_ne!(small, big);
+    small.pop_value();
+    assert_ne!(small, big);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_paged_vec_page_size() {
+    value_traits::adapters::paged::PagedVec::<i32>::with_page_size(3);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_paged_vec_iter_value_from_out_of_bounds() {
+    use value_traits::iter::IterateByValueFrom;
+
+    let v: value_traits::adapters::paged::PagedVec<i32> = (0..5).collect();
+    let _ = v.iter_value_from(6);
+}
+
+#[test]
+fn test_frozen() {
+    use value_traits::adapters::frozen::Frozen;
+
+    let mut a = [1, 2, 3, 4, 5];
+    let frozen = Frozen::new(&mut a);
+    generic_get(&frozen, &[1, 2, 3, 4, 5]);
+    generic_slice(&frozen, &[1, 2, 3, 4, 5]);
+    generic_iter(&frozen, &[1, 2, 3, 4, 5]);
+    assert_eq!(frozen.as_inner()[2], 3);
+    frozen.into_inner()[2] = 0;
+    generic_get(Frozen::new(a), &[1, 2, 0, 4, 5]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_write_once() {
+    use value_traits::adapters::write_once::WriteOnce;
+    use value_traits::slices::SliceByValue;
+
+    let mut w = WriteOnce::new([0; 130]);
+    assert_eq!(w.len(), 130);
+    assert!(!w.is_initialized());
+    for i in (0..130).rev() {
+        assert!(!w.is_written(i));
+        w.try_set_value(i, i as i32 + 1).unwrap();
+        assert!(w.is_written(i));
+    }
+    assert_eq!(w.num_written(), 130);
+    let err = w.try_set_value(64, 0).unwrap_err();
+    assert_eq!(err.index(), 64);
+    assert_eq!(
+        err.to_string(),
+        "the value at index 64 has already been set"
+    );
+    assert_eq!(w.get_value(64), Some(65));
+    assert_eq!(w.get_value(130), None);
+    let frozen = w.try_freeze().unwrap();
+    assert_eq!(frozen.index_value(129), 130);
+
+    let mut v = [0; 5];
+    let mut w = WriteOnce::new(&mut v);
+    w.try_set_value(1, 2).unwrap();
+    assert_eq!(w.get_value(0), None);
+    let mut w = w.try_freeze().unwrap_err();
+    for (i, x) in [(0, 1), (2, 3), (3, 4), (4, 5)] {
+        w.try_set_value(i, x).unwrap();
+    }
+    generic_get(w.try_freeze().unwrap(), &[1, 2, 3, 4, 5]);
+
+    let empty = WriteOnce::new([0_i32; 0]);
+    assert!(empty.is_empty());
+    assert!(empty.is_initialized());
+    assert_eq!(empty.into_inner(), [0_i32; 0]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_write_once_out_of_bounds() {
+    let mut w = value_traits::adapters::write_once::WriteOnce::new([0; 3]);
+    let _ = w.try_set_value(3, 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_journaled() {
+    use value_traits::adapters::journaled::Journaled;
+    use value_traits::slices::*;
+
+    let mut j = Journaled::new([1, 2, 3, 4, 5]);
+    generic_get(&j, &[1, 2, 3, 4, 5]);
+    generic_slice(&j, &[1, 2, 3, 4, 5]);
+    generic_iter(&j, &[1, 2, 3, 4, 5]);
+    generic_mut(&mut j);
+    generic_slice_mut(&mut j);
+    assert!(j.journal_len() > 0);
+    j.rollback();
+    assert_eq!(j.journal_len(), 0);
+    assert_eq!(j.as_inner(), &[1, 2, 3, 4, 5]);
+
+    j.apply_in_place_chunked(2, |x| x * 2);
+    j.index_subslice_mut(1..3).set_value(0, 0);
+    j.copy_within(3.., 0);
+    assert_eq!(j.as_inner(), &[8, 10, 6, 8, 10]);
+    let savepoint = j.savepoint();
+    assert_eq!(j.replace_value(4, 0), 10);
+    j.rollback_to(savepoint);
+    assert_eq!(j.as_inner(), &[8, 10, 6, 8, 10]);
+    j.commit();
+    j.rollback();
+    assert_eq!(j.into_inner(), [8, 10, 6, 8, 10]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_journaled_invalid_savepoint() {
+    let mut j = value_traits::adapters::journaled::Journaled::new([1, 2]);
+    j.rollback_to(1);
+}
+
+#[test]
+#[cfg(feature = "ffi")]
+fn test_extern_iter() {
+    use value_traits::adapters::{ffi::ExternIter, functional::FnSlice};
+
+    let s = FnSlice::new(4, |i| (i as u16, i as u16 * 10));
+    let mut iter = ExternIter::new(&s);
+    assert_eq!(iter.value_size(), 4);
+    assert_eq!(iter.len(), 4);
+    let mut out = (0, 0);
+    let mut values = vec![];
+    while iter.next_into(&mut out) {
+        values.push(out);
+    }
+    assert_eq!(values, [(0, 0), (1, 10), (2, 20), (3, 30)]);
+    assert!(iter.is_empty());
+    assert!(!iter.next_into(&mut out));
+    assert_eq!(out, (3, 30));
+
+    // The boxed iterator is dropped with the adapter
+    let counter = std::rc::Rc::new(());
+    let guard = counter.clone();
+    let iter = ExternIter::from_iter([1_u8, 2].into_iter().inspect(move |_| {
+        let _ = &guard;
+    }));
+    assert_eq!(std::rc::Rc::strong_count(&counter), 2);
+    assert_eq!(format!("{iter:?}"), "ExternIter { value_size: 1, len: 2 }");
+    drop(iter);
+    assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_shared_mut_slice() {
+    use value_traits::adapters::shared_mut::SharedMutSlice;
+    use value_traits::slices::*;
+
+    let shared = SharedMutSlice::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let mut a = shared.borrow_mut(0..5);
+    generic_get(&a, &EXPECTED);
+    generic_mut(&mut a);
+
+    let mut b = shared.borrow_mut(5..=7);
+    assert_eq!(b.len(), 3);
+    assert_eq!(b.replace_value(0, 60), 6);
+    assert_eq!(shared.num_borrowed(), 2);
+    assert_eq!(format!("{b:?}"), "SharedRangeMut { range: 5..8, .. }");
+
+    let err = shared.try_borrow_mut(7..).unwrap_err();
+    assert_eq!(err.range(), 5..8);
+    assert_eq!(err.to_string(), "range 5..8 is already mutably borrowed");
+    // Empty ranges do not overlap
+    assert!(shared.try_borrow_mut(6..6).is_ok());
+    let c = shared.borrow_mut(8..);
+    assert!(b.try_chunks_mut(1).is_err());
+
+    drop(b);
+    let b = shared.borrow_mut(5..8);
+    assert_eq!(b.index_value(0), 60);
+    drop((a, b, c));
+    assert_eq!(shared.num_borrowed(), 0);
+
+    let mut shared = shared;
+    shared.get_mut().set_value(9, 100);
+    assert_eq!(shared.into_inner()[5..], [60, 7, 8, 9, 100]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "already mutably borrowed")]
+fn test_shared_mut_slice_overlap() {
+    use value_traits::adapters::shared_mut::SharedMutSlice;
+
+    let shared = SharedMutSlice::new([0; 10]);
+    let _a = shared.borrow_mut(2..5);
+    let _b = shared.borrow_mut(4..);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_arc_locked_slice() {
+    use value_traits::adapters::locked::ArcLockedSlice;
+    use value_traits::iter::IterateByValue;
+    use value_traits::slices::*;
+
+    let x = ArcLockedSlice::new(EXPECTED.to_vec());
+    generic_get(&x, &EXPECTED);
+    generic_slice(&x, &EXPECTED);
+    generic_iter(&x, &EXPECTED);
+    generic_mut(x.clone());
+    generic_slice_mut(x.clone());
+
+    // Writes through a handle are seen by the other handles
+    let shared = ArcLockedSlice::new(vec![0_u64; 100]);
+    std::thread::scope(|scope| {
+        for t in 0..4 {
+            let mut handle = shared.clone();
+            scope.spawn(move || {
+                for i in (t..100).step_by(4) {
+                    handle.set_value(i, i as u64);
+                }
+            });
+        }
+    });
+    assert_eq!(shared.iter_value().sum::<u64>(), 4950);
+
+    let mut guard = shared.write();
+    assert_eq!(guard.replace_value(0, 7), 0);
+    for chunk in guard.try_chunks_mut(50).unwrap() {
+        chunk.set_value(1, 1);
+    }
+    drop(guard);
+    assert_eq!(shared.read()[..3], [7, 1, 2]);
+    assert_eq!(shared.index_value(51), 1);
+
+    let other = shared.clone();
+    let shared = shared.try_into_inner().unwrap_err();
+    drop(other);
+    assert_eq!(shared.try_into_inner().unwrap().len(), 100);
+}
+
+#[test]
+fn test_double_buffered() {
+    use value_traits::adapters::double_buffered::DoubleBuffered;
+    use value_traits::slices::*;
+
+    let mut d = DoubleBuffered::new([1, 2, 3, 4, 5], [0; 5]);
+    generic_get(&d, &[1, 2, 3, 4, 5]);
+    generic_slice(&d, &[1, 2, 3, 4, 5]);
+    generic_iter(&d, &[1, 2, 3, 4, 5]);
+
+    // Reads see the front slice, writes go to the back slice
+    d.set_value(0, 10);
+    assert_eq!(d.index_value(0), 1);
+    assert_eq!(d.replace_value(0, 20), 10);
+    d.index_subslice_mut(3..).set_value(0, 40);
+    assert_eq!(d.index_subslice(3..).index_value(0), 4);
+    assert!(d.try_chunks_mut(2).is_err());
+    assert_eq!(d.back(), &[20, 0, 0, 40, 0]);
+
+    d.swap();
+    assert_eq!(d.front(), &[20, 0, 0, 40, 0]);
+    assert_eq!(d.index_value(3), 40);
+    d.copy_front_to_back();
+    assert_eq!(d.into_parts(), ([20, 0, 0, 40, 0], [20, 0, 0, 40, 0]));
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn test_double_buffered_lengths() {
+    value_traits::adapters::double_buffered::DoubleBuffered::new(&mut [1, 2][..], &mut [0][..]);
+}
+
+#[test]
+fn test_observed_slice() {
+    use value_traits::adapters::observed::ObservedSlice;
+    use value_traits::slices::*;
+
+    let mut log = Vec::new();
+    let mut o = ObservedSlice::new([1, 2, 3, 4, 5], |i, old, new| log.push((i, old, new)));
+    generic_get(&o, &[1, 2, 3, 4, 5]);
+    generic_slice(&o, &[1, 2, 3, 4, 5]);
+    generic_iter(&o, &[1, 2, 3, 4, 5]);
+    o.set_value(0, 10);
+    assert_eq!(o.replace_value(4, 50), 5);
+    o.index_subslice_mut(1..3).set_value(1, 30);
+    assert!(o.try_chunks_mut(2).is_err());
+    assert_eq!(o.as_inner(), &[10, 2, 30, 4, 50]);
+    let (slice, _) = o.into_parts();
+    assert_eq!(slice, [10, 2, 30, 4, 50]);
+    assert_eq!(log, [(0, 1, 10), (4, 5, 50), (2, 3, 30)]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_persistent_slice() {
+    use value_traits::adapters::persistent::{BRANCHING, PersistentSlice};
+    use value_traits::slices::*;
+
+    let p: PersistentSlice<i32> = vec![1, 2, 3, 4, 5].into();
+    generic_get(&p, &[1, 2, 3, 4, 5]);
+    generic_slice(&p, &[1, 2, 3, 4, 5]);
+    generic_iter(&p, &[1, 2, 3, 4, 5]);
+    let mut b = p.builder();
+    generic_mut(&mut b);
+    generic_slice_mut(&mut b);
+    assert!(b.try_chunks_mut(2).is_err());
+    assert_eq!(p.index_subslice(..), [1, 2, 3, 4, 5]);
+
+    // Several levels, including a partial last leaf and a partial last
+    // inner node
+    for len in [0, 1, BRANCHING, BRANCHING + 1, BRANCHING * BRANCHING + 7] {
+        let values: Vec<usize> = (0..len).collect();
+        let v0: PersistentSlice<usize> = values.iter().copied().collect();
+        assert_eq!(v0.len(), len);
+        assert!((0..len).all(|i| v0.index_value(i) == i));
+
+        let mut builder = v0.builder();
+        assert!(builder.snapshot().ptr_eq(&v0));
+        for i in (0..len).step_by(3) {
+            assert_eq!(builder.replace_value(i, i + 1), i);
+        }
+        let v1 = builder.snapshot();
+        for i in (0..len).step_by(5) {
+            builder.set_value(i, 0);
+        }
+        let v2 = builder.build();
+        for i in 0..len {
+            assert_eq!(v0.index_value(i), i);
+            assert_eq!(v1.index_value(i), if i % 3 == 0 { i + 1 } else { i });
+            let expected = if i % 5 == 0 {
+                0
+            } else if i % 3 == 0 {
+                i + 1
+            } else {
+                i
+            };
+            assert_eq!(v2.index_value(i), expected);
+        }
+        if len > 1 {
+            let v3 = v0.with_value(len - 1, 0);
+            assert_eq!(v3.index_value(len - 1), 0);
+            assert_eq!(v0.index_value(len - 1), len - 1);
+            assert!(!v3.ptr_eq(&v0));
+        }
+    }
+
+    assert_eq!(format!("{:?}", PersistentSlice::from(vec![1, 2])), "[1, 2]");
+}
+
+#[test]
+fn test_validated() {
+    use value_traits::adapters::validated::{
+        Invariant, InvariantViolated, Sorted, StrictlySorted, Validated,
+    };
+    use value_traits::slices::*;
+
+    let v = Validated::<_, Sorted>::new([1, 2, 2, 4, 5]).unwrap();
+    generic_get(&v, &[1, 2, 2, 4, 5]);
+    generic_slice(&v, &[1, 2, 2, 4, 5]);
+    generic_iter(&v, &[1, 2, 2, 4, 5]);
+    for (x, expected) in [
+        (0, Err(0)),
+        (1, Ok(0)),
+        (3, Err(3)),
+        (5, Ok(4)),
+        (6, Err(5)),
+    ] {
+        assert_eq!(v.binary_search_value(&x), expected);
+    }
+    assert!(matches!(v.binary_search_value(&2), Ok(1 | 2)));
+    assert_eq!(v.partition_point_value(|&x| x < 4), 3);
+    assert_eq!(v.into_inner(), [1, 2, 2, 4, 5]);
+
+    let err = Validated::<_, StrictlySorted>::new([1, 2, 2, 4, 5]).unwrap_err();
+    assert_eq!(err.index(), 2);
+    assert_eq!(err.to_string(), "the invariant is violated at index 2");
+    assert_eq!(err.into_inner(), [1, 2, 2, 4, 5]);
+    let empty: [i32; 0] = [];
+    let empty = Validated::<_, StrictlySorted>::new(empty).unwrap();
+    assert_eq!(empty.binary_search_value(&0), Err(0));
+
+    // A user-defined invariant
+    struct Even;
+    impl<S: SliceByValue<Value = i32>> Invariant<S> for Even {
+        fn check(slice: &S) -> Result<(), usize> {
+            match (0..slice.len()).find(|&i| slice.index_value(i) % 2 != 0) {
+                Some(i) => Err(i),
+                None => Ok(()),
+            }
+        }
+    }
+    assert!(Validated::<_, Even>::new([2, 4]).is_ok());
+    let err: InvariantViolated<[i32; 2]> = Validated::<_, Even>::new([2, 3]).unwrap_err();
+    assert_eq!(err.index(), 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_compact() {
+    use value_traits::adapters::compact::{Compacted, RunLength, Sparse, compact, compact_boxed};
+    use value_traits::slices::*;
+
+    let data = [1, 1, 1, 2, 2, 3, 1, 1];
+    let r: RunLength<i32> = data.into_iter().collect();
+    assert_eq!(r.num_runs(), 4);
+    generic_get(&r, &data);
+    generic_slice(&r, &data);
+    generic_iter(&r, &data);
+
+    let s = Sparse::with_default(1, data);
+    assert_eq!(*s.default_value(), 1);
+    assert_eq!(s.num_entries(), 3);
+    generic_get(&s, &data);
+    generic_slice(&s, &data);
+    generic_iter(&s, &data);
+
+    let empty: RunLength<i32> = core::iter::empty().collect();
+    assert_eq!(empty.len(), 0);
+    assert_eq!(compact(&[0_i32; 0]), Compacted::Plain(vec![]));
+
+    let runs: Vec<i32> = (0..100).map(|i| i / 25).collect();
+    let c = compact(&runs[..]);
+    assert!(matches!(&c, Compacted::RunLength(r) if r.num_runs() == 4));
+    generic_get(&c, &runs);
+    generic_iter(&c, &runs);
+
+    let sparse: Vec<i32> = (0..100).map(|i| if i % 10 == 3 { i } else { -1 }).collect();
+    let c = compact(&sparse[..]);
+    assert!(matches!(&c, Compacted::Sparse(s) if *s.default_value() == -1));
+    generic_get(&c, &sparse);
+    generic_slice(&c, &sparse);
+
+    let plain: Vec<i32> = (0..100).collect();
+    let c = compact(&plain[..]);
+    assert!(matches!(c, Compacted::Plain(_)));
+    generic_get(&c, &plain);
+
+    // Boxed trait objects
+    let b = compact_boxed(&runs[..]);
+    generic_get(&b, &runs);
+    let b = compact(&sparse[..]).into_boxed();
+    generic_get(&b, &sparse);
+    let b: Box<dyn SliceByValue<Value = i32>> = Compacted::Plain(plain.clone()).into_boxed();
+    generic_get(&b, &plain);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_sync_lazy_slice() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use value_traits::adapters::lazy::SyncLazySlice;
+    use value_traits::slices::SliceByValue;
+
+    let calls = AtomicUsize::new(0);
+    let mut lazy = SyncLazySlice::new(100, |i| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        i as i32
+    });
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for i in 0..100 {
+                    assert_eq!(lazy.index_value(i), i as i32);
+                }
+            });
+        }
+    });
+    assert_eq!(calls.load(Ordering::Relaxed), 100);
+    generic_get(&lazy, &(0..100).collect::<Vec<_>>());
+    assert_eq!(calls.load(Ordering::Relaxed), 100);
+
+    lazy.clear();
+    assert!(!lazy.is_cached(7));
+    assert_eq!(lazy.get_value(7), Some(7));
+    assert!(lazy.is_cached(7));
+    assert_eq!(calls.load(Ordering::Relaxed), 101);
+}
+
+/// A backend storing values with a parity bit, whose iterator fails on
+/// corrupted values.
+struct Checked(Vec<u8>);
+
+#[derive(Debug, PartialEq)]
+struct Corrupted(usize);
+
+impl<'a> value_traits::iter::TryIterateByValueGat<'a> for Checked {
+    type Item = u8;
+    type Error = Corrupted;
+    type TryIter = Box<dyn Iterator<Item = Result<u8, Corrupted>> + 'a>;
+}
+
+impl value_traits::iter::TryIterateByValue for Checked {
+    fn try_iter_value(&self) -> value_traits::iter::TryIter<'_, Self> {
+        Box::new(self.0.iter().enumerate().map(|(i, &x)| {
+            if x.count_ones() % 2 == 0 {
+                Ok(x >> 1)
+            } else {
+                Err(Corrupted(i))
+            }
+        }))
+    }
+}
+
+fn try_collect<S, E>(s: S) -> Result<Vec<u8>, E>
+where
+    S: value_traits::iter::TryIterateByValue
+        + for<'a> value_traits::iter::TryIterateByValueGat<'a, Item = u8, Error = E>,
+{
+    s.try_iter_value().collect()
+}
+
+#[test]
+fn test_try_iterate() {
+    use value_traits::adapters::fallible::Fallible;
+    use value_traits::iter::TryIterateByValue;
+    use value_traits::slices::SliceByValue;
+
+    let checked = Checked(vec![0b110, 0b1010]);
+    let by_ref = &checked;
+    assert_eq!(try_collect(by_ref), Ok(vec![3, 5]));
+    assert_eq!(
+        try_collect(Checked(vec![0b110, 0b111, 0b1])),
+        Err(Corrupted(1))
+    );
+
+    let fallible = Fallible::new([1_u8, 2, 3]);
+    assert_eq!(fallible.len(), 3);
+    assert_eq!(fallible.index_value(1), 2);
+    let iter = fallible.try_iter_value();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [Ok(3), Ok(2), Ok(1)]);
+    assert_eq!(try_collect(fallible), Ok(vec![1, 2, 3]));
+    assert_eq!(fallible.into_inner(), [1, 2, 3]);
+}
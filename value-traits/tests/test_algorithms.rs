@@ -0,0 +1,675 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_concat_join() {
+    use value_traits::algorithms::{concat_values, join_values};
+    use value_traits::slices::SliceByValueSubsliceRange;
+
+    let v = vec![1, 2, 3, 4, 5];
+    let pieces = [
+        v.index_subslice(..2),
+        v.index_subslice(2..2),
+        v.index_subslice(3..),
+    ];
+    assert_eq!(concat_values(pieces), vec![1, 2, 4, 5]);
+    assert_eq!(join_values(pieces, 0), vec![1, 2, 0, 0, 4, 5]);
+
+    let empty: [&[i32]; 0] = [];
+    assert_eq!(concat_values(empty), Vec::<i32>::new());
+    assert_eq!(join_values(empty, 0), Vec::<i32>::new());
+    assert_eq!(join_values([&v[..1]], 0), vec![1]);
+
+    let owned = vec![vec![1, 2], vec![3]];
+    assert_eq!(concat_values(owned.clone()), vec![1, 2, 3]);
+    assert_eq!(join_values(owned, 9), vec![1, 2, 9, 3]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_count_values() {
+    use value_traits::algorithms::CountValues;
+
+    let v = vec![3, 1, 3, 2, 3, 1];
+    let counts = v.count_values();
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&1], 2);
+    assert_eq!(counts[&2], 1);
+    assert_eq!(counts[&3], 3);
+    assert_eq!(v.count_value(&3), 3);
+    assert_eq!(v.count_value(&4), 0);
+    assert_eq!(v[1..4].count_value(&3), 1);
+    assert!([0_u8; 0].count_values().is_empty());
+}
+
+#[test]
+fn test_validate() {
+    use value_traits::algorithms::{validate, validate_iter, validate_subslices};
+
+    let v: Vec<u32> = (0..5000).collect();
+    let s = v.as_slice();
+    validate(s);
+    validate_subslices(&s);
+    validate_iter(s);
+
+    let empty: [u32; 0] = [];
+    validate(&empty);
+    validate_subslices(&empty);
+    validate_iter(&empty);
+
+    validate(&[7]);
+    validate_subslices(&[7]);
+    validate_iter(&[7]);
+}
+
+/// A slice whose index_value disagrees with get_value_unchecked.
+struct Broken;
+
+impl value_traits::slices::SliceByValue for Broken {
+    type Value = usize;
+    fn len(&self) -> usize {
+        10
+    }
+    fn index_value(&self, index: usize) -> usize {
+        index + (index == 7) as usize
+    }
+    unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+        index
+    }
+}
+
+#[test]
+#[cfg_attr(
+    not(feature = "panic-lite"),
+    should_panic(expected = "get_value(7) disagrees with index_value(7)")
+)]
+#[cfg_attr(
+    feature = "panic-lite",
+    should_panic(expected = "get_value disagrees with index_value")
+)]
+fn test_validate_broken() {
+    value_traits::algorithms::validate(&Broken);
+}
+
+/// A slice with block minima and maxima, counting the number of values
+/// read.
+struct Zoned {
+    values: Vec<i64>,
+    reads: core::cell::Cell<usize>,
+}
+
+impl value_traits::slices::SliceByValue for Zoned {
+    type Value = i64;
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+    unsafe fn get_value_unchecked(&self, index: usize) -> i64 {
+        self.reads.set(self.reads.get() + 1);
+        self.values[index]
+    }
+}
+
+impl value_traits::algorithms::BlockSummaries for Zoned {
+    fn block_size(&self) -> usize {
+        3
+    }
+    // Simulates stored summaries without counting reads
+    fn block_min(&self, block: usize) -> i64 {
+        self.values[self.block_range(block)]
+            .iter()
+            .copied()
+            .min()
+            .unwrap()
+    }
+    fn block_max(&self, block: usize) -> i64 {
+        self.values[self.block_range(block)]
+            .iter()
+            .copied()
+            .max()
+            .unwrap()
+    }
+}
+
+#[test]
+fn test_block_summaries() {
+    use value_traits::algorithms::BlockSummaries;
+
+    let zoned = Zoned {
+        values: (0..10).map(|x| x * x).collect(),
+        reads: Default::default(),
+    };
+    assert_eq!(zoned.num_blocks(), 4);
+    assert_eq!(zoned.block_range(3), 9..10);
+    assert_eq!(zoned.min_value(), Some(0));
+    assert_eq!(zoned.max_value(), Some(81));
+    assert_eq!(zoned.reads.get(), 0);
+    // The sum uses the default implementation
+    assert_eq!(zoned.sum_values(), 285);
+    assert_eq!(zoned.block_sum(1), 9 + 16 + 25);
+    assert_eq!(zoned.reads.get(), 13);
+    let by_ref = &zoned;
+    assert_eq!(by_ref.max_value(), Some(81));
+
+    let empty = Zoned {
+        values: vec![],
+        reads: Default::default(),
+    };
+    assert_eq!(empty.num_blocks(), 0);
+    assert_eq!(empty.min_value(), None);
+    assert_eq!(empty.sum_values(), 0);
+}
+
+#[test]
+#[should_panic(expected = "block out of bounds")]
+fn test_block_range_out_of_bounds() {
+    use value_traits::algorithms::BlockSummaries;
+
+    let zoned = Zoned {
+        values: vec![1, 2, 3],
+        reads: Default::default(),
+    };
+    zoned.block_range(1);
+}
+
+impl value_traits::algorithms::RangeQueries for Zoned {
+    fn range_sum<R: value_traits::slices::ComposeRange>(&self, range: R) -> i64 {
+        use value_traits::algorithms::BlockSummaries;
+        self.range_sum_by_blocks(range)
+    }
+    fn range_min<R: value_traits::slices::ComposeRange>(&self, range: R) -> Option<i64> {
+        use value_traits::algorithms::BlockSummaries;
+        self.range_min_by_blocks(range)
+    }
+    fn range_max<R: value_traits::slices::ComposeRange>(&self, range: R) -> Option<i64> {
+        use value_traits::algorithms::BlockSummaries;
+        self.range_max_by_blocks(range)
+    }
+}
+
+#[test]
+fn test_range_queries() {
+    use value_traits::algorithms::RangeQueries;
+
+    let v = [3, 1, 4, 1, 5, 9, 2, 6];
+    assert_eq!(v.range_sum(..), 31);
+    assert_eq!(v.range_sum(2..2), 0);
+    assert_eq!(v.range_min(4..), Some(2));
+    assert_eq!(v.range_max(..=4), Some(5));
+    assert_eq!(v.as_slice().range_max(6..), Some(6));
+    let by_ref = &v;
+    assert_eq!(RangeQueries::range_min(&by_ref, 1..1), None);
+
+    let zoned = Zoned {
+        values: (0..11).map(|x| (x * 7) % 11 - 5).collect(),
+        reads: Default::default(),
+    };
+    let values = zoned.values.clone();
+    for start in 0..=values.len() {
+        for end in start..=values.len() {
+            let expected = &values[start..end];
+            assert_eq!(zoned.range_sum(start..end), expected.iter().sum::<i64>());
+            assert_eq!(zoned.range_min(start..end), expected.iter().min().copied());
+            assert_eq!(zoned.range_max(start..end), expected.iter().max().copied());
+        }
+    }
+    // Only the values in the partial blocks are read
+    zoned.reads.set(0);
+    zoned.range_min(1..11);
+    assert_eq!(zoned.reads.get(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_range_queries_out_of_bounds() {
+    use value_traits::algorithms::RangeQueries;
+    [1, 2, 3].range_sum(2..4);
+}
+
+#[test]
+fn test_rank_select() {
+    use value_traits::algorithms::RankSelect;
+
+    let mask = [false, true, true, false, true, false];
+    let ranks: Vec<usize> = (0..=mask.len())
+        .map(|i| mask.count_ones_before(i))
+        .collect();
+    assert_eq!(ranks, [0, 0, 1, 2, 2, 3, 3]);
+    let selects: Vec<_> = (0..4).map(|k| mask.select_one(k)).collect();
+    assert_eq!(selects, [Some(1), Some(2), Some(4), None]);
+    for k in 0..3 {
+        let pos = mask.select_one(k).unwrap();
+        assert!(mask[pos]);
+        assert_eq!(mask.count_ones_before(pos), k);
+    }
+    let by_ref = &mask.as_slice();
+    assert_eq!(by_ref.count_ones_before(6), 3);
+    assert_eq!([false; 0].select_one(0), None);
+}
+
+#[test]
+#[should_panic]
+fn test_rank_out_of_bounds() {
+    use value_traits::algorithms::RankSelect;
+    [true, false].count_ones_before(3);
+}
+
+#[test]
+fn test_describe() {
+    use value_traits::algorithms::{DescribeSliceByValue, SliceStats};
+
+    let a = [1_u32, 2, 3];
+    let stats = a.stats();
+    assert_eq!(
+        stats,
+        SliceStats {
+            len: 3,
+            value_size_hint: Some(32),
+            backend_name: "[u32; 3]",
+        }
+    );
+    let by_ref = &a.as_slice();
+    assert_eq!(by_ref.stats().backend_name, "[u32]");
+    assert_eq!(by_ref.stats().value_size_hint, Some(32));
+    #[cfg(feature = "alloc")]
+    assert_eq!(stats.to_string(), "[u32; 3]: 3 values, 32 bits/value");
+
+    #[cfg(feature = "alloc")]
+    {
+        use value_traits::adapters::front_coded::FrontCoded;
+        let fc = FrontCoded::new(["a", "ab"], 2);
+        assert_eq!(
+            fc.stats().to_string(),
+            "FrontCoded: 2 values, variable size"
+        );
+    }
+}
+
+#[test]
+fn test_diff_values() {
+    use value_traits::algorithms::diff_values;
+
+    let a = [1, 2, 3, 4, 5];
+    let b = [1, 2, 3, 4, 5];
+    assert_eq!(diff_values(&a, &b).next(), None);
+
+    let c = [0, 2, 3, 0];
+    let mut diff = diff_values(&a, &c);
+    assert_eq!(diff.size_hint(), (0, Some(4)));
+    assert_eq!(diff.next(), Some((0, 1, 0)));
+    assert_eq!(diff.clone().count(), 1);
+    assert_eq!(diff.next(), Some((3, 4, 0)));
+    assert_eq!(diff.next(), None);
+    assert_eq!(diff.next(), None);
+
+    let by_ref = &a.as_slice();
+    assert_eq!(diff_values(by_ref, &[1_i32; 0]).count(), 0);
+    assert_eq!(diff_values(&c, &a).map(|(i, _, _)| i).sum::<usize>(), 3);
+}
+
+#[test]
+fn test_mismatch() {
+    use value_traits::algorithms::{MismatchValues, common_prefix_len, mismatch_value};
+
+    let a: [u32; 200] = core::array::from_fn(|i| i as u32);
+    for i in [0, 1, 63, 64, 65, 130, 199] {
+        let mut b = a;
+        b[i] += 1;
+        assert_eq!(mismatch_value(&a, &b), Some(i));
+        assert_eq!(a.mismatch_value(&b), Some(i));
+        assert_eq!(a.as_slice().mismatch_value(&b[..]), Some(i));
+        assert_eq!(common_prefix_len(&a, &b), i);
+        assert_eq!(a.common_prefix_len(&b), i);
+    }
+    assert_eq!(a.mismatch_value(&a), None);
+    assert_eq!(a.common_prefix_len(&a), 200);
+    assert_eq!(a[..100].mismatch_value(&a[..]), Some(100));
+    assert_eq!(a[..].mismatch_value(&a[..150]), Some(150));
+    assert_eq!(a[..100].common_prefix_len(&a[..]), 100);
+    assert_eq!(mismatch_value(&a[..130], &a), Some(130));
+    assert_eq!(mismatch_value(&[0_u32; 0], &[0_u32; 0]), None);
+    let (x, y) = (&a[..10], &a[..5]);
+    assert_eq!((&x).mismatch_value(&y), Some(5));
+
+    #[cfg(feature = "std")]
+    {
+        let d: std::collections::VecDeque<_> = a.iter().copied().collect();
+        let mut e = d.clone();
+        e[77] = 0;
+        assert_eq!(d.mismatch_value(&e), Some(77));
+        assert_eq!(a.to_vec().common_prefix_len(&a[..3].to_vec()), 3);
+    }
+}
+
+#[test]
+fn test_fingerprint() {
+    use core::hash::Hasher;
+    use value_traits::adapters::functional::{ConstSlice, FnSlice};
+    use value_traits::algorithms::{FingerprintHasher, FingerprintValues};
+
+    let a = [7_u64; 10];
+    let c = ConstSlice::new(7_u64, 10);
+    let f = FnSlice::new(10, |_| 7_u64);
+    assert_eq!(a.fingerprint_values_128(), c.fingerprint_values_128());
+    assert_eq!(a.fingerprint_values_128(), f.fingerprint_values_128());
+    assert_eq!(
+        a.fingerprint_values(FingerprintHasher::new()),
+        f.fingerprint_values(FingerprintHasher::default())
+    );
+    assert_eq!(
+        a.fingerprint_values(FingerprintHasher::new()),
+        a.fingerprint_values_128() as u64
+    );
+    assert_ne!(
+        a.fingerprint_values_128(),
+        ConstSlice::new(7_u64, 9).fingerprint_values_128()
+    );
+    assert_ne!(
+        [1_u32, 2].fingerprint_values_128(),
+        [2_u32, 1].fingerprint_values_128()
+    );
+    assert_ne!(
+        [0_u8; 0].fingerprint_values_128(),
+        [0_u8].fingerprint_values_128()
+    );
+    // Digests are stable across platforms
+    assert_eq!(
+        [1_u32, 2, 3].fingerprint_values_128(),
+        [1_usize, 2, 3].fingerprint_values_128()
+    );
+
+    let mut h = FingerprintHasher::new();
+    h.write(b"abcdefghij");
+    let mut g = FingerprintHasher::new();
+    g.write(b"abcdefghi");
+    assert_ne!(h.finish(), g.finish());
+    assert_ne!(h.finish128(), g.finish128());
+
+    #[cfg(feature = "std")]
+    {
+        let v = vec!["a", "bc"];
+        let w = vec!["ab", "c"];
+        assert_ne!(v.fingerprint_values_128(), w.fingerprint_values_128());
+        let sip = v.fingerprint_values(std::hash::DefaultHasher::new());
+        assert_eq!(
+            sip,
+            ["a", "bc"].fingerprint_values(std::hash::DefaultHasher::new())
+        );
+    }
+}
+
+#[test]
+fn test_heap_values() {
+    use value_traits::algorithms::{heapify_values, pop_heap_value, push_heap_value};
+
+    let mut v: [u32; 100] = core::array::from_fn(|i| ((i * 37) % 101) as u32);
+    let mut sorted = v;
+    sorted.sort_unstable();
+    heapify_values(&mut v, 100);
+    for (len, &expected) in (1..=100).rev().zip(sorted.iter().rev()) {
+        assert_eq!(pop_heap_value(&mut v, len), Some(expected));
+    }
+    assert_eq!(pop_heap_value(&mut v, 0), None);
+
+    let mut w = [0_u8; 8];
+    for (len, x) in [5, 2, 8, 2, 7, 1, 9, 3].into_iter().enumerate() {
+        push_heap_value(&mut w, len, x);
+    }
+    let popped: Vec<u8> = (1..=8)
+        .rev()
+        .map(|len| pop_heap_value(&mut w, len).unwrap())
+        .collect();
+    assert_eq!(popped, [9, 8, 7, 5, 3, 2, 2, 1]);
+
+    #[cfg(feature = "bitvec")]
+    {
+        use bitvec::prelude::*;
+        let mut b = bitvec![0, 1, 0, 1];
+        heapify_values(b.as_mut_bitslice(), 4);
+        assert_eq!(pop_heap_value(b.as_mut_bitslice(), 4), Some(true));
+        assert_eq!(pop_heap_value(b.as_mut_bitslice(), 3), Some(true));
+        assert_eq!(pop_heap_value(b.as_mut_bitslice(), 2), Some(false));
+    }
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_push_heap_full() {
+    use value_traits::algorithms::push_heap_value;
+
+    let mut v = [0; 3];
+    push_heap_value(&mut v, 3, 1);
+}
+
+#[test]
+fn test_partition_values() {
+    use value_traits::algorithms::partition_in_place_values;
+
+    for len in 0..20 {
+        let mut v: Vec<u32> = (0..len).map(|i| (i * 7) % 11).collect();
+        let mut calls = 0;
+        let split = partition_in_place_values(v.as_mut_slice(), |&x| {
+            calls += 1;
+            x < 5
+        });
+        assert_eq!(calls, len);
+        assert_eq!(split, v.iter().filter(|&&x| x < 5).count());
+        assert!(v[..split].iter().all(|&x| x < 5));
+        assert!(v[split..].iter().all(|&x| x >= 5));
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        use value_traits::algorithms::stable_partition_values;
+
+        let mut v: Vec<u32> = (0..20).map(|i| (i * 7) % 11).collect();
+        let expected: Vec<u32> = v
+            .iter()
+            .filter(|&&x| x < 5)
+            .chain(v.iter().filter(|&&x| x >= 5))
+            .copied()
+            .collect();
+        let count = v.iter().filter(|&&x| x < 5).count();
+        assert_eq!(stable_partition_values(&mut v, |&x| x < 5), count);
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_sort_values_counting() {
+    use value_traits::algorithms::sort_values_counting;
+
+    let mut v: Vec<u16> = (0..1000).map(|i| ((i * 7919) % 97) as u16).collect();
+    let mut expected = v.clone();
+    expected.sort_unstable();
+    sort_values_counting(&mut v, 96);
+    assert_eq!(v, expected);
+
+    let mut b = [true, false, true, false, false];
+    sort_values_counting(&mut b, 1);
+    assert_eq!(b, [false, false, false, true, true]);
+
+    let mut e: [u8; 0] = [];
+    sort_values_counting(&mut e, 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic(expected = "value greater than the maximum value")]
+fn test_sort_values_counting_too_large() {
+    use value_traits::algorithms::sort_values_counting;
+
+    let mut v = [1_u8, 5, 2];
+    sort_values_counting(&mut v, 4);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_top_k_values() {
+    use value_traits::adapters::functional::FnSlice;
+    use value_traits::algorithms::{TopKValues, top_k_values, top_k_values_by};
+
+    let v: Vec<u32> = (0..500).map(|i| (i * 7919) % 1009).collect();
+    let mut sorted = v.clone();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    for k in [0, 1, 2, 10, 499, 500, 1000] {
+        assert_eq!(v.top_k_values(k), sorted[..k.min(500)]);
+    }
+    sorted.reverse();
+    assert_eq!(v.top_k_values_by(5, |a, b| b.cmp(a)), sorted[..5]);
+    assert!([0_u8; 0].top_k_values(3).is_empty());
+    assert_eq!([3, 3, 1, 3].top_k_values(2), [3, 3]);
+
+    // Any by-value slice through the free functions
+    let f = FnSlice::new(10, |i| (i * 3) % 10);
+    assert_eq!(top_k_values(&f, 3), [9, 8, 7]);
+    assert_eq!(top_k_values_by(&f, 2, |a, b| b.cmp(a)), [0, 1]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_rechunk_values() {
+    use value_traits::algorithms::{Rechunk, rechunk_values};
+
+    let v: Vec<u32> = (0..10).collect();
+    let chunks = rechunk_values(&v, 4);
+    assert_eq!(chunks.chunk_size(), 4);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(
+        chunks.collect::<Vec<_>>(),
+        [vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]
+    );
+    assert_eq!(rechunk_values(&v, 5).count(), 2);
+    assert_eq!(rechunk_values(&v, 20).next(), Some(v.clone()));
+    assert_eq!(rechunk_values(&[0_u8; 0], 3).next(), None);
+
+    // Any iterator
+    let mut chunks = Rechunk::new((0..5).filter(|x| x % 2 == 0), 2);
+    assert_eq!(chunks.size_hint(), (0, Some(3)));
+    assert_eq!(chunks.next(), Some(vec![0, 2]));
+    assert_eq!(chunks.next(), Some(vec![4]));
+    assert_eq!(chunks.next(), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic(expected = "chunk size must be positive")]
+fn test_rechunk_values_zero() {
+    use value_traits::algorithms::rechunk_values;
+
+    rechunk_values(&[1, 2, 3], 0);
+}
+
+#[test]
+fn test_rechunk_values_array() {
+    use value_traits::algorithms::{RechunkArrays, rechunk_values_array};
+
+    let v = [1, 2, 3, 4, 5, 6, 7, 8];
+    let mut chunks = rechunk_values_array::<_, 3>(&v);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.next(), Some([1, 2, 3]));
+    assert_eq!(chunks.next(), Some([4, 5, 6]));
+    assert_eq!(chunks.len(), 0);
+    assert_eq!(chunks.next(), None);
+    assert!(chunks.into_remainder().eq([7, 8]));
+
+    let mut chunks = rechunk_values_array::<_, 4>(&v);
+    assert_eq!(chunks.by_ref().count(), 2);
+    assert_eq!(chunks.into_remainder().count(), 0);
+
+    // The remainder is empty until the iterator is exhausted
+    let mut chunks = RechunkArrays::<_, 2>::new(0..3);
+    assert_eq!(chunks.next(), Some([0, 1]));
+    assert_eq!(chunks.clone().into_remainder().count(), 0);
+    assert_eq!(chunks.next(), None);
+    assert!(chunks.into_remainder().eq([2]));
+}
+
+#[test]
+fn test_analyze_values() {
+    use value_traits::algorithms::{AnalyzeValues, BitWidth};
+
+    let stats = [5_i32, 5, -3, -3, -3, 5, 12].analyze_values();
+    assert_eq!(stats.len, 7);
+    assert_eq!(stats.runs, 4);
+    assert_eq!((stats.min, stats.max), (Some(-3), Some(12)));
+    assert_eq!(stats.distinct_estimate, None);
+    assert_eq!(stats.bit_width(), 5);
+
+    let empty: [u8; 0] = [];
+    let stats = empty.analyze_values();
+    assert_eq!((stats.len, stats.runs, stats.min), (0, 0, None));
+    assert_eq!(stats.bit_width(), 0);
+
+    let stats = [1.0, f64::NAN, 0.5].analyze_values();
+    assert_eq!((stats.min, stats.max), (Some(0.5), Some(1.0)));
+
+    assert_eq!(0_u64.min_bit_width(), 0);
+    assert_eq!(u64::MAX.min_bit_width(), 64);
+    assert_eq!(0_i8.min_bit_width(), 1);
+    assert_eq!((-1_i8).min_bit_width(), 1);
+    assert_eq!(i8::MIN.min_bit_width(), 8);
+    assert_eq!(i8::MAX.min_bit_width(), 8);
+    assert_eq!((-4_i64).min_bit_width(), 3);
+}
+
+#[test]
+#[cfg(feature = "hll")]
+fn test_analyze_values_with_distinct() {
+    use value_traits::algorithms::AnalyzeValues;
+
+    let stats = [1, 2, 2, 3, 3, 3].analyze_values_with_distinct();
+    assert_eq!(stats.distinct_estimate, Some(3));
+
+    let v: Vec<u64> = (0..100_000).map(|i| i % 50_000).collect();
+    let estimate = v.analyze_values_with_distinct().distinct_estimate.unwrap();
+    assert!(estimate.abs_diff(50_000) < 2_500, "{estimate}");
+}
+
+#[test]
+fn test_indices_where() {
+    use value_traits::algorithms::FilterIndices;
+
+    let v = [4, 7, 1, 8, 8, 2];
+    assert!(v.indices_where(|&x| x % 2 == 0).eq([0, 3, 4, 5]));
+    assert!(v[1..].indices_where(|&x| x > 5).eq([0, 2, 3]));
+    let by_ref = &v.as_slice();
+    assert!(by_ref.indices_where(|_| false).eq([0_usize; 0]));
+    assert!([0; 0].indices_where(|_| true).eq([0_usize; 0]));
+
+    let mask = [true, false, true];
+    assert!(mask.indices_where(|&b| b).eq([0, 2]));
+
+    #[cfg(feature = "bitvec")]
+    {
+        use bitvec::prelude::*;
+        let b = bitvec![1, 0, 0, 1, 1, 0];
+        assert!(b.indices_where(|&b| b).eq([0, 3, 4]));
+        assert!(b.indices_where(|&b| !b).eq([1, 2, 5]));
+        assert!(b.indices_where(|_| true).eq(0..6));
+        assert!(b.indices_where(|_| false).eq([0_usize; 0]));
+        assert!(b[2..].indices_where(|&b| b).eq([1, 2]));
+    }
+
+    // Adapters of the crate
+    let f = value_traits::adapters::functional::FnSlice::new(6, |i| i * i);
+    assert!(f.indices_where(|&x| x > 10).eq([4, 5]));
+    #[cfg(feature = "alloc")]
+    {
+        use value_traits::adapters::{compact::compact, paged::PagedVec};
+        use value_traits::slices::SliceByValueSubsliceRange;
+
+        let c = compact(&[0, 0, 5, 0, 0, 0, 7, 0, 0, 0]);
+        assert!(c.indices_where(|&x| x != 0).eq([2, 6]));
+        let mut p = PagedVec::with_page_size(2);
+        p.extend(v);
+        assert!(p.indices_where(|&x| x == 8).eq([3, 4]));
+        assert!(p.index_subslice(2..).indices_where(|&x| x < 4).eq([0, 3]));
+    }
+}
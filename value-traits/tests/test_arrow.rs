@@ -0,0 +1,50 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "arrow")]
+
+use arrow_array::{Array, Int32Array, PrimitiveArray, types::Int32Type};
+use value_traits::impls::arrow_arrays::*;
+use value_traits::iter::*;
+
+mod common;
+pub use common::*;
+
+#[test]
+fn test_arrow() {
+    let expected: Vec<i32> = (0..10).collect();
+    let a = Int32Array::from(expected.clone());
+    generic_get(&a, &expected);
+    generic_iter(&a, &expected);
+    generic_slice(&a, &expected);
+
+    // Offsets of sliced arrays are honored
+    let s = a.slice(3, 4);
+    generic_get(&s, &expected[3..7]);
+    assert_eq!(s.iter_value_from(2).collect::<Vec<_>>(), [5, 6]);
+}
+
+#[test]
+fn test_to_primitive_array() {
+    let v: Vec<i32> = (0..10).map(|i| i * i).collect();
+    let a: PrimitiveArray<Int32Type> = to_primitive_array(&v);
+    assert_eq!(a.null_count(), 0);
+    assert_eq!(a.values().as_ref(), v.as_slice());
+
+    let a: PrimitiveArray<Int32Type> = to_primitive_array_range(&v, 2..=4);
+    assert_eq!(a.values().as_ref(), &v[2..=4]);
+
+    let chunks: Vec<PrimitiveArray<Int32Type>> = to_primitive_arrays(&v, 3).collect();
+    assert_eq!(chunks.len(), 4);
+    let joined: Vec<i32> = chunks.iter().flat_map(|c| c.iter_value()).collect();
+    assert_eq!(joined, v);
+    assert_eq!(
+        to_primitive_arrays::<Int32Type, _>(&[0_i32; 0], 3).count(),
+        0
+    );
+}
@@ -0,0 +1,103 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "bitvec")]
+
+use bitvec::prelude::*;
+use value_traits::iter::{IterateByValue, IterateByValueFrom};
+use value_traits::slices::*;
+
+const EXPECTED: [bool; 6] = [true, false, true, true, false, false];
+
+fn check_get(s: impl SliceByValue<Value = bool>, expected: &[bool]) {
+    assert_eq!(s.len(), expected.len());
+    for (i, &e) in expected.iter().enumerate() {
+        assert_eq!(s.index_value(i), e);
+        assert_eq!(s.get_value(i), Some(e));
+        assert_eq!(unsafe { s.get_value_unchecked(i) }, e);
+    }
+    assert_eq!(s.get_value(expected.len()), None);
+}
+
+fn check_mut(mut s: impl SliceByValueMut<Value = bool>) {
+    for i in 0..s.len() {
+        let old = s.index_value(i);
+        s.set_value(i, !old);
+        assert_eq!(s.index_value(i), !old);
+        assert_eq!(s.replace_value(i, old), !old);
+        assert_eq!(unsafe { s.replace_value_unchecked(i, !old) }, old);
+        unsafe { s.set_value_unchecked(i, old) };
+        assert_eq!(s.index_value(i), old);
+    }
+}
+
+#[test]
+fn test_bit_slice() {
+    let mut data = [0b0000_1101_u8];
+    let bits = &mut data.view_bits_mut::<Lsb0>()[..6];
+    check_get(&*bits, &EXPECTED);
+    check_get(bits.index_subslice(1..4), &EXPECTED[1..4]);
+    check_get(bits.index_subslice(..=2), &EXPECTED[..=2]);
+    assert!(bits.get_subslice(2..7).is_none());
+    assert_eq!(bits.iter_value().collect::<Vec<_>>(), EXPECTED);
+    assert_eq!(bits.iter_value_from(4).collect::<Vec<_>>(), [false, false]);
+    assert_eq!(bits.iter_value_from(7).count(), 0);
+
+    check_mut(&mut *bits);
+    check_mut(bits.index_subslice_mut(2..));
+    SliceByValueMut::copy_within(bits, 0..3, 3);
+    check_get(&*bits, &[true, false, true, true, false, true]);
+    for chunk in bits.try_chunks_mut(4).unwrap() {
+        chunk.set_value(0, false);
+    }
+    check_get(&*bits, &[false, false, true, true, false, true]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_bit_vec() {
+    let mut bits = BitVec::<u16, Msb0>::from_iter(EXPECTED);
+    check_get(&bits, &EXPECTED);
+    check_get(bits.index_subslice(3..), &EXPECTED[3..]);
+    assert_eq!(bits.iter_value().collect::<Vec<_>>(), EXPECTED);
+    assert_eq!(bits.iter_value_from(5).collect::<Vec<_>>(), [false]);
+    check_mut(&mut bits);
+    check_mut(bits.index_subslice_mut(..4));
+    SliceByValueMut::copy_within(&mut bits, 1..3, 4);
+    check_get(&bits, &[true, false, true, true, false, true]);
+}
+
+#[test]
+#[should_panic]
+fn test_bit_slice_copy_within_out_of_bounds() {
+    let mut data = [0_u8];
+    SliceByValueMut::copy_within(data.view_bits_mut::<Lsb0>(), 0..3, 6);
+}
+
+#[test]
+fn test_bitvec_rank_select() {
+    use value_traits::algorithms::RankSelect;
+
+    let bits = bits![u8, Lsb0; 0, 1, 1, 0, 1, 0, 0, 0, 0, 1, 1];
+    let bools: Vec<bool> = bits.iter().by_vals().collect();
+    for i in 0..=bits.len() {
+        assert_eq!(
+            bits.count_ones_before(i),
+            bools.as_slice().count_ones_before(i)
+        );
+    }
+    for k in 0..7 {
+        assert_eq!(bits.select_one(k), bools.as_slice().select_one(k));
+    }
+    #[cfg(feature = "alloc")]
+    {
+        let bv = bits.to_bitvec();
+        assert_eq!(bv.count_ones_before(11), 5);
+        assert_eq!(bv.select_one(4), Some(10));
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "either")]
+
+use either::Either;
+use value_traits::iter::*;
+use value_traits::slices::*;
+
+mod common;
+pub use common::*;
+
+const EXPECTED: [i32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+fn backends() -> [Either<[i32; 10], &'static [i32]>; 2] {
+    [Either::Left(EXPECTED), Either::Right(EXPECTED.as_slice())]
+}
+
+#[test]
+fn test_either() {
+    for e in backends() {
+        generic_get(e, &EXPECTED);
+        generic_iter(&e, &EXPECTED);
+        generic_slice(e, &EXPECTED);
+        assert_eq!(e.iter_value().len(), 10);
+        assert_eq!(e.iter_value_from(7).collect::<Vec<_>>(), [7, 8, 9]);
+    }
+}
+
+#[test]
+fn test_either_mut() {
+    let array = EXPECTED;
+    let mut slice = EXPECTED;
+    let mut backends: [Either<[i32; 10], &mut [i32]>; 2] =
+        [Either::Left(array), Either::Right(slice.as_mut_slice())];
+    for mut e in backends.iter_mut() {
+        generic_mut(&mut *e);
+        generic_slice_mut(&mut *e);
+
+        e.copy_within(0..3, 7);
+        assert_eq!(e.index_value(9), e.index_value(2));
+        // Through a mutable reference, reaching the Either override
+        SliceByValueMut::for_each_value_mut(&mut e, 3..5, |i, x| (i == 3).then_some(-x));
+        assert_eq!(e.index_value(3), -3);
+        assert_eq!(e.index_value(4), 4);
+        for mut chunk in e.try_chunks_mut(4).unwrap() {
+            chunk.set_value(0, -1);
+        }
+        assert_eq!(e.index_value(4), -1);
+        assert_eq!(e.index_value(8), -1);
+        let mut sub = e.index_subslice_mut(1..);
+        sub.set_value(0, 42);
+        assert_eq!(e.index_value(1), 42);
+    }
+
+    let [left, _] = backends;
+    assert_eq!(left.left().unwrap()[1], 42);
+    assert_eq!(slice[1], 42);
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut b: Box<Either<[i32; 10], Vec<i32>>> = Box::new(Either::Right(EXPECTED.to_vec()));
+        b.for_each_value_mut(..2, |_, x| Some(x + 10));
+        assert_eq!(b.index_value(0), 10);
+        assert_eq!(b.index_value(1), 11);
+        assert_eq!(b.index_value(2), 2);
+    }
+}
@@ -0,0 +1,70 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "nightly")]
+
+use value_traits::gat::*;
+use value_traits::slices::{SliceByValue, SliceByValueMut};
+
+fn sum<S: IterateByValue<Item = i32> + ?Sized>(s: &S) -> i32 {
+    s.iter_value().sum()
+}
+
+fn sum_from<S: IterateByValueFrom<Item = i32> + ?Sized>(s: &S, from: usize) -> i32 {
+    s.iter_value_from(from).sum()
+}
+
+fn sum_range<S>(s: &S, from: usize, to: usize) -> i32
+where
+    S: SliceByValueSubslice<Value = i32> + ?Sized,
+    for<'a> S::Subslice<'a>: IterateByValue<Item = i32>,
+{
+    sum(&s.index_subslice(from..to))
+}
+
+#[test]
+fn test_gat_subslices() {
+    let mut a = [1, 2, 3, 4, 5];
+    assert_eq!(sum_range(&a, 1, 4), 9);
+    // Without higher-rank bounds, non-'static slices can be used, too
+    let r = &a[..];
+    assert_eq!(sum(&r.index_subslice(1..4)), 9);
+
+    let s = a.index_subslice(1..=3);
+    assert_eq!(s.len(), 3);
+    // Subslices of subslices
+    let t = s.index_subslice(1..);
+    assert_eq!(t.index_value(0), 3);
+    assert!(s.get_subslice(2..4).is_none());
+    assert_eq!(s.get_subslice(..).map(|s| s.len()), Some(3));
+
+    let mut s = a.index_subslice_mut(2..);
+    s.set_value(0, 10);
+    let mut t = s.index_subslice_mut(..=1);
+    t.set_value(1, 20);
+    assert!(t.get_subslice_mut(..3).is_none());
+    assert_eq!(a, [1, 2, 10, 20, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_gat_subslice_out_of_bounds() {
+    let a = [1, 2, 3];
+    let _ = a.index_subslice(2..4);
+}
+
+#[test]
+fn test_gat_iterators() {
+    let a = [1, 2, 3, 4];
+    assert_eq!(sum(&a), 10);
+    assert_eq!(sum(&a[..]), 10);
+    assert_eq!(sum_from(&a, 1), 9);
+    assert_eq!(sum_from(&a, 4), 0);
+    let it: <[i32; 4] as IterateByValue>::Iter<'_> = a.iter_value();
+    assert_eq!(it.len(), 4);
+}
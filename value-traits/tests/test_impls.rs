@@ -19,6 +19,19 @@ fn test_array() {
     generic_slice_mut(EXPECTED);
 }
 
+#[test]
+fn test_tuple() {
+    let t = (1, 2, 3, 4, 5);
+    generic_get(t, &EXPECTED);
+    generic_iter(&t, &EXPECTED);
+    generic_get((7,), &[7]);
+    generic_iter(&(7,), &[7]);
+    generic_get(
+        (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11),
+        &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+    );
+}
+
 #[test]
 fn test_slice() {
     generic_get(EXPECTED.as_slice(), &EXPECTED);
@@ -108,6 +121,24 @@ fn test_rc_iter() {
     generic_iter(&x, &EXPECTED);
 }
 
+/// Test that `Cow<'_, S>` delegates the read-only traits, both when borrowed
+/// and when owned.
+#[test]
+#[cfg(feature = "std")]
+fn test_cow() {
+    use std::borrow::Cow;
+    use value_traits::iter::ConsistentIteration;
+
+    let borrowed: Cow<'_, [i32]> = Cow::Borrowed(&EXPECTED);
+    let owned: Cow<'_, [i32]> = Cow::Owned(EXPECTED.to_vec());
+    for x in [borrowed, owned] {
+        generic_get(&x, &EXPECTED);
+        generic_slice(&x, &EXPECTED);
+        generic_iter(&x, &EXPECTED);
+        x.debug_check_consistency();
+    }
+}
+
 /// Test that `Vec<T>` iteration works.
 #[test]
 #[cfg(feature = "alloc")]
@@ -130,3 +161,375 @@ fn test_vec_deque_iter() {
     let x = Into::<VecDeque<_>>::into(EXPECTED.to_vec());
     generic_iter(&x, &EXPECTED);
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_into_subslice() {
+    use value_traits::slices::IntoSubslice;
+
+    let v = EXPECTED.to_vec();
+    generic_get(v.clone().into_subslice(..), &EXPECTED);
+    generic_get(v.clone().into_subslice(1..4), &EXPECTED[1..4]);
+    generic_get(v.clone().into_subslice(..=2), &EXPECTED[..=2]);
+    generic_get(v.clone().into_subslice(5..), &[]);
+
+    let b = EXPECTED.to_vec().into_boxed_slice();
+    generic_get(b.into_subslice(2..), &EXPECTED[2..]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_into_subslice_out_of_bounds() {
+    use value_traits::slices::IntoSubslice;
+    let _ = EXPECTED.to_vec().into_subslice(2..6);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_into_subslice_arc() {
+    use std::sync::Arc;
+    use value_traits::slices::IntoSubslice;
+
+    let x = <Arc<[i32]>>::from(EXPECTED);
+    generic_get(x.clone().into_subslice(..), &EXPECTED);
+    let sub = x.clone().into_subslice(1..);
+    assert_eq!(sub.range(), 1..5);
+    generic_get(sub.clone(), &EXPECTED[1..]);
+    generic_get(sub.into_subslice(1..=2), &EXPECTED[2..=3]);
+
+    // Pieces can be moved to other threads
+    let piece = x.into_subslice(3..);
+    let handle = std::thread::spawn(move || generic_get(piece, &EXPECTED[3..]));
+    handle.join().unwrap();
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_dyn_iter() {
+    use std::{rc::Rc, sync::Arc};
+    use value_traits::iter::DynIterateByValue;
+
+    let boxed: Vec<Box<dyn DynIterateByValue<Item = i32>>> =
+        vec![Box::new(vec![1, 2, 3]), Box::new([1, 2, 3])];
+    for b in &boxed {
+        generic_iter(b, &[1, 2, 3]);
+    }
+
+    let arc: Arc<dyn DynIterateByValue<Item = i32> + Send + Sync> = Arc::new(vec![1, 2, 3]);
+    let clone = arc.clone();
+    std::thread::spawn(move || generic_iter(&clone, &[1, 2, 3]))
+        .join()
+        .unwrap();
+    generic_iter(&arc, &[1, 2, 3]);
+
+    let rc: Rc<dyn DynIterateByValue<Item = i32>> = Rc::new([1, 2, 3]);
+    generic_iter(&rc, &[1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_boxed_iter() {
+    use value_traits::iter::*;
+
+    fn evens<'a, S>(s: &'a S) -> Box<dyn Iterator<Item = i32> + 'a>
+    where
+        S: IterateByValue + for<'b> IterateByValueGat<'b, Item = i32>,
+    {
+        Box::new(s.boxed_iter_value().filter(|x| x % 2 == 0))
+    }
+
+    let v = vec![1, 2, 3, 4];
+    assert_eq!(evens(&v).collect::<Vec<_>>(), [2, 4]);
+    assert_eq!(evens(&[6, 7]).collect::<Vec<_>>(), [6]);
+    assert_eq!(v.boxed_iter_value_from(1).collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(v.boxed_iter_value_from(4).next(), None);
+}
+
+#[test]
+fn test_copy_within() {
+    use value_traits::slices::*;
+
+    fn check<S>(s: &mut S, src: core::ops::Range<usize>, dst: usize, expected: &[i32])
+    where
+        S: SliceByValueMut<Value = i32> + ?Sized,
+    {
+        s.copy_within(src, dst);
+        generic_get(&*s, expected);
+    }
+
+    // Disjoint, backward and forward
+    check(&mut [1, 2, 3, 4, 5], 3..5, 0, &[4, 5, 3, 4, 5]);
+    check(&mut [1, 2, 3, 4, 5], 0..2, 3, &[1, 2, 3, 1, 2]);
+    // Overlapping, backward and forward
+    check(&mut [1, 2, 3, 4, 5], 1..5, 0, &[2, 3, 4, 5, 5]);
+    check(&mut [1, 2, 3, 4, 5][..], 0..4, 1, &[1, 1, 2, 3, 4]);
+    // Empty and identity
+    check(&mut [1, 2, 3], 1..1, 3, &[1, 2, 3]);
+    check(&mut [1, 2, 3], 0..3, 0, &[1, 2, 3]);
+
+    // A mutable slice using the default implementation
+    struct Cell([i32; 5]);
+
+    impl SliceByValue for Cell {
+        type Value = i32;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        unsafe fn get_value_unchecked(&self, index: usize) -> i32 {
+            self.0[index]
+        }
+    }
+
+    impl SliceByValueMut for Cell {
+        unsafe fn set_value_unchecked(&mut self, index: usize, value: i32) {
+            self.0[index] = value;
+        }
+
+        type ChunksMut<'a> = core::iter::Empty<&'a mut [i32]>;
+
+        type ChunksMutError = ChunksMutNotSupported;
+
+        fn try_chunks_mut(
+            &mut self,
+            _chunk_size: usize,
+        ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+            Err(ChunksMutNotSupported)
+        }
+    }
+
+    let mut c = Cell([1, 2, 3, 4, 5]);
+    c.copy_within(0..4, 1);
+    assert_eq!(c.0, [1, 1, 2, 3, 4]);
+    c.copy_within(1..=3, 0);
+    assert_eq!(c.0, [1, 2, 3, 3, 4]);
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut v = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        SliceByValueMut::copy_within(&mut v, ..2, 1);
+        assert_eq!(v, ["a", "a", "b"]);
+
+        // All ranges, through references and boxes, against slice::copy_within
+        for start in 0..=8 {
+            for end in start..=8 {
+                for dst in 0..=8 - (end - start) {
+                    let mut expected: Vec<usize> = (0..8).collect();
+                    expected.copy_within(start..end, dst);
+                    let expected: Vec<String> = expected.iter().map(usize::to_string).collect();
+
+                    let mut v: Vec<String> = (0..8).map(|i| i.to_string()).collect();
+                    SliceByValueMut::copy_within(&mut &mut v[..], start..end, dst);
+                    assert_eq!(v, expected);
+
+                    let mut b: Box<[String]> = (0..8).map(|i| i.to_string()).collect();
+                    SliceByValueMut::copy_within(&mut b, start..end, dst);
+                    assert_eq!(&*b, &expected[..]);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "dest is out of bounds")]
+fn test_copy_within_out_of_bounds() {
+    value_traits::slices::SliceByValueMut::copy_within(&mut [1, 2, 3], 0..2, 2);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_vec_by_value() {
+    use value_traits::slices::*;
+    use value_traits::vecs::VecByValue;
+
+    /// A vector using the default implementations of the provided methods.
+    #[derive(Default)]
+    struct Defaults(Vec<i32>);
+
+    impl SliceByValue for Defaults {
+        type Value = i32;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        unsafe fn get_value_unchecked(&self, index: usize) -> i32 {
+            self.0[index]
+        }
+    }
+
+    impl SliceByValueMut for Defaults {
+        unsafe fn set_value_unchecked(&mut self, index: usize, value: i32) {
+            self.0[index] = value;
+        }
+
+        type ChunksMut<'a> = core::iter::Empty<&'a mut [i32]>;
+
+        type ChunksMutError = ChunksMutNotSupported;
+
+        fn try_chunks_mut(
+            &mut self,
+            _chunk_size: usize,
+        ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+            Err(ChunksMutNotSupported)
+        }
+    }
+
+    impl VecByValue for Defaults {
+        fn push_value(&mut self, value: i32) {
+            self.0.push(value);
+        }
+
+        fn pop_value(&mut self) -> Option<i32> {
+            self.0.pop()
+        }
+
+        fn truncate_values(&mut self, len: usize) {
+            self.0.truncate(len);
+        }
+
+        fn drain_values<R: ComposeRange>(&mut self, range: R) -> impl Iterator<Item = i32> + '_ {
+            let len = self.0.len();
+            self.0.drain(range.compose(0..len))
+        }
+    }
+
+    fn generic_vec<V: VecByValue<Value = i32>>(mut v: V) {
+        v.clear_values();
+        for x in [1, 1, 2, 3, 3, 3, 4, 5, 5] {
+            v.push_value(x);
+        }
+        v.dedup_values();
+        assert_eq!(v.len(), 5);
+        v.retain_values(|&x| x != 3);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.index_value(2), 4);
+        assert_eq!(v.pop_value(), Some(5));
+        v.truncate_values(2);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v.index_value(1), 2);
+        v.clear_values();
+        assert_eq!(v.pop_value(), None);
+
+        for x in 0..6 {
+            v.push_value(x);
+        }
+        assert_eq!(v.swap_remove_value(1), 1);
+        assert_eq!(v.swap_remove_value(4), 4);
+        assert_eq!(v.drain_values(1..3).collect::<Vec<_>>(), [5, 2]);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v.index_value(1), 3);
+        let _ = v.drain_values(..);
+        assert_eq!(v.len(), 0);
+
+        v.append_run(7, 3);
+        v.append_run(8, 0);
+        v.append_run(9, 1);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v.index_value(2), 7);
+        assert_eq!(v.index_value(3), 9);
+        v.clear_values();
+    }
+
+    fn generic_split_off<V: VecByValue<Value = i32> + Default>(mut v: V) {
+        for x in 0..5 {
+            v.push_value(x);
+        }
+        let w = v.split_off_values(3);
+        assert_eq!(v.len(), 3);
+        assert_eq!(w.len(), 2);
+        assert_eq!(w.index_value(0), 3);
+        assert_eq!(v.split_off_values(3).len(), 0);
+    }
+
+    let mut v = vec![0];
+    generic_vec(&mut v);
+    generic_split_off(Vec::new());
+    #[cfg(feature = "std")]
+    {
+        generic_vec(std::collections::VecDeque::new());
+        generic_split_off(std::collections::VecDeque::new());
+    }
+    generic_vec(Defaults::default());
+    generic_split_off(Defaults::default());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_swap_remove_value_out_of_bounds() {
+    use value_traits::vecs::VecByValue;
+
+    vec![1, 2, 3].swap_remove_value(3);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_get_value_into() {
+    use std::collections::VecDeque;
+    use value_traits::slices::SliceByValueGetInto;
+
+    fn check(s: impl SliceByValueGetInto<Value = Vec<u8>>) {
+        let mut out = Vec::with_capacity(16);
+        let ptr = out.as_ptr();
+        for i in 0..s.len() {
+            s.get_value_into(i, &mut out);
+            assert_eq!(out, s.index_value(i));
+            // The capacity of the destination is reused
+            assert_eq!(out.as_ptr(), ptr);
+        }
+    }
+
+    let a = [vec![1_u8, 2, 3], vec![], vec![4]];
+    check(a.clone());
+    check(a.as_slice());
+    check(a.to_vec());
+    check(Box::new(a.to_vec()));
+    check(VecDeque::from(a.to_vec()));
+    check(std::rc::Rc::new(a.clone()));
+}
+
+#[test]
+#[should_panic]
+fn test_get_value_into_out_of_bounds() {
+    use value_traits::slices::SliceByValueGetInto;
+
+    let mut out = 0;
+    [1, 2, 3].get_value_into(3, &mut out);
+}
+
+#[test]
+fn test_option() {
+    use value_traits::iter::*;
+    use value_traits::slices::*;
+
+    generic_get(Some(EXPECTED), &EXPECTED);
+    generic_iter(&Some(EXPECTED), &EXPECTED);
+    generic_slice(Some(EXPECTED), &EXPECTED);
+    generic_mut(Some(EXPECTED));
+    generic_slice_mut(Some(EXPECTED));
+
+    let none = None::<[i32; 5]>;
+    generic_get(none, &[]);
+    generic_iter(&none, &[]);
+    generic_mut(none);
+    assert!(none.index_subslice(..).is_none());
+    assert!(none.get_subslice(1..).is_none());
+    assert_eq!(none.iter_value_from(3).count(), 0);
+
+    let mut none = none;
+    none.copy_within(.., 0);
+    assert_eq!(none.try_chunks_mut(2).unwrap().count(), 0);
+    let mut some = Some(EXPECTED);
+    assert_eq!(some.try_chunks_mut(2).unwrap().count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_option_none_out_of_bounds() {
+    use value_traits::slices::*;
+    None::<[i32; 5]>.index_value(0);
+}
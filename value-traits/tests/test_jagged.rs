@@ -0,0 +1,95 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use value_traits::adapters::jagged::Csr;
+use value_traits::jagged::JaggedByValue;
+use value_traits::slices::SliceByValue;
+
+fn row_values(s: impl SliceByValue<Value = u32>) -> Vec<u32> {
+    (0..s.len()).map(|i| s.index_value(i)).collect()
+}
+
+fn total_row_len(j: impl JaggedByValue) -> usize {
+    (0..j.num_rows()).map(|i| j.row_len(i)).sum()
+}
+
+#[test]
+fn test_csr() {
+    // An adjacency list: 0 -> 1, 2; 1 -> (none); 2 -> 0; 3 -> 0, 1, 2
+    let offsets = [0, 2, 2, 3, 6];
+    let values = [1_u32, 2, 0, 0, 1, 2];
+    let j = Csr::new(offsets, values.as_slice());
+    assert_eq!(j.num_rows(), 4);
+    assert_eq!(j.total_len(), 6);
+    assert_eq!(row_values(j.row(0)), [1, 2]);
+    assert_eq!(row_values(j.row(1)), [0_u32; 0]);
+    assert_eq!(j.row_len(3), 3);
+    assert!(j.get_row(4).is_none());
+    assert_eq!(
+        j.rows().rev().map(row_values).collect::<Vec<_>>(),
+        [vec![0, 1, 2], vec![0], vec![], vec![1, 2]]
+    );
+    let by_ref = &j;
+    assert_eq!(total_row_len(by_ref), 6);
+    assert_eq!(j.rows().nth(2).map(row_values), Some(vec![0]));
+
+    let (offsets, _) = j.into_parts();
+    assert_eq!(offsets, [0, 2, 2, 3, 6]);
+
+    let empty = Csr::new([0], [0_u32; 0]);
+    assert_eq!(empty.num_rows(), 0);
+    assert_eq!(empty.rows().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_csr_decreasing_offsets() {
+    Csr::new([0, 3, 2, 4], [0_u32; 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_csr_bad_last_offset() {
+    Csr::new([0, 3], [0_u32; 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_csr_row_out_of_bounds() {
+    Csr::new([0, 3], [0_u32; 3]).row(1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_jagged_builder() {
+    use std::collections::VecDeque;
+    use value_traits::adapters::jagged::JaggedBuilder;
+
+    let mut builder = JaggedBuilder::new(VecDeque::from([7, 7]), vec![9_u32]);
+    assert_eq!(builder.num_rows(), 0);
+    assert_eq!(builder.total_len(), 0);
+    builder.push_row([1, 2]);
+    builder.extend_rows([vec![], vec![0], vec![0, 1, 2]]);
+    assert_eq!(builder.num_rows(), 4);
+    assert_eq!(builder.total_len(), 6);
+
+    let (offsets, values) = builder.clone().into_parts();
+    assert_eq!(offsets, [0, 2, 2, 3, 6]);
+    assert_eq!(values, [1, 2, 0, 0, 1, 2]);
+
+    let j = builder.build();
+    assert_eq!(j.num_rows(), 4);
+    assert_eq!(
+        j.rows().map(row_values).collect::<Vec<_>>(),
+        [vec![1, 2], vec![], vec![0], vec![0, 1, 2]]
+    );
+
+    let j = JaggedBuilder::<Vec<usize>, Vec<u32>>::default().build();
+    assert_eq!(j.num_rows(), 0);
+    assert_eq!(j.total_len(), 0);
+}
@@ -0,0 +1,117 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use value_traits::adapters::matrix::{RowMajor, Transposed};
+use value_traits::matrices::MatrixByValue;
+use value_traits::slices::SliceByValue;
+
+fn line_values(s: impl SliceByValue<Value = i32>) -> Vec<i32> {
+    (0..s.len()).map(|i| s.index_value(i)).collect()
+}
+
+#[test]
+fn test_row_major() {
+    let m = RowMajor::new([1, 2, 3, 4, 5, 6], 2);
+    assert_eq!(m.num_rows(), 3);
+    assert_eq!(m.num_cols(), 2);
+    assert_eq!(m.index_entry(2, 1), 6);
+    assert_eq!(m.get_entry(3, 0), None);
+    assert_eq!(m.get_entry(0, 2), None);
+    assert_eq!(line_values(m.row(1)), [3, 4]);
+    assert_eq!(line_values(m.col(1)), [2, 4, 6]);
+    assert_eq!(
+        m.rows().map(line_values).collect::<Vec<_>>(),
+        [[1, 2], [3, 4], [5, 6]]
+    );
+    assert_eq!(
+        m.cols().rev().map(line_values).collect::<Vec<_>>(),
+        [[2, 4, 6], [1, 3, 5]]
+    );
+    assert_eq!(m.rows().len(), 3);
+    assert_eq!(m.cols().nth(1).map(line_values), Some(vec![2, 4, 6]));
+
+    let empty = RowMajor::new([0; 0], 0);
+    assert_eq!(empty.num_rows(), 0);
+    assert_eq!(empty.rows().count(), 0);
+}
+
+#[test]
+fn test_transposed() {
+    let m = RowMajor::new([1, 2, 3, 4, 5, 6], 3);
+    let t = Transposed::new(&m);
+    assert_eq!(t.num_rows(), 3);
+    assert_eq!(t.num_cols(), 2);
+    assert_eq!(t.index_entry(2, 0), 3);
+    assert_eq!(
+        t.rows().map(line_values).collect::<Vec<_>>(),
+        m.cols().map(line_values).collect::<Vec<_>>()
+    );
+    let tt = Transposed::new(t);
+    assert_eq!(tt.index_entry(1, 2), 6);
+    assert_eq!(line_values(tt.into_inner().into_inner().row(0)), [1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_row_major_bad_length() {
+    RowMajor::new([1, 2, 3], 2);
+}
+
+#[test]
+#[should_panic]
+fn test_index_entry_out_of_bounds() {
+    RowMajor::new([1, 2, 3, 4], 2).index_entry(0, 2);
+}
+
+#[test]
+fn test_coord_matrix() {
+    use value_traits::matrices::CoordByValue;
+
+    let m = RowMajor::new([1, 2, 3, 4, 5, 6], 3);
+    assert_eq!(m.get_value((1, 2)), Some(6));
+    assert_eq!(m.get_value((2, 0)), None);
+    assert_eq!(Transposed::new(&m).get_value((2, 1)), Some(6));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_coo_matrix() {
+    use value_traits::adapters::matrix::CooMatrix;
+    use value_traits::matrices::CoordByValue;
+
+    let m = CooMatrix::new(3, 4, [(2, 1, 'c'), (0, 3, 'a'), (2, 1, 'd'), (1, 0, 'b')]);
+    assert_eq!(m.num_rows(), 3);
+    assert_eq!(m.num_cols(), 4);
+    assert_eq!(m.num_entries(), 3);
+    assert_eq!(m.entries(), [(0, 3, 'a'), (1, 0, 'b'), (2, 1, 'd')]);
+    assert_eq!(m.get_value((2, 1)), Some('d'));
+    assert_eq!(m.get_value((1, 0)), Some('b'));
+    assert_eq!(m.get_value((0, 0)), None);
+    assert_eq!(m.get_value((5, 5)), None);
+
+    fn count<C: CoordByValue>(c: &C, coords: &[(usize, usize)]) -> usize {
+        coords
+            .iter()
+            .filter(|&&c2| c.get_value(c2).is_some())
+            .count()
+    }
+    let coords = [(0, 0), (0, 3), (1, 1), (2, 1)];
+    assert_eq!(count(&m, &coords), 2);
+    assert_eq!(count(&RowMajor::new([0; 4], 2), &coords), 2);
+
+    let empty = CooMatrix::<u8>::new(0, 0, []);
+    assert_eq!(empty.get_value((0, 0)), None);
+    assert!(empty.into_inner().is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic(expected = "out of bounds")]
+fn test_coo_matrix_out_of_bounds() {
+    value_traits::adapters::matrix::CooMatrix::new(2, 2, [(0, 2, 1)]);
+}
@@ -0,0 +1,61 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "ndarray")]
+
+use ndarray::{Array1, ArrayView1, s};
+use value_traits::iter::*;
+use value_traits::slices::*;
+
+mod common;
+pub use common::*;
+
+#[test]
+fn test_ndarray() {
+    let mut a = Array1::from_vec(vec![0_i32, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let expected: Vec<i32> = (0..10).collect();
+    generic_get(&a, &expected);
+    generic_iter(&a, &expected);
+    generic_slice(&a, &expected);
+    generic_mut(&mut a);
+    generic_slice_mut(&mut a);
+
+    let mut a = Array1::from_vec(vec![0_i32, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let view = a.view();
+    generic_get(view, &expected);
+    generic_slice(a.view(), &expected);
+    generic_mut(a.view_mut());
+    generic_slice_mut(a.view_mut());
+}
+
+#[test]
+fn test_ndarray_strided() {
+    let mut a = Array1::from_vec((0..20).collect::<Vec<i32>>());
+    let expected: Vec<i32> = (0..20).step_by(2).collect();
+    let view: ArrayView1<'_, i32> = a.slice(s![..;2]);
+    generic_get(view, &expected);
+    generic_iter(&view, &expected);
+    generic_slice(view, &expected);
+
+    assert_eq!(view.iter_value_from(7).collect::<Vec<_>>(), [14, 16, 18]);
+    assert_eq!(view.iter_value_from(100).count(), 0);
+    let sub = view.index_subslice(2..=4);
+    assert_eq!(sub.iter_value().collect::<Vec<_>>(), [4, 6, 8]);
+    assert_eq!(sub.index_subslice(1..).index_value(0), 6);
+
+    let mut odd = a.slice_mut(s![1..;2]);
+    odd.set_value(0, -1);
+    assert_eq!(odd.replace_value(9, -19), 19);
+    for mut chunk in odd.try_chunks_mut(3).unwrap() {
+        chunk.set_value(0, 0);
+    }
+    assert_eq!(a[1], 0);
+    assert_eq!(a[7], 0);
+    assert_eq!(a[9], 9);
+    assert_eq!(a[19], 0);
+}
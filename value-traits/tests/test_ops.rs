@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use value_traits::ops::*;
+use value_traits::slices::*;
+
+#[test]
+fn test_assign_values() {
+    let mut a = [1_i32, 2, 3, 4];
+    add_assign_values(&mut a, &[10, 20, 30, 40]);
+    assert_eq!(a, [11, 22, 33, 44]);
+    sub_assign_values(&mut a, &[1, 2, 3, 4]);
+    assert_eq!(a, [10, 20, 30, 40]);
+    mul_assign_values(&mut a[..2], &[2, 3]);
+    assert_eq!(a, [20, 60, 30, 40]);
+    // Subslices are by-value slices, too
+    add_assign_values(&mut a.index_subslice_mut(2..), &[1, 1].as_slice());
+    assert_eq!(a, [20, 60, 31, 41]);
+}
+
+#[test]
+fn test_saturating_assign_values() {
+    let mut a = [250_u8, 5, 128];
+    saturating_add_assign_values(&mut a, &[10, 10, 10]);
+    assert_eq!(a, [255, 15, 138]);
+    saturating_sub_assign_values(&mut a, &[0, 20, 10]);
+    assert_eq!(a, [255, 0, 128]);
+    saturating_mul_scalar_in_place(&mut a, 2);
+    assert_eq!(a, [255, 0, 255]);
+
+    let mut b = [i8::MIN, 0, i8::MAX];
+    saturating_sub_assign_values(&mut b, &[1, 1, -1]);
+    assert_eq!(b, [i8::MIN, -1, i8::MAX]);
+}
+
+#[test]
+fn test_scalar_in_place() {
+    let mut a = [1_u64, 2, 3];
+    add_scalar_in_place(&mut a, 1);
+    assert_eq!(a, [2, 3, 4]);
+    mul_scalar_in_place(&mut a, 3);
+    assert_eq!(a, [6, 9, 12]);
+}
+
+#[test]
+#[should_panic]
+fn test_assign_values_different_lengths() {
+    let mut a = [1, 2, 3];
+    add_assign_values(&mut a, &[1, 2]);
+}
+
+#[test]
+fn test_prefix_suffix_sums() {
+    let mut a = [3_u32, 1, 4, 1, 5];
+    prefix_sum_in_place(&mut a);
+    assert_eq!(a, [3, 4, 8, 9, 14]);
+    let mut b = [3_u32, 1, 4, 1, 5];
+    suffix_sum_in_place(&mut b);
+    assert_eq!(b, [14, 11, 10, 6, 5]);
+
+    let mut empty: [u32; 0] = [];
+    prefix_sum_in_place(&mut empty);
+    suffix_sum_in_place(&mut empty);
+    delta_encode_in_place(&mut empty);
+}
+
+#[test]
+fn test_delta_round_trip() {
+    let mut a = [5_i64, -3, 8, 8, 0];
+    delta_encode_in_place(&mut a);
+    assert_eq!(a, [5, -8, 11, 0, -8]);
+    delta_decode_in_place(&mut a);
+    assert_eq!(a, [5, -3, 8, 8, 0]);
+
+    let mut b = [1_u8, 2, 3, 4];
+    delta_encode_in_place(&mut b.index_subslice_mut(1..));
+    assert_eq!(b, [1, 2, 1, 1]);
+}
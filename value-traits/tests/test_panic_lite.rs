@@ -0,0 +1,97 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Panics with static messages. Run with `--features panic-lite`.
+
+#![cfg(feature = "panic-lite")]
+
+use value_traits::slices::*;
+
+/// A slice whose values are their indices, using default implementations.
+struct Identity;
+
+impl SliceByValue for Identity {
+    type Value = usize;
+
+    fn len(&self) -> usize {
+        10
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+        index
+    }
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_index_out_of_bounds() {
+    Identity.index_value(10);
+}
+
+#[test]
+fn test_static_payload() {
+    // Formatted messages have a String payload, static ones a &str payload
+    let payload = std::panic::catch_unwind(|| Identity.index_value(10)).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"index out of bounds"));
+    let payload = std::panic::catch_unwind(|| value_traits::algorithms::validate(&Broken));
+    assert_eq!(
+        payload.unwrap_err().downcast_ref::<&str>(),
+        Some(&"get_value disagrees with index_value")
+    );
+}
+
+#[test]
+#[should_panic(expected = "range out of range")]
+fn test_range_out_of_range() {
+    use value_traits::adapters::subslice::SubsliceOf;
+
+    let _ = SubsliceOf::new(&Identity, 2..)
+        .unwrap()
+        .iter_value_range(5..9);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic(expected = "invalid savepoint")]
+fn test_journaled_invalid_savepoint() {
+    let mut j = value_traits::adapters::journaled::Journaled::new([1, 2]);
+    j.rollback_to(1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "range is already mutably borrowed")]
+fn test_shared_mut_slice_overlap() {
+    let s = value_traits::adapters::shared_mut::SharedMutSlice::new([0; 10]);
+    let _a = s.borrow_mut(0..5);
+    let _b = s.borrow_mut(4..6);
+}
+
+struct Broken;
+
+impl SliceByValue for Broken {
+    type Value = usize;
+
+    fn len(&self) -> usize {
+        10
+    }
+
+    fn index_value(&self, index: usize) -> usize {
+        index + 1
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+        index
+    }
+}
+
+#[test]
+#[should_panic(expected = "get_value disagrees with index_value")]
+fn test_validate_broken() {
+    value_traits::algorithms::validate(&Broken);
+}
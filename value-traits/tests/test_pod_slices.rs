@@ -0,0 +1,42 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "bytemuck")]
+
+use value_traits::impls::pod_slices::{CastValues, PodCastError};
+use value_traits::slices::*;
+
+mod common;
+pub use common::*;
+
+#[test]
+fn test_cast_values() {
+    let words: Vec<i32> = vec![1, 2, 3, 4, 5];
+    let bytes: &[u8] = words.cast_values().unwrap();
+    assert_eq!(bytes.len(), 20);
+    assert_eq!(bytes.index_value(4 * 2), 3_i32.to_ne_bytes()[0]);
+
+    let again: &[i32] = bytes.cast_values().unwrap();
+    generic_get(again, &words);
+
+    // Size and alignment are checked
+    assert_eq!(
+        bytes[..6].cast_values::<u32>(),
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+    );
+    assert_eq!(
+        bytes[1..5].cast_values::<u32>(),
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+    );
+
+    let mut pairs = [[1_i32, 2], [3, 4], [5, 6]];
+    let flat: &mut [i32] = pairs.cast_values_mut().unwrap();
+    flat.set_value(3, 40);
+    assert_eq!(flat.index_value(3), 40);
+    generic_mut(flat);
+}
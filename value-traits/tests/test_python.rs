@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "pyo3")]
+
+use value_traits::py_value_slice;
+use value_traits::python::pyo3::{prelude::*, py_run};
+
+py_value_slice! {
+    /// A test class.
+    pub struct PyI64Slice(Vec<i64>);
+}
+
+py_value_slice! {
+    struct PyBoolSlice([bool; 4]);
+}
+
+#[test]
+fn test_py_value_slice() -> PyResult<()> {
+    Python::initialize();
+    Python::attach(|py| {
+        let s = Bound::new(py, PyI64Slice::from(vec![10, 20, 30, 40, 50]))?;
+        py_run!(
+            py,
+            s,
+            r#"
+assert len(s) == 5
+assert s[0] == 10 and s[4] == 50
+assert s[-1] == 50 and s[-5] == 10
+for i in (5, -6):
+    try:
+        s[i]
+        assert False
+    except IndexError:
+        pass
+try:
+    s["a"]
+    assert False
+except TypeError:
+    pass
+assert s[1:3] == [20, 30]
+assert s[3:] == [40, 50]
+assert s[:] == [10, 20, 30, 40, 50]
+assert s[4:2] == []
+assert s[::2] == [10, 30, 50]
+assert s[::-1] == [50, 40, 30, 20, 10]
+assert s[-2:0:-2] == [40, 20]
+assert list(s) == [10, 20, 30, 40, 50]
+assert sum(s) == 150
+it = iter(s)
+assert next(it) == 10
+assert it.__length_hint__() == 4
+assert list(it) == [20, 30, 40, 50]
+"#
+        );
+
+        let b = Bound::new(py, PyBoolSlice([true, false, false, true]))?;
+        py_run!(
+            py,
+            b,
+            "assert list(b) == [True, False, False, True]\nassert b[1:3] == [False, False]"
+        );
+        assert!(b.get().0[0]);
+        Ok(())
+    })
+}
+
+#[test]
+fn test_get_item() -> PyResult<()> {
+    Python::initialize();
+    Python::attach(|py| {
+        let c = [5_u8, 6, 7];
+        let index = (-1_i32).into_pyobject(py)?.into_any();
+        assert_eq!(
+            value_traits::python::get_item(&c, &index)?.extract::<u8>(py)?,
+            7
+        );
+        let index = 3_i32.into_pyobject(py)?.into_any();
+        assert!(value_traits::python::get_item(&c, &index).is_err());
+        let index = pyo3::types::PySlice::new(py, 1, 3, 1).into_any();
+        let values: Vec<u8> = value_traits::python::get_item(&c, &index)?.extract(py)?;
+        assert_eq!(values, [6, 7]);
+        Ok(())
+    })
+}
@@ -595,3 +595,781 @@ fn test_derived_iter_rfold() {
         .rfold(String::new(), |acc, x| format!("{x}{acc}"));
     assert_eq!(result, "123");
 }
+
+/// Test that derived iterators can be cloned and formatted.
+#[test]
+fn test_derived_iter_clone_debug() {
+    let s = Sbv(vec![10, 20, 30, 40, 50]);
+    let sub = s.index_subslice(1..4); // [20, 30, 40]
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert_eq!(iter.next(), Some(20));
+
+    // The clone resumes from the same position, independently
+    let mut look_ahead = iter.clone();
+    assert_eq!(look_ahead.next(), Some(30));
+    assert_eq!(look_ahead.next(), Some(40));
+    assert_eq!(look_ahead.next(), None);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(30));
+
+    assert_eq!(format!("{iter:?}"), "SubsliceIter { range: 3..4 }");
+}
+
+/// Test the optimized short-circuiting methods of derived iterators.
+#[test]
+fn test_derived_iter_short_circuit() {
+    let s = Sbv(vec![10, 20, 30, 40, 50]);
+    let sub = s.index_subslice(1..5); // [20, 30, 40, 50]
+
+    // any/all leave the iterator right after the deciding element
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert!(iter.any(|x| x == 30));
+    assert_eq!(iter.next(), Some(40));
+    assert!(!iter.any(|x| x == 30));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert!(!iter.all(|x| x < 40));
+    assert_eq!(iter.next(), Some(50));
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert!(iter.all(|x| x > 0));
+    assert_eq!(iter.len(), 0);
+
+    // find/find_map/rfind
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert_eq!(iter.find(|&x| x > 25), Some(30));
+    assert_eq!(iter.find_map(|x| (x > 45).then_some(x * 2)), Some(100));
+    assert_eq!(iter.find(|_| true), None);
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert_eq!(iter.rfind(|&x| x < 45), Some(40));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.rfind(|&x| x > 100), None);
+    assert_eq!(iter.len(), 0);
+
+    // position/rposition are relative to the current position
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    iter.next();
+    assert_eq!(iter.position(|x| x == 40), Some(1));
+    assert_eq!(iter.position(|x| x == 40), None);
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    iter.next();
+    assert_eq!(iter.rposition(|x| x == 30), Some(0));
+    assert_eq!(iter.len(), 0);
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert_eq!(iter.rposition(|x| x == 40), Some(2));
+    assert_eq!(iter.next_back(), Some(30));
+}
+
+/// Test constant-time skipping and taking on derived iterators.
+#[test]
+fn test_derived_iter_skip_take() {
+    let s = Sbv(vec![10, 20, 30, 40, 50]);
+    let sub = s.index_subslice(1..5); // [20, 30, 40, 50]
+
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert_eq!(iter.skip_values(2), Ok(()));
+    assert_eq!(iter.next(), Some(40));
+    assert_eq!(iter.skip_values(0), Ok(()));
+    assert_eq!(iter.skip_values(3).map_err(|e| e.get()), Err(2));
+    assert_eq!(iter.next(), None);
+
+    let iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    let mut iter = iter.take_values(2);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(30));
+    assert_eq!(iter.next(), Some(20));
+    assert_eq!(iter.next(), None);
+
+    let iter = value_traits::iter::IterateByValueFrom::iter_value_from(&sub, 3);
+    let values: Vec<_> = iter.take_values(100).collect();
+    assert_eq!(values, vec![50]);
+}
+
+/// Test peeking on derived iterators.
+#[test]
+fn test_derived_iter_peek() {
+    let s = Sbv(vec![10, 20, 30, 40, 50]);
+    let sub = s.index_subslice(1..4); // [20, 30, 40]
+
+    let mut iter = value_traits::iter::IterateByValue::iter_value(&sub);
+    assert_eq!(iter.peek_value(), Some(20));
+    assert_eq!(iter.peek_nth(2), Some(40));
+    assert_eq!(iter.peek_nth(3), None);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(20));
+    assert_eq!(iter.next_back(), Some(40));
+    assert_eq!(iter.peek_value(), Some(30));
+    assert_eq!(iter.peek_nth(1), None);
+    assert_eq!(iter.next(), Some(30));
+    assert_eq!(iter.peek_value(), None);
+}
+
+/// Test `for_each_value_mut` on slices and derived mutable subslices.
+#[test]
+fn test_for_each_value_mut() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    v.for_each_value_mut(.., |i, x| (i % 2 == 0).then_some(x * 10));
+    assert_eq!(v, vec![10, 2, 30, 4, 50]);
+
+    let mut s = Sbv(vec![1, 2, 3, 4, 5]);
+    let mut sub = s.index_subslice_mut(1..5); // [2, 3, 4, 5]
+    let mut seen = vec![];
+    sub.for_each_value_mut(1..=2, |i, x| {
+        seen.push((i, x));
+        Some(x + 100)
+    });
+    assert_eq!(seen, vec![(1, 3), (2, 4)]);
+    sub.for_each_value_mut(..1, |_, _| None);
+    assert_eq!(s.0, vec![1, 2, 103, 104, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_for_each_value_mut_out_of_bounds() {
+    let mut s = Sbv(vec![1, 2, 3, 4, 5]);
+    let mut sub = s.index_subslice_mut(1..3);
+    sub.for_each_value_mut(1..3, |_, x| Some(x));
+}
+
+/// Test reborrowing a derived mutable subslice as a read-only one.
+#[test]
+fn test_as_subslice() {
+    fn sum(s: impl SliceByValue<Value = i32>) -> i32 {
+        (0..s.len()).map(|i| s.index_value(i)).sum()
+    }
+
+    let mut s = Sbv(vec![1, 2, 3, 4, 5]);
+    let mut sub = s.index_subslice_mut(1..4); // [2, 3, 4]
+    assert_eq!(sum(sub.as_subslice()), 9);
+    generic_get(sub.as_subslice(), &[2, 3, 4]);
+    sub.set_value(0, 10);
+    // The mutable subslice is still usable after reborrowing
+    assert_eq!(sum(sub.as_subslice()), 17);
+    assert_eq!(sub.as_subslice().index_subslice(1..).len(), 2);
+}
+
+/// Test comparison and formatting of derived subslices.
+#[test]
+fn test_subslice_eq_debug() {
+    let mut s = Sbv(vec![1, 2, 3, 4, 5]);
+    assert_eq!(s.index_subslice(1..3), &[2, 3]);
+    assert_eq!(s.index_subslice(1..3), [2, 3]);
+    assert_eq!(s.index_subslice(..), &[1, 2, 3, 4, 5][..]);
+    assert_eq!(s.index_subslice(1..1), []);
+    assert_ne!(s.index_subslice(1..3), &[2, 4]);
+    assert_ne!(s.index_subslice(1..3), &[2, 3, 4]);
+    assert_eq!(format!("{:?}", s.index_subslice(2..)), "[3, 4, 5]");
+
+    let mut t = s.index_subslice_mut(1..4);
+    t.set_value(0, 0);
+    assert_eq!(t, [0, 3, 4]);
+    assert_eq!(format!("{t:?}"), "[0, 3, 4]");
+}
+
+/// Test the exact-size marker on standard and derived types.
+#[test]
+fn test_exact_size_iterate_by_value() {
+    use value_traits::iter::*;
+
+    fn collect<S>(s: &S) -> Vec<i32>
+    where
+        S: ExactSizeIterateByValue + for<'a> IterateByValueGat<'a, Item = i32> + ?Sized,
+    {
+        let mut v = Vec::with_capacity(s.len());
+        v.extend(s.iter_value());
+        assert_eq!(v.len(), s.len());
+        v
+    }
+
+    assert_eq!(collect(&[1, 2, 3]), vec![1, 2, 3]);
+    assert_eq!(collect([1, 2, 3].as_slice()), vec![1, 2, 3]);
+
+    let mut s = Sbv(vec![1, 2, 3, 4, 5]);
+    assert_eq!(collect(&s.index_subslice(1..4)), vec![2, 3, 4]);
+    assert_eq!(collect(&s.index_subslice_mut(2..)), vec![3, 4, 5]);
+}
+
+#[test]
+fn test_derived_copy_within() {
+    let mut s = Sbv(vec![1, 2, 3, 4, 5, 6]);
+    let mut sub = s.index_subslice_mut(1..5); // [2, 3, 4, 5]
+    sub.copy_within(2.., 1);
+    assert_eq!(sub, [2, 4, 5, 5]);
+    sub.copy_within(..2, 2);
+    assert_eq!(sub, [2, 4, 2, 4]);
+    assert_eq!(s.0, vec![1, 2, 4, 2, 4, 6]);
+}
+
+#[test]
+#[should_panic(expected = "dest is out of bounds")]
+fn test_derived_copy_within_out_of_bounds() {
+    let mut s = Sbv(vec![1, 2, 3, 4, 5, 6]);
+    // The destination fits in the underlying slice, but not in the subslice
+    s.index_subslice_mut(1..5).copy_within(..2, 3);
+}
+
+/// A strided view with lifetime and const parameters.
+#[derive(Subslices, Iterators, SubslicesMut, IteratorsMut)]
+pub struct View<'a, T: Copy, const W: usize>(&'a mut [T]);
+
+impl<T: Copy, const W: usize> SliceByValue for View<'_, T, W> {
+    type Value = T;
+
+    fn len(&self) -> usize {
+        self.0.len() / W
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> T {
+        self.0[index * W]
+    }
+}
+
+impl<T: Copy, const W: usize> SliceByValueMut for View<'_, T, W> {
+    unsafe fn set_value_unchecked(&mut self, index: usize, value: T) {
+        self.0[index * W] = value;
+    }
+
+    type ChunksMut<'b>
+        = core::iter::Empty<&'b mut Self>
+    where
+        Self: 'b;
+
+    type ChunksMutError = ChunksMutNotSupported;
+
+    fn try_chunks_mut(
+        &mut self,
+        _chunk_size: usize,
+    ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+        Err(ChunksMutNotSupported)
+    }
+}
+
+#[test]
+fn test_lifetime_const_generics() {
+    use value_traits::iter::IterateByValue;
+
+    let mut data = [0, 1, 2, 3, 4, 5];
+    let mut v = View::<_, 2>(&mut data);
+    assert_eq!(v.index_subslice(1..), [2, 4]);
+    assert_eq!(
+        v.index_subslice(..).iter_value().collect::<Vec<_>>(),
+        [0, 2, 4]
+    );
+    v.index_subslice_mut(1..).set_value(0, 9);
+    assert_eq!(v.index_subslice_mut(..), [0, 9, 4]);
+    assert_eq!(v.index_subslice_mut(1..).iter_value().sum::<i32>(), 13);
+    assert_eq!(data, [0, 1, 9, 3, 4, 5]);
+}
+
+/// Test deriving on multiple types defined in the same function body.
+#[test]
+fn test_derive_in_function_body() {
+    use value_traits::iter::IterateByValue;
+
+    #[derive(Subslices, Iterators)]
+    struct Squares(usize);
+
+    #[derive(Subslices, Iterators)]
+    struct Cubes(usize);
+
+    impl SliceByValue for Squares {
+        type Value = usize;
+
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+            index * index
+        }
+    }
+
+    impl SliceByValue for Cubes {
+        type Value = usize;
+
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+            index * index * index
+        }
+    }
+
+    let squares = Squares(5);
+    let cubes = Cubes(5);
+    let s: Subslice<'_, Squares> = squares.index_subslice(1..4);
+    assert_eq!(s, [1, 4, 9]);
+    assert_eq!(s.iter_value().sum::<usize>(), 14);
+    assert_eq!(cubes.index_subslice(2..).index_subslice(1..), [27, 64]);
+}
+
+/// Test iterating on a range of derived subslices.
+#[test]
+fn test_derived_iter_value_range() {
+    let mut data = [0, 1, 2, 3, 4, 5, 6, 7];
+    let mut v = View::<_, 1>(&mut data);
+    let s = v.index_subslice(2..7);
+    assert_eq!(s.iter_value_to(2).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(s.iter_value_range(1..=3).collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!(
+        s.iter_value_range(..).rev().collect::<Vec<_>>(),
+        [6, 5, 4, 3, 2]
+    );
+    assert_eq!(s.iter_value_to(0).count(), 0);
+    let s = v.index_subslice_mut(1..4);
+    assert_eq!(s.iter_value_range(1..).collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(s.iter_value_to(3).len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_derived_iter_value_to_out_of_bounds() {
+    let mut data = [0, 1, 2, 3];
+    let v = View::<_, 1>(&mut data);
+    let _ = v.index_subslice(1..).iter_value_to(4);
+}
+
+/// Seekable iterators do not shadow the methods of `Iterator`.
+#[test]
+fn test_seekable_iter_position() {
+    use value_traits::iter::*;
+
+    let s = Sbv(vec![1, 3, 16, 3]);
+    let sub = s.index_subslice(1..);
+    assert_eq!(sub.iter_value().position(|x| x == 16), Some(1));
+}
+
+/// Test seeking on derived iterators.
+#[test]
+fn test_derived_seekable_iter() {
+    use value_traits::iter::{IterateByValue, IterateByValueFrom, SeekableValueIter};
+
+    let s = Sbv(vec![1, 3, 3, 3, 7, 10, 12, 20, 21, 40]);
+    let sub = s.index_subslice(..);
+    for target in 0..=41 {
+        for from in 0..=sub.len() {
+            let mut iter = sub.iter_value_from(from);
+            let expected = s.0[from..].iter().copied().find(|&x| x >= target);
+            assert_eq!(iter.seek_value(&target), expected);
+            assert_eq!(
+                iter.current_index(),
+                from + s.0[from..].iter().take_while(|&&x| x < target).count()
+                    + expected.is_some() as usize
+            );
+        }
+    }
+
+    // Indices are relative to the subslice, as in iter_value_from
+    let sub = s.index_subslice(2..8);
+    let mut iter = sub.iter_value();
+    assert_eq!(iter.current_index(), 0);
+    iter.seek(3);
+    assert_eq!(iter.current_index(), 3);
+    assert!(iter.clone().eq(sub.iter_value_from(3)));
+    assert_eq!(iter.next(), Some(10));
+    iter.seek(0);
+    assert_eq!(iter.current_index(), 4);
+    assert_eq!(iter.seek_value(&20), Some(20));
+    assert_eq!(iter.current_index(), 6);
+    iter.seek(100);
+    assert_eq!(iter.current_index(), 6);
+    assert_eq!(iter.next(), None);
+    let mut iter = sub.iter_value_from(2);
+    assert_eq!(iter.current_index(), 2);
+    iter.seek(usize::MAX);
+    assert_eq!(iter.current_index(), sub.len());
+    let mut iter = s.index_subslice(3..).iter_value_range(1..4);
+    assert_eq!(iter.current_index(), 1);
+    iter.seek(2);
+    assert_eq!(iter.next(), Some(10));
+    let sub = s.index_subslice(2..8);
+    assert_eq!(sub.iter_value().seek_value(&21), None);
+}
+
+#[test]
+fn test_dyn_slice_by_value() {
+    // SliceByValue must remain dyn compatible
+    fn sum(s: &dyn SliceByValue<Value = i32>) -> i32 {
+        (0..s.len()).map(|i| s.index_value(i)).sum()
+    }
+    let v = [1, 2, 3];
+    assert_eq!(sum(&v), 6);
+    let d: &dyn SliceByValue<Value = i32> = &v;
+    assert_eq!(d.get_value(3), None);
+    // Extension methods are available also on trait objects
+    assert!(d.pairs_value().eq([(1, 2), (2, 3)]));
+    assert_eq!(d.array_chunks_value::<2>().next(), Some([1, 2]));
+}
+
+#[test]
+fn test_array_chunks_value() {
+    let v: Vec<i32> = (0..11).collect();
+    let chunks = v.array_chunks_value::<4>();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.remainder(), 8..11);
+    assert_eq!(
+        chunks.clone().collect::<Vec<_>>(),
+        [[0, 1, 2, 3], [4, 5, 6, 7]]
+    );
+    assert_eq!(
+        chunks.rev().collect::<Vec<_>>(),
+        [[4, 5, 6, 7], [0, 1, 2, 3]]
+    );
+
+    let by_ref = &v;
+    let mut chunks = by_ref.array_chunks_value::<1>();
+    assert_eq!(chunks.nth(10), Some([10]));
+    assert_eq!(chunks.next(), None);
+    assert!(chunks.remainder().is_empty());
+
+    let short = [1, 2];
+    let mut chunks = short.array_chunks_value::<3>();
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.remainder(), 0..2);
+}
+
+#[test]
+fn test_windows_value() {
+    let v: Vec<i32> = (0..6).collect();
+    let windows = v.tuple_windows_value::<3>();
+    assert_eq!(windows.len(), 4);
+    assert_eq!(
+        windows.clone().collect::<Vec<_>>(),
+        [[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5]]
+    );
+    assert_eq!(windows.clone().nth(3), Some([3, 4, 5]));
+    assert_eq!(windows.rev().nth(1), Some([2, 3, 4]));
+    assert_eq!(v.tuple_windows_value::<6>().count(), 1);
+    assert_eq!(v.tuple_windows_value::<7>().count(), 0);
+
+    let mut pairs = v.pairs_value();
+    assert_eq!(pairs.len(), 5);
+    assert_eq!(pairs.next(), Some((0, 1)));
+    assert_eq!(pairs.nth(2), Some((3, 4)));
+    assert_eq!(pairs.next_back(), Some((4, 5)));
+    assert_eq!(pairs.next(), None);
+    assert_eq!([7].pairs_value().count(), 0);
+    assert_eq!([0_i32; 0].pairs_value().count(), 0);
+}
+
+/// A backend whose values may be unavailable, as in a remote store.
+struct Remote {
+    values: Vec<Option<i32>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Unavailable(usize);
+
+impl TrySliceByValueGet for Remote {
+    type Value = i32;
+    type Error = Unavailable;
+
+    fn try_get_value(&self, index: usize) -> Result<Option<i32>, Unavailable> {
+        match self.values.get(index) {
+            None => Ok(None),
+            Some(value) => value.map(Some).ok_or(Unavailable(index)),
+        }
+    }
+}
+
+fn try_sum<S: TrySliceByValueGet<Value = i32>>(s: &S) -> Result<i32, S::Error> {
+    let mut sum = 0;
+    let mut index = 0;
+    while let Some(value) = s.try_get_value(index)? {
+        sum += value;
+        index += 1;
+    }
+    Ok(sum)
+}
+
+#[test]
+fn test_try_get_value() {
+    assert_eq!(try_sum(&[1, 2, 3]), Ok(6));
+    assert_eq!(try_sum(&vec![1, 2, 3].as_slice()), Ok(6));
+    assert_eq!([1, 2, 3].try_get_value(3), Ok(None));
+
+    let remote = Remote {
+        values: vec![Some(1), Some(2)],
+    };
+    assert_eq!(try_sum(&remote), Ok(3));
+    let remote = Remote {
+        values: vec![Some(1), None, Some(2)],
+    };
+    assert_eq!(try_sum(&remote), Err(Unavailable(1)));
+    assert_eq!(remote.try_get_value(2), Ok(Some(2)));
+}
+
+/// Test `apply_in_place_chunked` with and without chunk support.
+#[test]
+fn test_apply_in_place_chunked() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let mut total = 0;
+    v.apply_in_place_chunked(2, |x| {
+        total += x;
+        total
+    });
+    assert_eq!(v, vec![1, 3, 6, 10, 15]);
+
+    let mut s = Sbv(vec![1, 2, 3, 4, 5]);
+    s.apply_in_place_chunked(3, |x| x * 10);
+    assert_eq!(s.0, vec![10, 20, 30, 40, 50]);
+
+    // Derived mutable subslices do not support chunks
+    let mut sub = s.index_subslice_mut(1..4);
+    assert!(sub.try_chunks_mut(2).is_err());
+    sub.apply_in_place_chunked(2, |x| x + 1);
+    assert_eq!(s.0, vec![10, 21, 31, 41, 50]);
+}
+
+#[test]
+fn test_compose_all() {
+    assert_eq!(compose_all(core::iter::empty::<Range<usize>>(), 7), 0..7);
+    assert_eq!(compose_all([1..=5, 2..=3], 10), 3..5);
+    assert_eq!(compose_all([3.., 1.., 0..], 10), 4..10);
+    assert_eq!(compose_all([..=4, ..=0], 10), 0..1);
+    // The composed range is the one covered by nested subslices
+    let v = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let s = v.index_subslice(2..9);
+    let t = s.index_subslice(1..5);
+    let u = t.index_subslice(3..);
+    let r = compose_all([2..9, 1..5, 3..4], v.len());
+    assert_eq!(u, &v[r]);
+
+    assert_eq!(try_compose_all([5..], 10), Ok(5..10));
+    let err = try_compose_all([5.., 6..], 10).unwrap_err();
+    assert_eq!(err.slice_len(), 5);
+    assert!(try_compose_all([..11], 10).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_compose_all_out_of_bounds() {
+    let _ = compose_all([2..8, 3..7], 10);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_get_subslices() {
+    let v = vec![0, 1, 2, 3, 4, 5, 6, 7];
+    let windows = v.get_subslices(&[0..2, 2..2, 5..8]).unwrap();
+    assert_eq!(windows, [&v[0..2], &v[2..2], &v[5..8]]);
+    assert!(v.get_subslices(&[0..2, 7..9]).is_none());
+    assert_eq!(v.index_subslices(&[1..=1, 3..=4]), [&v[1..2], &v[3..5]]);
+    assert!(v.get_subslices(&[] as &[Range<usize>]).unwrap().is_empty());
+
+    // Derived subslices
+    let s = Sbv(v.clone());
+    let windows = s.index_subslices(&[1..3, 4..7]);
+    assert_eq!(windows[0], [1, 2]);
+    assert_eq!(windows[1], [4, 5, 6]);
+    let boxed = Box::new(s);
+    assert_eq!(boxed.get_subslices(&[6..]).unwrap().len(), 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_index_subslices_out_of_bounds() {
+    let v = [0, 1, 2];
+    let _ = v.index_subslices(&[0..1, 2..4]);
+}
+
+#[test]
+fn test_consistent_iteration() {
+    use value_traits::adapters::functional::FnSlice;
+    use value_traits::iter::ConsistentIteration;
+
+    vec![1, 2, 3].debug_check_consistency();
+    [0_u8; 0].debug_check_consistency();
+    FnSlice::new(5, |i| i * i).debug_check_consistency();
+    let s = Sbv(vec![5, 6, 7, 8]);
+    s.index_subslice(1..3).debug_check_consistency();
+}
+
+/// A slice whose iterator skips the first value.
+struct Inconsistent;
+
+impl SliceByValue for Inconsistent {
+    type Value = usize;
+
+    fn len(&self) -> usize {
+        3
+    }
+
+    unsafe fn get_value_unchecked(&self, index: usize) -> usize {
+        index
+    }
+}
+
+impl<'a> value_traits::iter::IterateByValueGat<'a> for Inconsistent {
+    type Item = usize;
+    type Iter = Range<usize>;
+}
+
+impl value_traits::iter::IterateByValue for Inconsistent {
+    fn iter_value(&self) -> Range<usize> {
+        1..3
+    }
+}
+
+impl value_traits::iter::ConsistentIteration for Inconsistent {}
+
+#[test]
+#[cfg(debug_assertions)]
+#[cfg_attr(
+    not(feature = "panic-lite"),
+    should_panic(expected = "iter_value disagrees with get_value at index 0")
+)]
+#[cfg_attr(
+    feature = "panic-lite",
+    should_panic(expected = "iter_value disagrees with get_value")
+)]
+fn test_inconsistent_iteration() {
+    use value_traits::iter::ConsistentIteration;
+
+    Inconsistent.debug_check_consistency();
+}
+
+#[test]
+fn test_fixed_len() {
+    use value_traits::slices::FixedLenSliceByValue;
+
+    fn swap_ends<S: FixedLenSliceByValue<3, Value = i32> + SliceByValueMut>(s: &mut S) {
+        let first = s.get_value_const::<0>();
+        let last = s.replace_value_const::<2>(first);
+        s.set_value_const::<0>(last);
+    }
+
+    let mut a = [1, 2, 3];
+    swap_ends(&mut a);
+    assert_eq!(a, [3, 2, 1]);
+    swap_ends(&mut &mut a);
+    assert_eq!(a, [1, 2, 3]);
+
+    let t = (4, 5, 6);
+    assert_eq!(t.get_value_const::<1>(), 5);
+    fn third<S: FixedLenSliceByValue<3, Value = i32>>(s: S) -> i32 {
+        s.get_value_const::<2>()
+    }
+    assert_eq!(third(&t), 6);
+    #[cfg(feature = "alloc")]
+    assert_eq!(Box::new([7, 8]).get_value_const::<1>(), 8);
+}
+
+#[test]
+fn test_swap_with() {
+    let mut a = [1, 2, 3];
+    let mut b = [4, 5];
+    a.swap_with(2, &mut b, 0);
+    assert_eq!(a, [1, 2, 4]);
+    assert_eq!(b, [3, 5]);
+    // Different slice types with the same value type
+    let mut c = Some([6, 7]);
+    b[..].swap_with(1, &mut c, 0);
+    assert_eq!(b, [3, 6]);
+    assert_eq!(c, Some([5, 7]));
+}
+
+#[test]
+#[should_panic]
+fn test_swap_with_out_of_bounds() {
+    let mut a = [1, 2, 3];
+    let mut b = [4, 5];
+    a.swap_with(0, &mut b, 2);
+}
+
+#[test]
+fn test_take_value() {
+    let mut a = [1, 2, 3];
+    assert_eq!(a.take_value(0), 1);
+    assert_eq!(a, [0, 2, 3]);
+    assert_eq!(a[..].take_value(2), 3);
+    assert_eq!(a, [0, 2, 0]);
+    assert_eq!(a.take_value(0), 0);
+}
+
+#[test]
+fn test_update_value() {
+    let mut a = [1, 2, 3];
+    assert_eq!(a.update_value(0, |x| x + 10), 11);
+    assert_eq!(a, [11, 2, 3]);
+    assert_eq!(a[..].update_value(2, |x| -x), -3);
+    assert_eq!(a, [11, 2, -3]);
+    // SAFETY: the index is within bounds
+    assert_eq!(unsafe { a.update_value_unchecked(1, |x| x * x) }, 4);
+    assert_eq!(a, [11, 4, -3]);
+
+    // The slice is read just once
+    struct Counting {
+        values: [i32; 3],
+        reads: core::cell::Cell<usize>,
+    }
+
+    impl SliceByValue for Counting {
+        type Value = i32;
+
+        fn len(&self) -> usize {
+            self.values.len()
+        }
+
+        unsafe fn get_value_unchecked(&self, index: usize) -> i32 {
+            self.reads.set(self.reads.get() + 1);
+            self.values[index]
+        }
+    }
+
+    impl SliceByValueMut for Counting {
+        unsafe fn set_value_unchecked(&mut self, index: usize, value: i32) {
+            self.values[index] = value;
+        }
+
+        type ChunksMut<'a> = core::iter::Empty<&'a mut [i32]>;
+
+        type ChunksMutError = ChunksMutNotSupported;
+
+        fn try_chunks_mut(
+            &mut self,
+            _chunk_size: usize,
+        ) -> Result<Self::ChunksMut<'_>, ChunksMutNotSupported> {
+            Err(ChunksMutNotSupported)
+        }
+    }
+
+    let mut c = Counting {
+        values: [1, 2, 3],
+        reads: Default::default(),
+    };
+    assert_eq!(c.update_value(1, |x| x + 1), 3);
+    assert_eq!(c.try_update_value(2, |x| Ok::<_, ()>(x * 2)), Ok(6));
+    assert_eq!(c.values, [1, 3, 6]);
+    assert_eq!(c.reads.get(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_update_value_out_of_bounds() {
+    let mut a = [1, 2, 3];
+    a.update_value(3, |x| x);
+}
+
+#[test]
+fn test_try_update_value() {
+    let mut a = [1, i32::MAX - 1, 3];
+    let incr = |x: i32| x.checked_add(1).ok_or(x);
+    assert_eq!(a.try_update_value(0, incr), Ok(2));
+    assert_eq!(a.try_update_value(1, incr), Ok(i32::MAX));
+    assert_eq!(a.try_update_value(1, incr), Err(i32::MAX));
+    assert_eq!(a, [2, i32::MAX, 3]);
+}
+
+#[test]
+fn test_derived_indices_where() {
+    use value_traits::algorithms::FilterIndices;
+
+    let s = Sbv(vec![4, 7, 1, 8, 8, 2]);
+    assert!(s.indices_where(|&x| x % 2 == 0).eq([0, 3, 4, 5]));
+    let sub = s.index_subslice(1..5);
+    assert!(sub.indices_where(|&x| x > 5).eq([0, 2, 3]));
+    assert!(sub.index_subslice(1..).indices_where(|&x| x == 1).eq([0]));
+}
@@ -0,0 +1,106 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "alloc")]
+
+use value_traits::adapters::front_coded::FrontCoded;
+use value_traits::iter::{IterateByValue, IterateByValueFrom};
+use value_traits::slices::SliceByValue;
+use value_traits::strings::SliceOfStringsByValue;
+
+fn check_strings(s: impl SliceOfStringsByValue, expected: &[&str]) {
+    assert_eq!(s.len(), expected.len());
+    let mut buffer = String::from("garbage");
+    for (i, &e) in expected.iter().enumerate() {
+        s.get_str_into(i, &mut buffer);
+        assert_eq!(buffer, e);
+        assert_eq!(s.index_value(i), e);
+        assert_eq!(&*s.get_boxed_str(i), e);
+    }
+}
+
+#[test]
+fn test_vec_of_strings() {
+    let expected = ["b", "", "aa", "a"];
+    let v = expected.map(String::from).to_vec();
+    check_strings(&v, &expected);
+    check_strings(v.as_slice(), &expected);
+    check_strings(Box::new(expected.map(String::from)), &expected);
+}
+
+#[test]
+fn test_front_coded() {
+    let expected = [
+        "", "a", "ab", "abc", "abd", "b", "bèta", "bètb", "bêta", "ü", "üü", "x",
+    ];
+    for ratio in 1..=expected.len() + 1 {
+        let fc = FrontCoded::new(expected, ratio);
+        assert_eq!(fc.ratio(), ratio);
+        check_strings(&fc, &expected);
+        assert_eq!(fc.iter_value().collect::<Vec<_>>(), expected);
+        assert_eq!(fc.iter_value().len(), expected.len());
+        for from in 0..=expected.len() + 1 {
+            assert_eq!(
+                fc.iter_value_from(from).collect::<Vec<_>>(),
+                expected[from.min(expected.len())..]
+            );
+        }
+    }
+
+    let empty = FrontCoded::new([""; 0], 4);
+    assert!(empty.is_empty());
+    assert_eq!(empty.iter_value().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_front_coded_out_of_bounds() {
+    let mut buffer = String::new();
+    FrontCoded::new(["a", "b"], 2).get_str_into(2, &mut buffer);
+}
+
+#[test]
+#[should_panic]
+fn test_front_coded_zero_ratio() {
+    FrontCoded::new(["a"], 0);
+}
+
+#[test]
+fn test_front_coded_get_value_into() {
+    use value_traits::slices::SliceByValueGetInto;
+
+    let fc = FrontCoded::new(["abc", "abd", "x"], 2);
+    let mut out = String::new();
+    fc.get_value_into(1, &mut out);
+    assert_eq!(out, "abd");
+    fc.get_value_into(2, &mut out);
+    assert_eq!(out, "x");
+}
+
+#[test]
+fn test_front_coded_seek() {
+    use value_traits::iter::SeekableValueIter;
+
+    let expected = ["a", "ab", "abc", "b", "ba", "bb", "c", "ca", "cab", "d"];
+    let fc = FrontCoded::new(expected, 3);
+    for from in 0..=expected.len() {
+        for to in 0..=expected.len() + 1 {
+            let mut iter = fc.iter_value_from(from);
+            iter.seek(to);
+            let position = to.clamp(from, expected.len());
+            assert_eq!(iter.current_index(), position);
+            assert_eq!(iter.collect::<Vec<_>>(), expected[position..]);
+        }
+    }
+
+    let mut iter = fc.iter_value();
+    assert_eq!(iter.seek_value(&"abd".to_string()).as_deref(), Some("b"));
+    assert_eq!(iter.seek_value(&"c".to_string()).as_deref(), Some("c"));
+    assert_eq!(iter.current_index(), 7);
+    assert_eq!(iter.seek_value(&"z".to_string()), None);
+}
@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use value_traits::iter::*;
+use value_traits::slices::*;
+use value_traits::testing::*;
+
+#[test]
+fn test_uniform_slice() {
+    let s = uniform_slice(1000, 9, 0);
+    assert_eq!(s.len(), 1000);
+    let mut counts = [0; 10];
+    for x in s.iter_value() {
+        counts[x as usize] += 1;
+    }
+    assert!(counts.iter().all(|&c| c > 50));
+    assert!((0..1000).all(|i| s.index_value(i) == uniform_slice(1000, 9, 0).index_value(i)));
+    assert!((0..1000).any(|i| s.index_value(i) != uniform_slice(1000, 9, 1).index_value(i)));
+    assert_eq!(uniform_slice(3, 0, 7).iter_value().max(), Some(0));
+    let _ = uniform_slice(3, u64::MAX, 7).index_value(2);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_zipfian_slice() {
+    let s = zipfian_slice(10_000, 1.5, 0);
+    assert_eq!(s, zipfian_slice(10_000, 1.5, 0));
+    assert!(s.iter().all(|&x| (1..=10_000).contains(&x)));
+    let ones = s.iter().filter(|&&x| x == 1).count();
+    let twos = s.iter().filter(|&&x| x == 2).count();
+    assert!(ones > twos && twos > 0);
+    assert!(ones > 3_000);
+    assert!(zipfian_slice(0, 1.0, 0).is_empty());
+    assert_eq!(zipfian_slice(1, 1.0, 0), [1]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_random_sorted_slice() {
+    let s = random_sorted_slice(1000, 50, 3);
+    assert_eq!(s.len(), 1000);
+    assert!(s.windows(2).all(|w| w[0] <= w[1]));
+    assert!(*s.last().unwrap() <= 50);
+    assert_eq!(s, random_sorted_slice(1000, 50, 3));
+    assert_ne!(s, random_sorted_slice(1000, 50, 4));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_runs_slice() {
+    let s = runs_slice(1000, 8, 1000, 5);
+    assert_eq!(s.len(), 1000);
+    assert_eq!(s, runs_slice(1000, 8, 1000, 5));
+    assert!(s.iter().all(|&x| x <= 1000));
+    let runs = 1 + s.windows(2).filter(|w| w[0] != w[1]).count();
+    assert!((125..=1000).contains(&runs));
+    assert!(runs < 500);
+    assert!(runs_slice(0, 3, 1, 0).is_empty());
+    assert_eq!(runs_slice(5, 1, 0, 0), [0; 5]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic]
+fn test_runs_slice_zero_run() {
+    runs_slice(1, 0, 1, 0);
+}
@@ -0,0 +1,41 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Tommaso Fontana
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ * SPDX-FileCopyrightText: 2025 Inria
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use value_traits::total::*;
+
+fn sum<S: SliceByValueTotal<Value = i32> + ?Sized>(s: &S) -> i32 {
+    (0..s.len()).filter_map(|i| s.get_value(i)).sum()
+}
+
+#[test]
+fn test_total() {
+    let mut a = [1, 2, 3, 4];
+    assert_eq!(sum(&a), 10);
+    assert_eq!(a.get_value(4), None);
+
+    assert_eq!(a.try_set_value(0, 5), Ok(()));
+    assert_eq!(a.try_set_value(4, 6), Err(6));
+    assert_eq!(a.try_replace_value(1, 0), Ok(2));
+    assert_eq!(a.try_replace_value(5, 7), Err(7));
+    assert_eq!(a, [5, 0, 3, 4]);
+
+    let s = a.get_subslice(1..3).unwrap();
+    assert_eq!(sum(s), 3);
+    assert!(a.get_subslice(3..5).is_none());
+
+    a.get_subslice_mut(2..).unwrap()[0] = 9;
+    assert!(a.get_subslice_mut(..=4).is_none());
+    assert_eq!(a, [5, 0, 9, 4]);
+
+    assert_eq!(
+        a.try_iter_value_from(2).unwrap().collect::<Vec<_>>(),
+        [9, 4]
+    );
+    assert_eq!(a.try_iter_value_from(4).unwrap().count(), 0);
+    assert!(a.try_iter_value_from(5).is_none());
+}